@@ -35,6 +35,11 @@ pub enum PlaidFunctionError {
     Unknown,
     FailedToLogBack,
     LogbackBudgetExhausted,
+    MetricsDisabled,
+    MetricRejected,
+    RateLimited,
+    InvocationFailed,
+    CircuitOpen,
 }
 
 impl Error for PlaidFunctionError {}
@@ -59,6 +64,11 @@ impl core::fmt::Display for PlaidFunctionError {
             PlaidFunctionError::Unknown => write!(f, "An unknown error occurred. This can happen if the Plaid runtime is newer than the STL this rule was compiled against."),
             PlaidFunctionError::FailedToLogBack => write!(f, "Failed to dispatch log message: the receiver is disconnected or at capacity"),
             PlaidFunctionError::LogbackBudgetExhausted => write!(f, "Logback budget exhausted"),
+            PlaidFunctionError::MetricsDisabled => write!(f, "Metrics are not enabled on this Plaid instance"),
+            PlaidFunctionError::MetricRejected => write!(f, "The metric update was rejected. Check the Plaid logs for why."),
+            PlaidFunctionError::RateLimited => write!(f, "The call was rate limited"),
+            PlaidFunctionError::InvocationFailed => write!(f, "The invoked module could not be run or failed. Check the Plaid logs for why."),
+            PlaidFunctionError::CircuitOpen => write!(f, "The API has been failing, so the call was not made. Try again later."),
         }
     }
 }
@@ -82,6 +92,11 @@ impl From<i32> for PlaidFunctionError {
             -14 => Self::TimeoutElapsed,
             -15 => Self::FailedToLogBack,
             -16 => Self::LogbackBudgetExhausted,
+            -17 => Self::MetricsDisabled,
+            -18 => Self::MetricRejected,
+            -19 => Self::RateLimited,
+            -20 => Self::InvocationFailed,
+            -21 => Self::CircuitOpen,
             _ => Self::Unknown,
         }
     }
@@ -362,3 +377,74 @@ macro_rules! entrypoint_vec_with_source {
         }
     };
 }
+
+/// Define the entrypoint of a module which receives the messages of its log type in batches.
+/// The messages of a batch all have the same headers, query parameters and source. The
+/// module's `main` is given the messages of a batch, and returns the result of processing each
+/// of them, in the same order. Messages the module failed to process, or returned no result
+/// for, are retried, or kept as dead letters, one by one. A batch of a single message is
+/// delivered for retries.
+#[macro_export]
+macro_rules! entrypoint_batch_with_source {
+    () => {
+        use plaid_stl::{plaid::set_error_context, set_panic_hook};
+
+        #[no_mangle]
+        pub unsafe extern "C" fn entrypoint() -> i32 {
+            extern "C" {
+                fn fetch_data(data_buffer: *mut u8, buffer_size: u32) -> i32;
+                fn set_batch_results(data_buffer: *const u8, buffer_size: u32);
+            }
+
+            let buffer_size = fetch_data(vec![].as_mut_ptr(), 0);
+            let buffer_size = if buffer_size < 0 {
+                return buffer_size;
+            } else {
+                buffer_size as u32
+            };
+
+            let mut data_buffer = vec![0; buffer_size as usize];
+
+            let copied_size = fetch_data(data_buffer.as_mut_ptr(), buffer_size);
+            let copied_size = if copied_size < 0 {
+                return copied_size;
+            } else {
+                copied_size as u32
+            };
+
+            if copied_size != buffer_size {
+                return -1;
+            }
+
+            let batch =
+                match serde_json::from_slice::<Vec<plaid_stl::messages::BatchItem>>(&data_buffer) {
+                    Ok(batch) => batch,
+                    Err(_) => return -2,
+                };
+
+            set_panic_hook!();
+
+            // The error of each message, or nothing if it was processed. Messages without
+            // a result are considered failed by the runtime.
+            let ids: Vec<String> = batch.iter().map(|item| item.id.clone()).collect();
+            let results: Vec<plaid_stl::messages::BatchResult> = ids
+                .into_iter()
+                .zip(main(batch))
+                .map(|(id, result)| plaid_stl::messages::BatchResult {
+                    id,
+                    error: result.err().map(|e| e.to_string()),
+                })
+                .collect();
+            match serde_json::to_vec(&results) {
+                Ok(results) => {
+                    set_batch_results(results.as_ptr(), results.len() as u32);
+                    0
+                }
+                Err(e) => {
+                    set_error_context(&e.to_string());
+                    1
+                }
+            }
+        }
+    };
+}
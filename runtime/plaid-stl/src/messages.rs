@@ -27,6 +27,28 @@ pub enum LogSource {
     WebhookPost(String),
     WebhookGet(String),
     Logback(String),
+    /// The message was sent by a module which invoked this one directly
+    ModuleInvocation(String),
+}
+
+/// A message delivered to a module as part of a batch. See `entrypoint_batch_with_source`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BatchItem {
+    /// The ID of the message
+    pub id: String,
+    /// The message's data
+    pub log: String,
+    /// Where the message came from
+    pub source: LogSource,
+}
+
+/// The result of processing a message of a batch. See `entrypoint_batch_with_source`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BatchResult {
+    /// The ID of the message
+    pub id: String,
+    /// The error the module encountered, or `None` if it processed the message
+    pub error: Option<String>,
 }
 
 /// Represents how many logbacks can be triggered by the module that handles a message.
@@ -53,6 +75,7 @@ impl std::fmt::Display for LogSource {
             LogSource::WebhookPost(w) => write!(f, "webhookpost/{w}"),
             LogSource::WebhookGet(w) => write!(f, "webhookget/{w}"),
             LogSource::Logback(m) => write!(f, "logback/{m}"),
+            LogSource::ModuleInvocation(m) => write!(f, "invocation/{m}"),
         }
     }
 }
@@ -0,0 +1,75 @@
+//! Structured logs from a module, sent to every logging backend Plaid is configured with.
+//!
+//! Unlike `print_debug_string`, which only ends up in Plaid's own debug output, these logs
+//! are sent to Splunk, the webhook logger, etc., tagged with the module's name and the ID of
+//! the message being processed. Every module can only send a limited number of logs per
+//! period of time: logs over the limit are dropped, and the next log which goes through
+//! says how many were dropped.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::PlaidFunctionError;
+
+/// How important a module's log is
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+/// A log sent from a module to the runtime
+#[derive(Serialize, Deserialize)]
+pub struct ModuleLogEntry {
+    pub level: LogLevel,
+    pub message: String,
+    /// Structured data attached to the log
+    #[serde(default)]
+    pub fields: Map<String, Value>,
+}
+
+/// Send a log with structured fields to Plaid's logging backends
+pub fn log(
+    level: LogLevel,
+    message: &str,
+    fields: &[(&str, Value)],
+) -> Result<(), PlaidFunctionError> {
+    extern "C" {
+        /// Send a structured log to the logging system
+        fn module_log(entry: *const u8, entry_len: usize) -> i32;
+    }
+
+    let entry = ModuleLogEntry {
+        level,
+        message: message.to_string(),
+        fields: fields
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect(),
+    };
+    let entry = serde_json::to_vec(&entry).map_err(|_| PlaidFunctionError::InternalApiError)?;
+
+    let code = unsafe { module_log(entry.as_ptr(), entry.len()) };
+    if code == 0 {
+        Ok(())
+    } else {
+        Err(code.into())
+    }
+}
+
+/// Send an informative log to Plaid's logging backends
+pub fn info(message: &str) -> Result<(), PlaidFunctionError> {
+    log(LogLevel::Info, message, &[])
+}
+
+/// Send a warning to Plaid's logging backends
+pub fn warning(message: &str) -> Result<(), PlaidFunctionError> {
+    log(LogLevel::Warning, message, &[])
+}
+
+/// Send an error to Plaid's logging backends
+pub fn error(message: &str) -> Result<(), PlaidFunctionError> {
+    log(LogLevel::Error, message, &[])
+}
@@ -0,0 +1,97 @@
+//! Custom Prometheus metrics emitted by a module.
+//!
+//! Metrics are exposed by Plaid under a prefix made from the module's name, so a module
+//! `my_rule.wasm` recording the counter `events_total` produces
+//! `plaid_custom_my_rule_events_total`. If another module already produces a metric with the
+//! same full name (like `my.wasm` recording `rule_events_total`), the update is rejected.
+//! A metric's type and label names are set by the first
+//! update Plaid sees for it, and later updates which do not match are rejected. Every metric
+//! can only have a limited number of distinct label sets, configured per module by the
+//! Plaid operators, so labels should never contain unbounded values like IDs.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::PlaidFunctionError;
+
+/// An update to one of the module's metrics, as sent to the runtime
+#[derive(Serialize, Deserialize)]
+pub struct MetricUpdate {
+    /// The name of the metric, without the module's prefix
+    pub name: String,
+    /// The labels of the series being updated
+    pub labels: BTreeMap<String, String>,
+    pub operation: MetricOperation,
+}
+
+/// What is done to a metric
+#[derive(Serialize, Deserialize)]
+pub enum MetricOperation {
+    /// Add to a counter. The value cannot be negative.
+    IncrementCounter(f64),
+    /// Set a gauge to a value
+    SetGauge(f64),
+    /// Record an observation in a histogram. Histograms use Prometheus' default buckets.
+    ObserveHistogram(f64),
+}
+
+/// Add 1 to a counter
+pub fn increment_counter(name: &str, labels: &[(&str, &str)]) -> Result<(), PlaidFunctionError> {
+    increment_counter_by(name, labels, 1.0)
+}
+
+/// Add `value` to a counter
+pub fn increment_counter_by(
+    name: &str,
+    labels: &[(&str, &str)],
+    value: f64,
+) -> Result<(), PlaidFunctionError> {
+    record_metric(name, labels, MetricOperation::IncrementCounter(value))
+}
+
+/// Set a gauge to `value`
+pub fn set_gauge(
+    name: &str,
+    labels: &[(&str, &str)],
+    value: f64,
+) -> Result<(), PlaidFunctionError> {
+    record_metric(name, labels, MetricOperation::SetGauge(value))
+}
+
+/// Record `value` in a histogram
+pub fn observe_histogram(
+    name: &str,
+    labels: &[(&str, &str)],
+    value: f64,
+) -> Result<(), PlaidFunctionError> {
+    record_metric(name, labels, MetricOperation::ObserveHistogram(value))
+}
+
+fn record_metric(
+    name: &str,
+    labels: &[(&str, &str)],
+    operation: MetricOperation,
+) -> Result<(), PlaidFunctionError> {
+    extern "C" {
+        /// Update one of the module's metrics
+        fn record_metric(update: *const u8, update_len: usize) -> i32;
+    }
+
+    let update = MetricUpdate {
+        name: name.to_string(),
+        labels: labels
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        operation,
+    };
+    let update = serde_json::to_vec(&update).map_err(|_| PlaidFunctionError::InternalApiError)?;
+
+    let code = unsafe { record_metric(update.as_ptr(), update.len()) };
+    if code == 0 {
+        Ok(())
+    } else {
+        Err(code.into())
+    }
+}
@@ -1,6 +1,10 @@
+use serde::{Deserialize, Serialize};
+
 use crate::PlaidFunctionError;
 
 pub mod cache;
+pub mod logging;
+pub mod metrics;
 pub mod random;
 pub mod storage;
 
@@ -100,6 +104,186 @@ pub fn log_back_with_budget(
     log_back_host_result(code)
 }
 
+/// Run another module on `payload` and wait for the response it sets with `set_response`.
+/// The calling module must be allowed to invoke the other module in Plaid's configuration.
+///
+/// The invoked module receives `payload` as a message of its own log type, whose source is
+/// `LogSource::ModuleInvocation`. The computation it uses is taken from the calling module's
+/// budget, and it has to finish before the calling module's execution timeout. Returns an
+/// empty string if the invoked module did not set a response.
+pub fn invoke_module(module: &str, payload: &[u8]) -> Result<String, PlaidFunctionError> {
+    extern "C" {
+        /// Run a module and keep its response, returning how long the response is
+        fn invoke_module(
+            module: *const u8,
+            module_len: usize,
+            payload: *const u8,
+            payload_len: usize,
+        ) -> i32;
+        /// Get the response of the last module invoked
+        fn fetch_invocation_response(data_buffer: *mut u8, buffer_size: u32) -> i32;
+    }
+
+    let module_bytes = module.as_bytes().to_vec();
+    let buffer_size = unsafe {
+        invoke_module(
+            module_bytes.as_ptr(),
+            module_bytes.len(),
+            payload.as_ptr(),
+            payload.len(),
+        )
+    };
+    if buffer_size < 0 {
+        return Err(buffer_size.into());
+    }
+    if buffer_size == 0 {
+        return Ok(String::new());
+    }
+
+    let mut data_buffer = vec![0; buffer_size as usize];
+    let copied_size =
+        unsafe { fetch_invocation_response(data_buffer.as_mut_ptr(), buffer_size as u32) };
+    if copied_size < 0 {
+        return Err(copied_size.into());
+    }
+    if copied_size != buffer_size {
+        return Err(PlaidFunctionError::InternalApiError);
+    }
+
+    String::from_utf8(data_buffer).map_err(|_| PlaidFunctionError::ParametersNotUtf8)
+}
+
+/// A delayed logback which has not been delivered yet
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ScheduledLogback {
+    /// The ID returned when the logback was scheduled
+    pub id: String,
+    /// The log type the logback will be delivered to
+    pub log_type: String,
+    /// When the logback will be delivered, in seconds since the epoch
+    pub fires_at: u64,
+    /// The log that will be delivered
+    pub data: Vec<u8>,
+}
+
+/// Where and when a logback sent with `schedule_log_back` is delivered
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LogbackRequest {
+    /// The log type the logback will be delivered to
+    pub log_type: String,
+    /// How many seconds to wait before delivering the logback
+    pub delay: u32,
+    /// How many logbacks the invocation triggered by this logback can trigger in turn
+    pub logbacks_allowed: u32,
+}
+
+/// Send a log to the logback system like `log_back_with_budget`, and get back an ID for it.
+/// If the logback is delayed, the ID can be used to look it up with `get_scheduled_log_back`
+/// or to cancel it with `cancel_log_back` until it is delivered. This makes delayed logbacks
+/// usable as timers, e.g. to escalate something in an hour unless it is acknowledged first.
+///
+/// Only the module that scheduled a logback can look it up or cancel it.
+pub fn schedule_log_back(
+    type_: &str,
+    log: &[u8],
+    delay: u32,
+    logbacks_allowed: u32,
+) -> Result<String, PlaidFunctionError> {
+    extern "C" {
+        /// Send a log to the logback system and get its ID back
+        fn log_back_tracked(
+            request: *const u8,
+            request_len: usize,
+            log: *const u8,
+            log_len: usize,
+            id_buffer: *mut u8,
+            id_buffer_size: u32,
+        ) -> i32;
+    }
+
+    let request = LogbackRequest {
+        log_type: type_.to_string(),
+        delay,
+        logbacks_allowed,
+    };
+    let request = serde_json::to_string(&request).unwrap();
+
+    // Logback IDs are UUIDs
+    let mut id_buffer = vec![0; 36];
+    let copied_size = unsafe {
+        log_back_tracked(
+            request.as_ptr(),
+            request.len(),
+            log.as_ptr(),
+            log.len(),
+            id_buffer.as_mut_ptr(),
+            id_buffer.len() as u32,
+        )
+    };
+    if copied_size < 0 {
+        return Err(copied_size.into());
+    }
+
+    id_buffer.truncate(copied_size as usize);
+    String::from_utf8(id_buffer).map_err(|_| PlaidFunctionError::ParametersNotUtf8)
+}
+
+/// Look up a delayed logback that this module scheduled with `schedule_log_back`.
+/// Returns `None` if there is no such logback waiting to be delivered.
+pub fn get_scheduled_log_back(id: &str) -> Result<Option<ScheduledLogback>, PlaidFunctionError> {
+    extern "C" {
+        fn get_logback(id: *const u8, id_len: usize, data_buffer: *mut u8, buffer_size: u32)
+            -> i32;
+    }
+
+    let id_bytes = id.as_bytes().to_vec();
+    let buffer_size =
+        unsafe { get_logback(id_bytes.as_ptr(), id_bytes.len(), vec![].as_mut_ptr(), 0) };
+    if buffer_size < 0 {
+        return Err(buffer_size.into());
+    }
+    if buffer_size == 0 {
+        return Ok(None);
+    }
+
+    let mut data_buffer = vec![0; buffer_size as usize];
+    let copied_size = unsafe {
+        get_logback(
+            id_bytes.as_ptr(),
+            id_bytes.len(),
+            data_buffer.as_mut_ptr(),
+            buffer_size as u32,
+        )
+    };
+    if copied_size < 0 {
+        return Err(copied_size.into());
+    }
+    if copied_size != buffer_size {
+        return Err(PlaidFunctionError::InternalApiError);
+    }
+
+    serde_json::from_slice(&data_buffer)
+        .map(Some)
+        .map_err(|_| PlaidFunctionError::InternalApiError)
+}
+
+/// Cancel a delayed logback that this module scheduled with `schedule_log_back`.
+/// Returns `false` if there was no such logback waiting to be delivered, e.g. because
+/// it was already delivered.
+pub fn cancel_log_back(id: &str) -> Result<bool, PlaidFunctionError> {
+    extern "C" {
+        fn cancel_logback(id: *const u8, id_len: usize) -> i32;
+    }
+
+    let id_bytes = id.as_bytes().to_vec();
+    let code = unsafe { cancel_logback(id_bytes.as_ptr(), id_bytes.len()) };
+    match code {
+        0 => Ok(false),
+        1 => Ok(true),
+        code => Err(code.into()),
+    }
+}
+
 fn log_back_host_result(code: i32) -> Result<(), PlaidFunctionError> {
     if code == 0 {
         Ok(())
@@ -117,6 +301,17 @@ pub fn get_time() -> u32 {
     unsafe { get_time() }
 }
 
+/// Get which attempt at processing the current message this is. This is `1` the first time
+/// a message is processed and goes up every time the module's retry policy retries it.
+pub fn get_attempt() -> u32 {
+    extern "C" {
+        /// Get the current attempt from host
+        fn get_attempt() -> u32;
+    }
+
+    unsafe { get_attempt() }
+}
+
 macro_rules! generate_string_getter {
     ($what:ident) => {
         paste::item! {
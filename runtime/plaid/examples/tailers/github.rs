@@ -1,8 +1,8 @@
-use crossbeam_channel::bounded;
 use plaid::apis::github::Authentication;
 use serde::Deserialize;
 
 use plaid::data::{get_and_process_dg_logs, github::*};
+use plaid::executor::PoolSender;
 
 use std::env;
 use std::time::Duration;
@@ -54,7 +54,7 @@ async fn main() {
     env_logger::init();
     let config = get_config_from_env().unwrap();
 
-    let (logger_tx, logger_rx) = bounded(2048);
+    let (logger_tx, logger_rx) = PoolSender::unbounded();
 
     let mut gh = Github::new(config, logger_tx, None).unwrap();
 
@@ -0,0 +1,210 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use super::general::named_request_name;
+use crate::functions::FunctionErrors;
+
+/// The response to answer an API call with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CannedResponse {
+    /// The call succeeds. For functions that return data, this is the data. For functions that
+    /// only return a code, this is the code, and an empty string means 0.
+    Success(String),
+    /// The call fails with this error
+    Error(FunctionErrors),
+}
+
+impl CannedResponse {
+    /// The code a function that only returns a code returns
+    pub(crate) fn code(&self) -> Result<i32, FunctionErrors> {
+        match self {
+            Self::Success(data) if data.trim().is_empty() => Ok(0),
+            Self::Success(data) => data.trim().parse().map_err(|_| {
+                error!("Canned response [{data}] is not a valid return code");
+                FunctionErrors::InternalApiError
+            }),
+            Self::Error(e) => Err(*e),
+        }
+    }
+
+    /// The data a function that returns data returns
+    pub(crate) fn data(&self) -> Result<&str, FunctionErrors> {
+        match self {
+            Self::Success(data) => Ok(data),
+            Self::Error(e) => Err(*e),
+        }
+    }
+}
+
+/// A call a module made to an API
+#[derive(Debug, Clone)]
+pub struct ApiCall {
+    /// The host function the module called, like `github_add_user_to_repo`
+    pub function: String,
+    /// The parameters the module passed
+    pub params: String,
+    /// The response the call was answered with, if one was set
+    pub response: Option<CannedResponse>,
+}
+
+/// Responses to answer API calls with, instead of calling the APIs. These are used by the
+/// [`TestRuntime`](crate::testing::TestRuntime) and to replay recorded messages.
+#[derive(Default)]
+pub struct CannedResponses {
+    /// Responses to calls of a host function, by function name
+    functions: Mutex<HashMap<String, CannedResponse>>,
+    /// Responses to named requests, by request name. These take precedence over a response
+    /// set for `general_make_named_request`.
+    named_requests: Mutex<HashMap<String, CannedResponse>>,
+    /// Recorded responses, by function name and parameters. Each is given once, in the
+    /// order they were recorded, and they take precedence over the responses set above.
+    recorded: Mutex<HashMap<(String, String), VecDeque<CannedResponse>>>,
+    /// Every API call that was made, in order
+    calls: Mutex<Vec<ApiCall>>,
+}
+
+impl CannedResponses {
+    /// Record a call of `function` with `params` and get the response to answer it with, if
+    /// one is set. Calls without a response go to the API, which is not configured.
+    pub(crate) fn respond(&self, function: &str, params: &str) -> Option<CannedResponse> {
+        let recorded = lock(&self.recorded)
+            .get_mut(&(function.to_string(), params.to_string()))
+            .and_then(VecDeque::pop_front);
+        let named_request = (function == "general_make_named_request")
+            .then(|| named_request_name(params))
+            .flatten()
+            .and_then(|name| lock(&self.named_requests).get(&name).cloned());
+        let response = recorded
+            .or(named_request)
+            .or_else(|| lock(&self.functions).get(function).cloned());
+
+        lock(&self.calls).push(ApiCall {
+            function: function.to_string(),
+            params: params.to_string(),
+            response: response.clone(),
+        });
+        response
+    }
+
+    /// Answer calls with recorded responses, given as `(function, params, response)`, instead
+    /// of the ones recorded before
+    pub fn set_recorded(&self, calls: impl IntoIterator<Item = (String, String, CannedResponse)>) {
+        let mut recorded = lock(&self.recorded);
+        recorded.clear();
+        for (function, params, response) in calls {
+            recorded
+                .entry((function, params))
+                .or_default()
+                .push_back(response);
+        }
+    }
+
+    /// Answer calls of `function` with `response`
+    pub fn set_function_response(&self, function: &str, response: CannedResponse) {
+        lock(&self.functions).insert(function.to_string(), response);
+    }
+
+    /// Answer named requests for `request_name` with `response`
+    pub fn set_named_request_response(&self, request_name: &str, response: CannedResponse) {
+        lock(&self.named_requests).insert(request_name.to_string(), response);
+    }
+
+    /// Take the API calls made so far
+    pub fn take_calls(&self) -> Vec<ApiCall> {
+        std::mem::take(&mut *lock(&self.calls))
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_requests_take_precedence() {
+        let canned = CannedResponses::default();
+        canned.set_function_response(
+            "general_make_named_request",
+            CannedResponse::Success("any".to_string()),
+        );
+        canned.set_named_request_response(
+            "get_user",
+            CannedResponse::Error(FunctionErrors::InternalApiError),
+        );
+
+        let get_user = r#"{"request_name":"get_user","variables":{}}"#;
+        let other = r#"{"request_name":"other","variables":{}}"#;
+        assert_eq!(
+            canned.respond("general_make_named_request", get_user),
+            Some(CannedResponse::Error(FunctionErrors::InternalApiError))
+        );
+        assert_eq!(
+            canned.respond("general_make_named_request", other),
+            Some(CannedResponse::Success("any".to_string()))
+        );
+        assert_eq!(canned.respond("github_add_user_to_repo", "{}"), None);
+
+        let calls = canned.take_calls();
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[2].function, "github_add_user_to_repo");
+        assert!(canned.take_calls().is_empty());
+    }
+
+    #[test]
+    fn recorded_responses_are_given_once_in_order() {
+        let canned = CannedResponses::default();
+        canned.set_function_response(
+            "github_add_user_to_repo",
+            CannedResponse::Success("0".to_string()),
+        );
+        canned.set_recorded([
+            (
+                "github_add_user_to_repo".to_string(),
+                "{}".to_string(),
+                CannedResponse::Success("1".to_string()),
+            ),
+            (
+                "github_add_user_to_repo".to_string(),
+                "{}".to_string(),
+                CannedResponse::Error(FunctionErrors::RateLimited),
+            ),
+        ]);
+
+        let respond = || canned.respond("github_add_user_to_repo", "{}");
+        assert_eq!(respond(), Some(CannedResponse::Success("1".to_string())));
+        assert_eq!(
+            respond(),
+            Some(CannedResponse::Error(FunctionErrors::RateLimited))
+        );
+        assert_eq!(respond(), Some(CannedResponse::Success("0".to_string())));
+        // Recorded responses only answer calls with the parameters they were recorded for
+        canned.set_recorded([(
+            "github_add_user_to_repo".to_string(),
+            "{}".to_string(),
+            CannedResponse::Success("1".to_string()),
+        )]);
+        assert_eq!(
+            canned.respond("github_add_user_to_repo", r#"{"user":"a"}"#),
+            Some(CannedResponse::Success("0".to_string()))
+        );
+    }
+
+    #[test]
+    fn canned_codes() {
+        assert_eq!(CannedResponse::Success("".to_string()).code(), Ok(0));
+        assert_eq!(CannedResponse::Success(" 3 ".to_string()).code(), Ok(3));
+        assert_eq!(
+            CannedResponse::Success("yes".to_string()).code(),
+            Err(FunctionErrors::InternalApiError)
+        );
+        assert_eq!(
+            CannedResponse::Error(FunctionErrors::TestMode).code(),
+            Err(FunctionErrors::TestMode)
+        );
+    }
+}
@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use prometheus::core::{Collector, Desc};
+use prometheus::proto::MetricFamily;
+use prometheus::{IntCounterVec, IntGaugeVec, Opts};
+use serde::Deserialize;
+
+use crate::metrics::MetricsHandle;
+
+/// When a circuit breaker opens, and for how long
+#[derive(Deserialize, Clone)]
+pub struct CircuitBreakerSettings {
+    /// The ratio of calls that must fail, between 0 and 1, for the breaker to open
+    #[serde(default = "default_failure_ratio")]
+    pub failure_ratio: f64,
+    /// How many calls must have been made in the window before the breaker can open
+    #[serde(default = "default_minimum_calls")]
+    pub minimum_calls: u32,
+    /// How long calls are counted for, in seconds, before counting starts over
+    #[serde(default = "default_window_seconds")]
+    pub window_seconds: u64,
+    /// How long the breaker stays open, in seconds, before a single call is let through to
+    /// check whether the API has recovered
+    #[serde(default = "default_open_seconds")]
+    pub open_seconds: u64,
+}
+
+fn default_failure_ratio() -> f64 {
+    0.5
+}
+
+fn default_minimum_calls() -> u32 {
+    20
+}
+
+fn default_window_seconds() -> u64 {
+    60
+}
+
+fn default_open_seconds() -> u64 {
+    30
+}
+
+impl Default for CircuitBreakerSettings {
+    fn default() -> Self {
+        Self {
+            failure_ratio: default_failure_ratio(),
+            minimum_calls: default_minimum_calls(),
+            window_seconds: default_window_seconds(),
+            open_seconds: default_open_seconds(),
+        }
+    }
+}
+
+/// Configuration for the circuit breakers in front of the APIs. There is a breaker per API
+/// (named after it, like `github`, or `aws_kms` for the APIs with sub modules) and one per
+/// named request (named `named_request.<name>`). While a breaker is open, calls through it
+/// fail straight away instead of waiting on an API that keeps failing.
+#[derive(Deserialize, Default)]
+pub struct CircuitBreakerConfig {
+    /// The settings for breakers without an override
+    #[serde(flatten)]
+    pub defaults: CircuitBreakerSettings,
+    /// The settings for specific breakers. The mapping is `{breaker -> settings}`
+    #[serde(default)]
+    pub overrides: HashMap<String, CircuitBreakerSettings>,
+}
+
+/// The state of a single circuit breaker
+#[derive(Debug, PartialEq)]
+enum BreakerState {
+    /// Calls go through, and their outcomes are counted
+    Closed {
+        window_start: Instant,
+        calls: u32,
+        failures: u32,
+    },
+    /// Calls fail straight away until `until`
+    Open { until: Instant },
+    /// A single call, the probe, is let through. If it succeeds the breaker closes, otherwise it
+    /// opens again. Calls let through before the breaker opened have no say in this.
+    HalfOpen { probe: u64 },
+}
+
+/// How a breaker let a call through
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Admission {
+    /// The call counts towards opening the breaker
+    Counted,
+    /// The call checks whether the API has recovered, while the breaker is half-open
+    Probe(u64),
+}
+
+impl BreakerState {
+    fn closed(now: Instant) -> Self {
+        Self::Closed {
+            window_start: now,
+            calls: 0,
+            failures: 0,
+        }
+    }
+
+    /// The value of the state metric: 0 when closed, 1 when half-open and 2 when open
+    fn metric_value(&self) -> i64 {
+        match self {
+            Self::Closed { .. } => 0,
+            Self::HalfOpen { .. } => 1,
+            Self::Open { .. } => 2,
+        }
+    }
+}
+
+/// The circuit breakers in front of the APIs
+pub struct CircuitBreakers {
+    config: CircuitBreakerConfig,
+    /// Breakers are created the first time a call goes through them
+    breakers: Mutex<HashMap<String, BreakerState>>,
+    /// Tells the probes of half-open breakers apart
+    next_probe: AtomicU64,
+    rejected_calls: IntCounterVec,
+}
+
+/// A call that a circuit breaker let through. Its outcome is recorded with [`BreakerCall::finish`].
+/// If it is dropped without being finished, e.g. because the call timed out, it is recorded as
+/// a failure.
+pub struct BreakerCall<'a> {
+    breakers: &'a CircuitBreakers,
+    breaker: String,
+    admission: Admission,
+    finished: bool,
+}
+
+impl BreakerCall<'_> {
+    /// Record whether the call succeeded
+    pub fn finish(mut self, success: bool) {
+        self.finished = true;
+        self.breakers
+            .record_at(&self.breaker, self.admission, success, Instant::now());
+    }
+}
+
+impl Drop for BreakerCall<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.breakers
+                .record_at(&self.breaker, self.admission, false, Instant::now());
+        }
+    }
+}
+
+impl CircuitBreakers {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        let rejected_calls = IntCounterVec::new(
+            Opts::new(
+                "plaid_circuit_breaker_rejected_calls_total",
+                "Number of API calls that failed straight away because their circuit breaker was open",
+            ),
+            &["breaker"],
+        )
+        .expect("valid metric definition");
+
+        Self {
+            config,
+            breakers: Mutex::new(HashMap::new()),
+            next_probe: AtomicU64::new(0),
+            rejected_calls,
+        }
+    }
+
+    /// The name of the breaker in front of a call of `function`, which belongs to `api`.
+    /// Named requests each have their own breaker, since they go to different places.
+    pub fn breaker_name(api: &str, function: &str, params: &str) -> String {
+        if function == "general_make_named_request" {
+            if let Some(name) = crate::apis::general::named_request_name(params) {
+                return format!("named_request.{name}");
+            }
+        }
+        api.to_string()
+    }
+
+    /// Try to make a call through a breaker. Returns `None` if the breaker is open, in which
+    /// case the call must not be made.
+    pub fn call(&self, breaker: &str) -> Option<BreakerCall<'_>> {
+        let Some(admission) = self.allow_at(breaker, Instant::now()) else {
+            self.rejected_calls.with_label_values(&[breaker]).inc();
+            return None;
+        };
+
+        Some(BreakerCall {
+            breakers: self,
+            breaker: breaker.to_string(),
+            admission,
+            finished: false,
+        })
+    }
+
+    fn settings(&self, breaker: &str) -> &CircuitBreakerSettings {
+        self.config
+            .overrides
+            .get(breaker)
+            .unwrap_or(&self.config.defaults)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, BreakerState>> {
+        self.breakers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Whether a call through the breaker can be made at `now`, and how it was let through
+    fn allow_at(&self, breaker: &str, now: Instant) -> Option<Admission> {
+        let mut breakers = self.lock();
+        let state = breakers
+            .entry(breaker.to_string())
+            .or_insert_with(|| BreakerState::closed(now));
+
+        match state {
+            BreakerState::Closed { .. } => Some(Admission::Counted),
+            BreakerState::Open { until } if now < *until => None,
+            // The breaker has been open long enough: let this call through to check on the API
+            BreakerState::Open { .. } => {
+                let probe = self.next_probe.fetch_add(1, Ordering::Relaxed);
+                *state = BreakerState::HalfOpen { probe };
+                Some(Admission::Probe(probe))
+            }
+            // The probe is still in flight
+            BreakerState::HalfOpen { .. } => None,
+        }
+    }
+
+    /// Record the outcome of a call through the breaker, which finished at `now`
+    fn record_at(&self, breaker: &str, admission: Admission, success: bool, now: Instant) {
+        let settings = self.settings(breaker).clone();
+        let mut breakers = self.lock();
+        let state = breakers
+            .entry(breaker.to_string())
+            .or_insert_with(|| BreakerState::closed(now));
+
+        match state {
+            BreakerState::Closed {
+                window_start,
+                calls,
+                failures,
+            } => {
+                if now.duration_since(*window_start) >= Duration::from_secs(settings.window_seconds)
+                {
+                    *window_start = now;
+                    *calls = 0;
+                    *failures = 0;
+                }
+                *calls += 1;
+                if !success {
+                    *failures += 1;
+                }
+
+                if *calls >= settings.minimum_calls
+                    && *failures as f64 >= settings.failure_ratio * *calls as f64
+                {
+                    warn!(
+                        "Opening the circuit breaker for [{breaker}] for {}s: {failures} of the last {calls} calls failed",
+                        settings.open_seconds
+                    );
+                    *state = BreakerState::Open {
+                        until: now + Duration::from_secs(settings.open_seconds),
+                    };
+                }
+            }
+            // A call that was let through before the breaker opened
+            BreakerState::Open { .. } => (),
+            BreakerState::HalfOpen { probe } if admission != Admission::Probe(*probe) => (),
+            BreakerState::HalfOpen { .. } if success => {
+                info!("Closing the circuit breaker for [{breaker}]: the API has recovered");
+                *state = BreakerState::closed(now);
+            }
+            BreakerState::HalfOpen { .. } => {
+                warn!("Reopening the circuit breaker for [{breaker}]: the API is still failing");
+                *state = BreakerState::Open {
+                    until: now + Duration::from_secs(settings.open_seconds),
+                };
+            }
+        }
+    }
+}
+
+/// Reports the state of each circuit breaker and how many calls they rejected. States are
+/// read from the breakers at scrape time.
+pub struct CircuitBreakerMetrics {
+    breakers: Arc<CircuitBreakers>,
+    state: IntGaugeVec,
+}
+
+impl CircuitBreakerMetrics {
+    pub fn register(handle: &MetricsHandle, breakers: Arc<CircuitBreakers>) {
+        let state = IntGaugeVec::new(
+            Opts::new(
+                "plaid_circuit_breaker_state",
+                "State of the circuit breaker: 0 when closed, 1 when half-open and 2 when open",
+            ),
+            &["breaker"],
+        )
+        .expect("valid metric definition");
+
+        handle
+            .register(Box::new(Self { breakers, state }))
+            .expect("expected unique collector");
+    }
+}
+
+impl Collector for CircuitBreakerMetrics {
+    fn desc(&self) -> Vec<&Desc> {
+        let mut descs = self.state.desc();
+        descs.extend(self.breakers.rejected_calls.desc());
+        descs
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        for (breaker, state) in self.breakers.lock().iter() {
+            self.state
+                .with_label_values(&[breaker])
+                .set(state.metric_value());
+        }
+
+        let mut families = self.state.collect();
+        families.extend(self.breakers.rejected_calls.collect());
+        families
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakers() -> CircuitBreakers {
+        CircuitBreakers::new(CircuitBreakerConfig {
+            defaults: CircuitBreakerSettings {
+                failure_ratio: 0.5,
+                minimum_calls: 4,
+                window_seconds: 60,
+                open_seconds: 30,
+            },
+            overrides: HashMap::new(),
+        })
+    }
+
+    fn allow(breakers: &CircuitBreakers, breaker: &str, now: Instant) -> bool {
+        breakers.allow_at(breaker, now).is_some()
+    }
+
+    /// Fail enough calls to open the breaker at `now`
+    fn open(breakers: &CircuitBreakers, now: Instant) {
+        for _ in 0..4 {
+            assert_eq!(breakers.allow_at("github", now), Some(Admission::Counted));
+            breakers.record_at("github", Admission::Counted, false, now);
+        }
+        assert!(!allow(breakers, "github", now));
+    }
+
+    #[test]
+    fn breaker_opens_then_half_opens_and_closes() {
+        let breakers = breakers();
+        let start = Instant::now();
+
+        // Failures below the minimum number of calls do not open the breaker
+        for _ in 0..3 {
+            assert!(allow(&breakers, "github", start));
+            breakers.record_at("github", Admission::Counted, false, start);
+        }
+        assert!(allow(&breakers, "github", start));
+        breakers.record_at("github", Admission::Counted, true, start);
+        assert!(!allow(&breakers, "github", start));
+        // Other breakers are not affected
+        assert!(allow(&breakers, "slack", start));
+
+        // Once open long enough, a single call is let through
+        let later = start + Duration::from_secs(30);
+        let Some(probe) = breakers.allow_at("github", later) else {
+            panic!("the probe was not let through");
+        };
+        assert!(!allow(&breakers, "github", later));
+
+        // The probe fails, so the breaker opens again
+        breakers.record_at("github", probe, false, later);
+        assert!(!allow(&breakers, "github", later + Duration::from_secs(29)));
+
+        // The next probe succeeds, so the breaker closes
+        let recovered = later + Duration::from_secs(30);
+        let Some(probe) = breakers.allow_at("github", recovered) else {
+            panic!("the probe was not let through");
+        };
+        breakers.record_at("github", probe, true, recovered);
+        assert_eq!(breakers.lock()["github"], BreakerState::closed(recovered));
+    }
+
+    #[test]
+    fn only_the_probe_decides_whether_a_half_open_breaker_closes() {
+        let breakers = breakers();
+        let start = Instant::now();
+        // A slow call is let through before the breaker opens
+        assert_eq!(breakers.allow_at("github", start), Some(Admission::Counted));
+        open(&breakers, start);
+
+        let later = start + Duration::from_secs(30);
+        let Some(probe) = breakers.allow_at("github", later) else {
+            panic!("the probe was not let through");
+        };
+        // The slow call finishing neither closes nor reopens the breaker
+        breakers.record_at("github", Admission::Counted, true, later);
+        breakers.record_at("github", Admission::Counted, false, later);
+        assert!(!allow(&breakers, "github", later));
+
+        // Nor does the probe of an earlier half-open period
+        breakers.record_at("github", Admission::Probe(u64::MAX), true, later);
+        assert!(!allow(&breakers, "github", later));
+
+        breakers.record_at("github", probe, true, later);
+        assert!(allow(&breakers, "github", later));
+    }
+
+    #[test]
+    fn failures_are_counted_per_window() {
+        let breakers = breakers();
+        let start = Instant::now();
+        for _ in 0..3 {
+            breakers.record_at("github", Admission::Counted, false, start);
+        }
+        // The window is over, so only the last failure counts
+        let later = start + Duration::from_secs(60);
+        breakers.record_at("github", Admission::Counted, false, later);
+        assert!(allow(&breakers, "github", later));
+    }
+
+    #[test]
+    fn named_requests_have_their_own_breaker() {
+        assert_eq!(
+            CircuitBreakers::breaker_name(
+                "general",
+                "general_make_named_request",
+                r#"{"request_name": "test-response", "body": ""}"#
+            ),
+            "named_request.test-response"
+        );
+        assert_eq!(
+            CircuitBreakers::breaker_name("general", "general_simple_json_post_request", "{}"),
+            "general"
+        );
+    }
+}
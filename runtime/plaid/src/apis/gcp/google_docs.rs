@@ -495,12 +495,28 @@ mod tests {
             engine: engine.into(),
             computation_limit: 0,
             page_limit: 0,
+            execution_timeout: None,
             storage_current: Default::default(),
             storage_limit: LimitValue::Unlimited,
             accessory_data: Default::default(),
             secrets: Default::default(),
             persistent_response: Default::default(),
             test_mode,
+            bytes_hash: Default::default(),
+            config_hash: Default::default(),
+            retry_policy: None,
+            filter: None,
+            instance_pool: None,
+            throttle: None,
+            custom_metric_series_limit: 100,
+            log_rate_limiter: crate::executor::RateLimiter::new(&crate::executor::RateLimit {
+                executions: std::num::NonZeroU32::new(100).unwrap(),
+                period_secs: 60,
+            }),
+            invocable_modules: vec![],
+            shadow: None,
+            test_mode_journal: None,
+            batching: None,
         })
     }
 
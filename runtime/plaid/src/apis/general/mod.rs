@@ -5,9 +5,9 @@ mod random;
 use reqwest::{redirect, Client};
 use ring::rand::SystemRandom;
 use serde::Deserialize;
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
-use super::default_timeout_seconds;
+use super::{default_timeout_seconds, rate_limit::OutboundRateLimiter};
 
 #[derive(Deserialize)]
 pub struct GeneralConfig {
@@ -27,6 +27,8 @@ pub struct General {
     clients: Clients,
     /// Secure random generator
     system_random: SystemRandom,
+    /// Limits the rate of named requests
+    rate_limiter: Arc<OutboundRateLimiter>,
 }
 
 /// Holds the default HTTP client plus any named clients with per-request customizations.
@@ -110,7 +112,7 @@ impl Clients {
 }
 
 impl General {
-    pub fn new(config: GeneralConfig) -> Self {
+    pub fn new(config: GeneralConfig, rate_limiter: Arc<OutboundRateLimiter>) -> Self {
         let clients = Clients::new(&config);
         let system_random = SystemRandom::new();
 
@@ -118,6 +120,13 @@ impl General {
             config,
             clients,
             system_random,
+            rate_limiter,
         }
     }
 }
+
+/// Get the name of the request a module asked for in the parameters to `general_make_named_request`
+pub(crate) fn named_request_name(params: &str) -> Option<String> {
+    let params: serde_json::Value = serde_json::from_str(params).ok()?;
+    params.get("request_name")?.as_str().map(str::to_string)
+}
@@ -10,6 +10,7 @@ use serde::{
 };
 
 use crate::apis::ApiError;
+use crate::executor::RateLimit;
 
 use super::General;
 
@@ -78,6 +79,9 @@ pub struct Request {
     pub enable_redirects: bool,
     /// The max size for the response body. If none is provided, default to no limit.
     pub max_response_size: Option<usize>,
+    /// How often the request can be made, by all modules together. If none is provided,
+    /// the request is only limited when the server says so.
+    pub rate_limit: Option<RateLimit>,
 }
 
 /// Deserialize a non‐zero timeout (1–255 seconds) into a `Duration`, erroring on 0.
@@ -246,8 +250,13 @@ impl General {
             None => request.body,
         };
 
+        self.rate_limiter
+            .acquire("named_request", Some(request_name))
+            .await?;
         match request_builder.body(body).send().await {
             Ok(r) => {
+                self.rate_limiter
+                    .observe("named_request", Some(request_name), r.headers());
                 let mut ret = DynamicWebRequestResponse {
                     code: None,
                     data: None,
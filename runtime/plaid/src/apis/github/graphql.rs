@@ -63,10 +63,16 @@ impl Github {
             )))
         })?;
 
+        let credential = client_id.to_string();
+        self.rate_limiter
+            .acquire("github", Some(&credential))
+            .await?;
         let request = client._post(GITHUB_GQL_API, Some(&query)).await;
 
         match request {
             Ok(r) => {
+                self.rate_limiter
+                    .observe("github", Some(&credential), r.headers());
                 if r.status() == 200 {
                     let body = client.body_to_string(r).await.map_err(|e| {
                         ApiError::GitHubError(GitHubError::GraphQLRequestError(e.to_string()))
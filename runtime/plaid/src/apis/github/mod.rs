@@ -24,7 +24,7 @@ use std::{collections::HashMap, fmt::Display, sync::Arc};
 
 use crate::loader::PlaidModule;
 
-use super::ApiError;
+use super::{rate_limit::OutboundRateLimiter, ApiError};
 
 #[derive(Deserialize, Clone)]
 #[serde(untagged)]
@@ -72,6 +72,8 @@ pub struct Github {
     clients: HashMap<String, Octocrab>,
     /// Validators used to check parameters passed by modules
     validators: HashMap<&'static str, regex::Regex>,
+    /// Limits the rate of calls made with each client
+    rate_limiter: Arc<OutboundRateLimiter>,
 }
 
 /// All the errors that can be encountered while executing GitHub calls
@@ -88,7 +90,10 @@ pub enum GitHubError {
 }
 
 impl Github {
-    pub fn new(config: GithubConfig) -> Result<Self, ApiError> {
+    pub fn new(
+        config: GithubConfig,
+        rate_limiter: Arc<OutboundRateLimiter>,
+    ) -> Result<Self, ApiError> {
         let clients = build_github_clients(&config.authentication)?;
 
         // Create all the validators and compile all the regexes. If the module contains
@@ -99,6 +104,7 @@ impl Github {
             config,
             clients,
             validators,
+            rate_limiter,
         })
     }
 
@@ -147,10 +153,16 @@ impl Github {
             )))
         })?;
 
+        let credential = client_id.to_string();
+        self.rate_limiter
+            .acquire("github", Some(&credential))
+            .await?;
         let request = client._get_with_headers(uri, headers).await;
 
         match request {
             Ok(r) => {
+                self.rate_limiter
+                    .observe("github", Some(&credential), r.headers());
                 let status = r.status().as_u16();
                 let body = client.body_to_string(r).await.map_err(|e| {
                     ApiError::GitHubError(GitHubError::GraphQLRequestError(e.to_string()))
@@ -181,10 +193,16 @@ impl Github {
             )))
         })?;
 
+        let credential = client_id.to_string();
+        self.rate_limiter
+            .acquire("github", Some(&credential))
+            .await?;
         let request = client._post(uri, Some(&body)).await;
 
         match request {
             Ok(r) => {
+                self.rate_limiter
+                    .observe("github", Some(&credential), r.headers());
                 let status = r.status().as_u16();
                 let body = client.body_to_string(r).await.map_err(|e| {
                     ApiError::GitHubError(GitHubError::GraphQLRequestError(e.to_string()))
@@ -215,10 +233,16 @@ impl Github {
             )))
         })?;
 
+        let credential = client_id.to_string();
+        self.rate_limiter
+            .acquire("github", Some(&credential))
+            .await?;
         let request = client._put(uri, body).await;
 
         match request {
             Ok(r) => {
+                self.rate_limiter
+                    .observe("github", Some(&credential), r.headers());
                 let status = r.status().as_u16();
                 let body = client.body_to_string(r).await.map_err(|e| {
                     ApiError::GitHubError(GitHubError::GraphQLRequestError(e.to_string()))
@@ -249,10 +273,16 @@ impl Github {
             )))
         })?;
 
+        let credential = client_id.to_string();
+        self.rate_limiter
+            .acquire("github", Some(&credential))
+            .await?;
         let request = client._patch(uri, body).await;
 
         match request {
             Ok(r) => {
+                self.rate_limiter
+                    .observe("github", Some(&credential), r.headers());
                 let status = r.status().as_u16();
                 let body = client.body_to_string(r).await.map_err(|e| {
                     ApiError::GitHubError(GitHubError::GraphQLRequestError(e.to_string()))
@@ -283,10 +313,16 @@ impl Github {
             )))
         })?;
 
+        let credential = client_id.to_string();
+        self.rate_limiter
+            .acquire("github", Some(&credential))
+            .await?;
         let request = client._delete(uri, body).await;
 
         match request {
             Ok(r) => {
+                self.rate_limiter
+                    .observe("github", Some(&credential), r.headers());
                 let status = r.status().as_u16();
                 let body = client.body_to_string(r).await.map_err(|e| {
                     ApiError::GitHubError(GitHubError::GraphQLRequestError(e.to_string()))
@@ -2,6 +2,8 @@
 pub mod aws;
 pub mod blockchain;
 pub mod bloom_filter;
+pub mod canned;
+pub mod circuit_breaker;
 pub mod cryptography;
 #[cfg(feature = "gcp")]
 pub mod gcp;
@@ -11,6 +13,7 @@ pub mod jira;
 pub mod npm;
 pub mod okta;
 pub mod pagerduty;
+pub mod rate_limit;
 pub mod rustica;
 pub mod slack;
 pub mod splunk;
@@ -21,6 +24,8 @@ pub mod yubikey;
 use crate::apis::aws::kms::KmsErrors;
 use crate::apis::blockchain::{Blockchain, BlockchainConfig, BlockchainError};
 use crate::apis::bloom_filter::BloomFilter;
+use crate::apis::canned::{CannedResponse, CannedResponses};
+use crate::apis::circuit_breaker::{BreakerCall, CircuitBreakerConfig, CircuitBreakers};
 #[cfg(feature = "gcp")]
 use crate::apis::gcp::{Gcp, GcpConfig};
 use crate::apis::jira::{Jira, JiraConfig};
@@ -47,15 +52,19 @@ use npm::{Npm, NpmConfig};
 use okta::{Okta, OktaConfig};
 use pagerduty::{PagerDuty, PagerDutyConfig};
 use plaid_stl::npm::shared_structs::NpmError;
+use rate_limit::{OutboundRateLimitConfig, OutboundRateLimiter};
 use serde::Deserialize;
 use slack::{Slack, SlackConfig};
 use splunk::{Splunk, SplunkConfig};
+use std::sync::Arc;
 use tokio::runtime::Runtime;
 use web::{Web, WebConfig};
 use yubikey::{Yubikey, YubikeyConfig};
 
 use self::rustica::{Rustica, RusticaConfig};
 use crate::apis::cryptography::{Cryptography, CryptographyConfig};
+use crate::functions::FunctionErrors;
+use crate::recording::MessageRecorder;
 
 /// All the APIs that Plaid can use
 pub struct Api {
@@ -78,10 +87,17 @@ pub struct Api {
     pub web: Option<Web>,
     pub blockchain: Option<Blockchain>,
     pub bloom_filter: Option<BloomFilter>,
+    /// Responses to answer API calls with instead of calling the APIs. This is only set by
+    /// the [`TestRuntime`](crate::testing::TestRuntime) and when replaying recorded messages.
+    pub canned_responses: Option<Arc<CannedResponses>>,
+    /// The circuit breakers in front of the APIs, if they are configured
+    pub circuit_breakers: Option<Arc<CircuitBreakers>>,
+    /// Records the responses the APIs give to modules processing recorded messages
+    pub recorder: Option<Arc<MessageRecorder>>,
 }
 
-/// Configurations for all the APIs Plaid can use
-#[derive(Deserialize)]
+/// Configurations for all the APIs Plaid can use. The default configures none of them.
+#[derive(Default, Deserialize)]
 pub struct ApiConfigs {
     #[cfg(feature = "aws")]
     pub aws: Option<AwsConfig>,
@@ -101,6 +117,8 @@ pub struct ApiConfigs {
     pub yubikey: Option<YubikeyConfig>,
     pub web: Option<WebConfig>,
     pub blockchain: Option<BlockchainConfig>,
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    pub rate_limits: Option<OutboundRateLimitConfig>,
 }
 
 #[derive(Debug)]
@@ -147,6 +165,19 @@ pub enum ApiError {
     TlsError(String),
     BloomFilterError(String),
     CouldNotInstatiateRuntime(String),
+    /// The call was not made because this limit was hit
+    RateLimited(String),
+}
+
+impl ApiError {
+    /// Whether this error means the API is failing, rather than the call being refused before
+    /// it was made. Only these errors count towards opening a circuit breaker.
+    pub(crate) fn is_integration_failure(&self) -> bool {
+        !matches!(
+            self,
+            Self::TestMode | Self::BadRequest | Self::MissingParameter(_) | Self::RateLimited(_)
+        )
+    }
 }
 
 impl From<BlockchainError> for ApiError {
@@ -205,13 +236,28 @@ impl Api {
             _ => None,
         };
 
+        // Calls to all the APIs go through the same limiter, so modules sharing a credential
+        // share its rate limit
+        let named_request_limits: Vec<_> = config
+            .general
+            .iter()
+            .flat_map(|general| general.network.web_requests.iter())
+            .filter_map(|(name, request)| {
+                Some((format!("named_request.{name}"), request.rate_limit.clone()?))
+            })
+            .collect();
+        let rate_limiter = Arc::new(OutboundRateLimiter::new(
+            config.rate_limits.unwrap_or_default(),
+            named_request_limits,
+        ));
+
         let general = match config.general {
-            Some(gc) => Some(General::new(gc)),
+            Some(gc) => Some(General::new(gc, rate_limiter.clone())),
             _ => None,
         };
 
         let github = match config.github {
-            Some(gh) => Some(Github::new(gh)?),
+            Some(gh) => Some(Github::new(gh, rate_limiter.clone())?),
             _ => None,
         };
 
@@ -253,7 +299,7 @@ impl Api {
         };
 
         let slack = match config.slack {
-            Some(sc) => Some(Slack::new(sc)),
+            Some(sc) => Some(Slack::new(sc, rate_limiter.clone())),
             _ => None,
         };
 
@@ -294,8 +340,38 @@ impl Api {
             splunk,
             yubikey,
             web,
+            canned_responses: None,
+            circuit_breakers: config
+                .circuit_breaker
+                .map(|config| Arc::new(CircuitBreakers::new(config))),
+            recorder: None,
         })
     }
+
+    /// Get the canned response to a call of `function` with `params`, if canned responses are
+    /// configured and one is set for this call
+    pub(crate) fn canned_response(&self, function: &str, params: &str) -> Option<CannedResponse> {
+        self.canned_responses.as_ref()?.respond(function, params)
+    }
+
+    /// Get through the circuit breaker in front of a call of `function`, which belongs to `api`.
+    /// Returns `None` if circuit breakers are not configured, and `FunctionErrors::CircuitOpen`
+    /// if the breaker is open and the call must not be made.
+    pub(crate) fn circuit_breaker_call(
+        &self,
+        api: &str,
+        function: &str,
+        params: &str,
+    ) -> Result<Option<BreakerCall<'_>>, FunctionErrors> {
+        let Some(breakers) = &self.circuit_breakers else {
+            return Ok(None);
+        };
+        let breaker = CircuitBreakers::breaker_name(api, function, params);
+        breakers
+            .call(&breaker)
+            .map(Some)
+            .ok_or(FunctionErrors::CircuitOpen)
+    }
 }
 
 /// This function provides the default timeout value in seconds.
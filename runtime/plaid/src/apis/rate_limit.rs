@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use http::HeaderMap;
+use serde::Deserialize;
+
+use super::ApiError;
+use crate::executor::{RateLimit, RateLimiter};
+
+/// Configuration for limiting the rate of calls Plaid makes to the APIs, across all modules
+#[derive(Deserialize)]
+pub struct OutboundRateLimitConfig {
+    /// How long, in seconds, a call waits for its turn before it fails. Defaults to 10.
+    #[serde(default = "default_max_wait_seconds")]
+    pub max_wait_seconds: u64,
+    /// The limits, keyed by API (like `slack`) or by credential (like `slack.<bot>`). A limit
+    /// for a credential takes precedence over the limit for its API, which is shared by all
+    /// the credentials without a limit of their own.
+    /// The mapping is `{limit -> rate limit}`
+    #[serde(default)]
+    pub limits: HashMap<String, RateLimit>,
+}
+
+fn default_max_wait_seconds() -> u64 {
+    10
+}
+
+impl Default for OutboundRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            max_wait_seconds: default_max_wait_seconds(),
+            limits: HashMap::new(),
+        }
+    }
+}
+
+/// Limits the rate of calls to the APIs, and backs off the credentials that the APIs say are
+/// rate limited. This is shared by all the APIs.
+pub struct OutboundRateLimiter {
+    max_wait: Duration,
+    limiters: HashMap<String, RateLimiter>,
+    /// The credentials the APIs told us to back off, and until when
+    backoffs: Mutex<HashMap<String, Instant>>,
+}
+
+impl OutboundRateLimiter {
+    /// Create the limiter from the configured limits and the limits set on named requests,
+    /// which are keyed by `named_request.<name>`
+    pub fn new(
+        config: OutboundRateLimitConfig,
+        named_request_limits: impl IntoIterator<Item = (String, RateLimit)>,
+    ) -> Self {
+        let limiters = config
+            .limits
+            .into_iter()
+            .chain(named_request_limits)
+            .map(|(limit, rate_limit)| (limit, RateLimiter::new(&rate_limit)))
+            .collect();
+
+        Self {
+            max_wait: Duration::from_secs(config.max_wait_seconds),
+            limiters,
+            backoffs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait until a call to `api` with `credential` can be made. Fails with
+    /// `ApiError::RateLimited` if that would take longer than the configured maximum wait.
+    pub async fn acquire(&self, api: &str, credential: Option<&str>) -> Result<(), ApiError> {
+        let key = limit_key(api, credential);
+        let limiter = self.limiters.get(&key).or_else(|| self.limiters.get(api));
+        let deadline = Instant::now() + self.max_wait;
+
+        loop {
+            let now = Instant::now();
+            let backoff = self
+                .lock_backoffs()
+                .get(&key)
+                .and_then(|until| until.checked_duration_since(now));
+            let wait = match (backoff, limiter) {
+                (Some(backoff), _) if !backoff.is_zero() => backoff,
+                (_, Some(limiter)) => match limiter.try_take() {
+                    Ok(()) => return Ok(()),
+                    Err(wait) => wait,
+                },
+                (_, None) => return Ok(()),
+            };
+
+            if now + wait > deadline {
+                warn!("Not calling [{key}]: it is rate limited for another {wait:?}");
+                return Err(ApiError::RateLimited(key));
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Look at the headers of a response from `api` to a call with `credential`, and back off
+    /// the credential if the API says it is rate limited
+    pub fn observe(&self, api: &str, credential: Option<&str>, headers: &HeaderMap) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let Some(backoff) = backoff_from_headers(headers, now) else {
+            return;
+        };
+
+        let key = limit_key(api, credential);
+        info!("[{key}] is rate limited, backing off for {backoff:?}");
+        let until = Instant::now() + backoff;
+        let mut backoffs = self.lock_backoffs();
+        let current = backoffs.entry(key).or_insert(until);
+        *current = (*current).max(until);
+    }
+
+    fn lock_backoffs(&self) -> std::sync::MutexGuard<'_, HashMap<String, Instant>> {
+        self.backoffs.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// The key of the limit for calls to `api` with `credential`
+fn limit_key(api: &str, credential: Option<&str>) -> String {
+    match credential {
+        Some(credential) => format!("{api}.{credential}"),
+        None => api.to_string(),
+    }
+}
+
+/// How long to back off for, according to the rate limit headers of a response. `now` is in
+/// seconds since the epoch.
+fn backoff_from_headers(headers: &HeaderMap, now: u64) -> Option<Duration> {
+    let number =
+        |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+
+    // Sent by Slack, and by GitHub for its secondary rate limits
+    if let Some(seconds) = number("retry-after") {
+        return Some(Duration::from_secs(seconds));
+    }
+    // Sent by GitHub, with the time the limit resets in seconds since the epoch
+    if number("x-ratelimit-remaining") == Some(0) {
+        let reset = number("x-ratelimit-reset")?;
+        return Some(Duration::from_secs(reset.saturating_sub(now)));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (http::HeaderName::from_static(name), value.parse().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn backoff_is_read_from_headers() {
+        assert_eq!(
+            backoff_from_headers(&headers(&[("retry-after", "30")]), 0),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            backoff_from_headers(
+                &headers(&[
+                    ("x-ratelimit-remaining", "0"),
+                    ("x-ratelimit-reset", "1060")
+                ]),
+                1000
+            ),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            backoff_from_headers(
+                &headers(&[
+                    ("x-ratelimit-remaining", "10"),
+                    ("x-ratelimit-reset", "1060")
+                ]),
+                1000
+            ),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn credential_limits_take_precedence() {
+        let limit = |executions| RateLimit {
+            executions: NonZeroU32::new(executions).unwrap(),
+            period_secs: 3600,
+        };
+        let limiter = OutboundRateLimiter::new(
+            OutboundRateLimitConfig {
+                max_wait_seconds: 0,
+                limits: HashMap::from([
+                    ("slack".to_string(), limit(1)),
+                    ("slack.alerts".to_string(), limit(2)),
+                ]),
+            },
+            [("named_request.hook".to_string(), limit(1))],
+        );
+
+        assert!(limiter.acquire("slack", Some("alerts")).await.is_ok());
+        assert!(limiter.acquire("slack", Some("alerts")).await.is_ok());
+        assert!(limiter.acquire("slack", Some("alerts")).await.is_err());
+        // Other bots share the limit for the API
+        assert!(limiter.acquire("slack", Some("other")).await.is_ok());
+        assert!(limiter.acquire("slack", Some("another")).await.is_err());
+        assert!(limiter.acquire("named_request", Some("hook")).await.is_ok());
+        assert!(limiter
+            .acquire("named_request", Some("hook"))
+            .await
+            .is_err());
+        // APIs without a limit are not limited, unless they say so
+        assert!(limiter.acquire("github", Some("main")).await.is_ok());
+        limiter.observe("github", Some("main"), &headers(&[("retry-after", "30")]));
+        assert!(limiter.acquire("github", Some("main")).await.is_err());
+        assert!(limiter.acquire("github", Some("other")).await.is_ok());
+    }
+}
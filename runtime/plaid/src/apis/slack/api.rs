@@ -177,8 +177,11 @@ impl Slack {
             .build_request(&self.client)
             .header("Authorization", format!("Bearer {}", self.get_token(&bot)?));
 
+        self.rate_limiter.acquire("slack", Some(&bot)).await?;
         info!("Calling [{api}] using bot: [{bot}] on behalf of: [{module}]");
         let resp = r.send().await.map_err(|e| ApiError::NetworkError(e))?;
+        self.rate_limiter
+            .observe("slack", Some(&bot), resp.headers());
         let status = resp.status();
         let response = resp.text().await.unwrap_or_default();
         trace!("Slack returned: {status}: {response}");
@@ -8,8 +8,9 @@ use serde::Deserialize;
 use std::time::Duration;
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use super::default_timeout_seconds;
+use super::{default_timeout_seconds, rate_limit::OutboundRateLimiter};
 
 #[derive(Deserialize)]
 pub struct SlackConfig {
@@ -30,6 +31,8 @@ pub struct Slack {
     config: SlackConfig,
     /// A client to make requests with
     client: Client,
+    /// Limits the rate of calls made with each bot and webhook
+    rate_limiter: Arc<OutboundRateLimiter>,
 }
 
 #[derive(Debug)]
@@ -41,12 +44,16 @@ pub enum SlackError {
 }
 
 impl Slack {
-    pub fn new(config: SlackConfig) -> Self {
+    pub fn new(config: SlackConfig, rate_limiter: Arc<OutboundRateLimiter>) -> Self {
         let client = reqwest::Client::builder()
             .timeout(Duration::from_secs(config.api_timeout_seconds))
             .build()
             .unwrap();
 
-        Self { config, client }
+        Self {
+            config,
+            client,
+            rate_limiter,
+        }
     }
 }
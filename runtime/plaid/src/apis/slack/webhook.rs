@@ -26,10 +26,13 @@ impl Slack {
             .ok_or(ApiError::MissingParameter("body".to_string()))?
             .to_string();
 
+        self.rate_limiter.acquire("slack_webhook", None).await?;
         let address = format!("https://hooks.slack.com/services/{}", hook);
 
         match self.client.post(address).body(body).send().await {
             Ok(r) => {
+                self.rate_limiter
+                    .observe("slack_webhook", None, r.headers());
                 let status = r.status();
                 if status == 200 {
                     Ok(0)
@@ -70,11 +73,16 @@ impl Slack {
                 hook_name.clone(),
             )))?;
 
+        self.rate_limiter
+            .acquire("slack_webhook", Some(&hook_name))
+            .await?;
         info!("Sending a message to a Slack webhook: {hook_name} on behalf of: {module}");
         let address = format!("https://hooks.slack.com/services/{}", hook);
 
         match self.client.post(address).body(body).send().await {
             Ok(r) => {
+                self.rate_limiter
+                    .observe("slack_webhook", Some(&hook_name), r.headers());
                 let status = r.status();
                 if status == 200 {
                     Ok(0)
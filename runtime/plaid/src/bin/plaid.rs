@@ -11,17 +11,22 @@ use plaid::{
         CachingMode, ConfigurationWithRoles, GetMode, ResponseMode, WebhookConfig,
         WebhookServerConfiguration,
     },
-    loader::PlaidModule,
+    loader::LiveModules,
     logging::Logger,
+    trace::TraceContext,
     *,
 };
 
-use apis::Api;
-use data::Data;
+use apis::{circuit_breaker::CircuitBreakerMetrics, Api};
+use data::{Data, ScheduledLogbacks};
+use dead_letter::{DeadLetterError, DeadLetterQueue, DeadLetterSummary};
+use deduplication::{DeduplicationBackend, WebhookDeduplicator};
 use executor::metrics::{ModuleExecutionMetrics, QueueMetrics};
 use executor::*;
+use filters::FilterInput;
 use plaid::metrics::MetricsHandle;
 use plaid_stl::messages::LogSource;
+use recording::MessageRecorder;
 use storage::Storage;
 use tokio::{
     signal::{
@@ -32,6 +37,7 @@ use tokio::{
 };
 use tokio::{sync::RwLock, task::JoinSet};
 use tokio_util::{bytes::Buf, sync::CancellationToken};
+use write_ahead_log::{WriteAheadLog, WriteAheadLogConfig};
 
 use std::{
     collections::HashMap,
@@ -41,7 +47,7 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use crossbeam_channel::TrySendError;
@@ -73,6 +79,8 @@ async fn post_handler(
     headers: HeaderMap,
     webhooks: HashMap<String, WebhookConfig>,
     exec: Arc<Executor>,
+    deduplicator: Arc<WebhookDeduplicator>,
+    write_ahead_log: Option<Arc<WriteAheadLog>>,
 ) -> impl warp::Reply {
     // The status code we'll return. Defaults to 200, but is bumped to 429 if the
     // execution system's bounded queue is full so the sender can back off and retry.
@@ -106,6 +114,15 @@ async fn post_handler(
             logbacks_allowed,
         );
 
+        // Continue the trace of the sender, if it started one
+        if let Some(trace) = headers
+            .get("traceparent")
+            .and_then(|value| value.to_str().ok())
+            .and_then(TraceContext::from_traceparent)
+        {
+            message.trace = trace;
+        }
+
         for requested_header in webhook_configuration.headers.iter() {
             // TODO: Investigate if this should be get_all?
             // Without this we don't support receiving multiple headers with the same name
@@ -117,14 +134,86 @@ async fn post_handler(
             }
         }
 
+        // Drop the log if it is not of interest to anyone, and send it to the right
+        // logging channel if the webhook is split into several.
+        let filter_input = FilterInput::new(&message);
+        if let Some(filter) = &webhook_configuration.filter {
+            if !filter.matches(&filter_input) {
+                debug!("Log for webhook [{webhook}] did not match its filter, dropping it");
+                return Box::new(warp::reply::with_status(warp::reply(), status));
+            }
+        }
+        let routed_log_type = webhook_configuration
+            .routes
+            .iter()
+            .find(|route| route.filter.matches(&filter_input))
+            .map(|route| route.log_type.clone());
+        if let Some(log_type) = routed_log_type {
+            message.type_ = log_type;
+        }
+
+        // Drop the log if the webhook already received it, e.g. because the sender retried it
+        let deduplication = webhook_configuration
+            .deduplication
+            .as_ref()
+            .and_then(|config| {
+                let key = config.key(&message.data, |name| {
+                    headers
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .map(str::to_string)
+                })?;
+                Some((config, key))
+            });
+        if let Some((config, key)) = &deduplication {
+            match deduplicator.check_and_record(&webhook, key, config).await {
+                Ok(true) => {
+                    info!("Log for webhook [{webhook}] with key [{key}] was already received, dropping it");
+                    return Box::new(warp::reply::with_status(warp::reply(), status));
+                }
+                Ok(false) => (),
+                // Processing a log twice is better than not processing it at all
+                Err(e) => error!(
+                    "Could not check whether log for webhook [{webhook}] was already received: {e}"
+                ),
+            }
+        }
+
+        // Persist the log before acknowledging it, so it is processed even if Plaid stops
+        // before then
+        let write_ahead_log = write_ahead_log.filter(|_| !exec.drops_log_type(&message.type_));
+        if let Some(write_ahead_log) = &write_ahead_log {
+            if let Err(e) = write_ahead_log.append(&message).await {
+                error!("Could not persist log for webhook [{webhook}], rejecting it: {e}");
+                if let Some((config, key)) = &deduplication {
+                    if let Err(e) = deduplicator.forget(&webhook, key, config).await {
+                        error!(
+                            "Could not forget log for webhook [{webhook}] with key [{key}]: {e}"
+                        );
+                    }
+                }
+                return Box::new(warp::reply::with_status(
+                    warp::reply(),
+                    StatusCode::SERVICE_UNAVAILABLE,
+                ));
+            }
+        }
+
         // Webhook exists, buffer log
         if let Err(e) = exec.execute_webhook_message(message) {
             match e {
-                TrySendError::Full(_) => {
-                    error!(
-                        "Queue Full! [{}] log dropped!",
-                        webhook_configuration.log_type
-                    );
+                TrySendError::Full(message) => {
+                    error!("Queue Full! [{}] log dropped!", message.type_);
+                    // The sender will retry the log, so it must not be replayed as well
+                    if let Some(write_ahead_log) = &write_ahead_log {
+                        write_ahead_log.complete(&message.id).await;
+                    }
+                    // The sender will retry the log, which must not be dropped as a duplicate
+                    if let Some((config, key)) = &deduplication {
+                        if let Err(e) = deduplicator.forget(&webhook, key, config).await {
+                            error!("Could not forget log for webhook [{webhook}] with key [{key}]: {e}");
+                        }
+                    }
                     // The bounded queue to the execution system is full: signal
                     // backpressure to the caller instead of silently dropping the log.
                     status = StatusCode::TOO_MANY_REQUESTS;
@@ -216,6 +305,134 @@ fn probe_routes(
     ready.or(live).unify()
 }
 
+/// Routes of the dead-letter admin endpoint:
+/// * `GET /dead_letters` lists the dead letters
+/// * `POST /dead_letters/replay/{id}` replays one dead letter
+/// * `POST /dead_letters/replay` replays all dead letters, or only a module's if `?module=` is given
+fn dead_letter_routes(
+    dead_letters: Arc<DeadLetterQueue>,
+    modules: Arc<LiveModules>,
+    executor: Arc<Executor>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let list = warp::path!("dead_letters")
+        .and(warp::get())
+        .and(with(dead_letters.clone()))
+        .then(|dead_letters: Arc<DeadLetterQueue>| async move {
+            match dead_letters.list().await {
+                Ok(dead_letters) => warp::reply::with_status(
+                    warp::reply::json(
+                        &dead_letters
+                            .iter()
+                            .map(DeadLetterSummary::from)
+                            .collect::<Vec<_>>(),
+                    ),
+                    StatusCode::OK,
+                ),
+                Err(e) => {
+                    error!("Failed to list dead letters: {e}");
+                    dead_letter_error_reply(e)
+                }
+            }
+        });
+
+    let replay_one = warp::path!("dead_letters" / "replay" / String)
+        .and(warp::post())
+        .and(with(dead_letters.clone()))
+        .and(with(modules.clone()))
+        .and(with(executor.clone()))
+        .then(
+            |id: String,
+             dead_letters: Arc<DeadLetterQueue>,
+             modules: Arc<LiveModules>,
+             executor: Arc<Executor>| async move {
+                match dead_letters.replay(&id, &modules, &executor).await {
+                    Ok(()) => warp::reply::with_status(
+                        warp::reply::json(&serde_json::json!({ "replayed": 1 })),
+                        StatusCode::OK,
+                    ),
+                    Err(e) => {
+                        error!("Failed to replay dead letter [{id}]: {e}");
+                        dead_letter_error_reply(e)
+                    }
+                }
+            },
+        );
+
+    let replay_all = warp::path!("dead_letters" / "replay")
+        .and(warp::post())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(with(dead_letters))
+        .and(with(modules))
+        .and(with(executor))
+        .then(
+            |query: HashMap<String, String>,
+             dead_letters: Arc<DeadLetterQueue>,
+             modules: Arc<LiveModules>,
+             executor: Arc<Executor>| async move {
+                let module = query.get("module").map(String::as_str);
+                match dead_letters.replay_all(module, &modules, &executor).await {
+                    Ok((replayed, errors)) => {
+                        let failed = errors
+                            .iter()
+                            .map(|(id, e)| serde_json::json!({ "id": id, "error": e.to_string() }))
+                            .collect::<Vec<_>>();
+                        warp::reply::with_status(
+                            warp::reply::json(
+                                &serde_json::json!({ "replayed": replayed, "failed": failed }),
+                            ),
+                            StatusCode::OK,
+                        )
+                    }
+                    Err(e) => {
+                        error!("Failed to replay dead letters: {e}");
+                        dead_letter_error_reply(e)
+                    }
+                }
+            },
+        );
+
+    list.or(replay_one).unify().or(replay_all).unify()
+}
+
+/// Turn a dead-letter error into a reply for the admin endpoint
+fn dead_letter_error_reply(e: DeadLetterError) -> warp::reply::WithStatus<warp::reply::Json> {
+    let status = match e {
+        DeadLetterError::NotFound(_) => StatusCode::NOT_FOUND,
+        DeadLetterError::ModuleNotLoaded(_) => StatusCode::CONFLICT,
+        DeadLetterError::QueueFull | DeadLetterError::ExecutorUnavailable => {
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+        DeadLetterError::StorageError(_) | DeadLetterError::SerializationError(_) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    warp::reply::with_status(
+        warp::reply::json(&serde_json::json!({ "error": e.to_string() })),
+        status,
+    )
+}
+
+/// Send a message to the executor, waiting for room in its queues when they are full.
+/// Returns whether the message was queued.
+async fn requeue_message(executor: &Executor, mut message: Message) -> bool {
+    loop {
+        match executor.execute_webhook_message(message) {
+            Ok(()) => return true,
+            Err(TrySendError::Full(returned)) => {
+                message = returned;
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+            Err(TrySendError::Disconnected(returned)) => {
+                error!(
+                    "The execution system is no longer accepting messages, [{}] log is kept to be requeued later",
+                    returned.type_
+                );
+                return false;
+            }
+        }
+    }
+}
+
 async fn wait_for_shutdown_signal() {
     let mut sigterm = signal(SignalKind::terminate()).expect("Failed to install SIGTERM handler");
 
@@ -250,11 +467,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Plaid is booting up, please standby...");
 
     info!("Reading configuration");
-    let ConfigurationWithRoles { config, roles } = config::configure()?;
+    let ConfigurationWithRoles {
+        config,
+        roles,
+        config_folder,
+        secrets_path,
+    } = config::configure()?;
     info!("This is what this instance is running: {roles:?}");
 
     // Create thread pools for log execution
-    let exec_thread_pools = thread_pools::ExecutionThreadPools::new(&config.executor);
+    let exec_thread_pools = thread_pools::ExecutionThreadPools::new(&config.executor, &roles);
+    let shutdown_deadline = config
+        .executor
+        .shutdown_deadline_secs
+        .map(Duration::from_secs);
 
     let metrics = config
         .metrics
@@ -322,6 +548,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         None => Arc::new(Storage::new_in_memory()),
     };
 
+    if shutdown_deadline.is_some() && storage.is_none() {
+        warn!("No persistent storage system configured; logs still queued at the shutdown deadline will be lost");
+    }
+
     // Graceful shutdown handling
     let cancellation_token = CancellationToken::new();
     let is_ready = Arc::new(AtomicBool::new(false));
@@ -401,18 +631,36 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("Loading all the modules");
     // Load all the modules that form our Nanoservices and Plaid rules
-    let modules = Arc::new(
-        loader::load(&config.loading, storage.clone())
-            .await
-            .map_err(|_| Errors::FailedToLoadModules)?,
-    );
-    let modules_by_name = Arc::new(modules.get_modules());
+    let modules = loader::load(&config.loading, storage.clone())
+        .await
+        .map_err(|_| Errors::FailedToLoadModules)?;
 
     let modules_and_logtypes = modules.get_module_logtypes();
 
     let cache = Cache::new(modules_and_logtypes, config.cache).await?;
     let cache = Arc::new(cache);
 
+    // Everything that runs modules reads them from here, so that they can be hot reloaded
+    let live_modules = Arc::new(LiveModules::new(modules));
+
+    // Reload modules on SIGHUP or, if configured, when module_dir changes
+    spawn(loader::watch_for_module_changes(
+        Arc::new(config.loading),
+        Arc::new(move || {
+            match config::read_and_interpolate(&config_folder, &secrets_path, false) {
+                Ok(config) => Some(config.loading),
+                Err(e) => {
+                    error!("Failed to read the configuration while reloading modules, keeping the previous one. Error: {e}");
+                    None
+                }
+            }
+        }),
+        storage.clone(),
+        Some(cache.clone()),
+        live_modules.clone(),
+        cancellation_token.clone(),
+    ));
+
     // Print information about the threads we are starting
     info!(
         "Starting {} execution threads for general execution. Log queue size = {}",
@@ -431,6 +679,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         };
         info!("Starting {} {thread_or_threads} dedicated to log type [{log_type}]. Log queue size = {}", tp.num_threads, tp.sender.capacity().unwrap_or_default());
     }
+    match &exec_thread_pools.non_concurrent_pool {
+        Some(tp) => info!(
+            "Starting 1 thread dedicated to non-concurrent log types {:?}. Log queue size = {}",
+            exec_thread_pools.non_concurrent_log_types,
+            tp.sender.capacity().unwrap_or_default()
+        ),
+        None if !exec_thread_pools.non_concurrent_log_types.is_empty() => warn!(
+            "This instance is NOT running non-concurrent log types {:?}. Their logs will be dropped",
+            exec_thread_pools.non_concurrent_log_types
+        ),
+        None => {}
+    }
     // This sender provides an internal route to sending logs. This is what
     // powers the logback functions.
     let (delayed_log_sender, delayed_log_persister, mut dg_tasks) = Data::start(
@@ -445,13 +705,93 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .await?;
     info!("Configuring APIs for Modules");
     // Create the API that powers all the wrapped calls that modules can make
-    let api = Api::new(config.apis)
+    let mut api = Api::new(config.apis)
         .await
         .map_err(|e| Errors::FailedToStartApiSystem(e))?;
 
+    // Messages of the configured log types are recorded, along with the responses the APIs
+    // give to the modules processing them
+    let recorder = match &config.recording {
+        Some(recording_config) => {
+            info!(
+                "Recording messages of log types: {:?}",
+                recording_config.log_types.keys().collect::<Vec<_>>()
+            );
+            Some(Arc::new(MessageRecorder::new(
+                recording_config,
+                internal_storage.clone(),
+            )?))
+        }
+        None => None,
+    };
+    api.recorder = recorder.clone();
+
     // Create an Arc so all the handlers have access to our API object
     let api = Arc::new(api);
 
+    if let (Some(handle), Some(breakers)) = (&metrics, &api.circuit_breakers) {
+        CircuitBreakerMetrics::register(handle, breakers.clone());
+    }
+
+    let dead_letters = config.dead_letter.as_ref().map(|dead_letter_config| {
+        if storage.is_none() {
+            warn!("No persistent storage system configured; dead letters will be lost on shutdown");
+        }
+        info!(
+            "Dead-letter queue configured. Failed messages are kept for {} seconds",
+            dead_letter_config.retention_secs
+        );
+        let dead_letters = Arc::new(DeadLetterQueue::new(
+            dead_letter_config,
+            internal_storage.clone(),
+        ));
+        spawn(
+            dead_letters
+                .clone()
+                .purge_expired_periodically(cancellation_token.clone()),
+        );
+        dead_letters
+    });
+
+    // Logs that webhooks already received are recognized by their keys, kept in the storage
+    // or the cache
+    let deduplicator = Arc::new(WebhookDeduplicator::new(
+        internal_storage.clone(),
+        Some(cache.clone()),
+    ));
+    let deduplicates_in_storage = config
+        .webhooks
+        .values()
+        .flat_map(|server| server.webhooks.values())
+        .filter_map(|webhook| webhook.deduplication.as_ref())
+        .any(|deduplication| deduplication.backend == DeduplicationBackend::Storage);
+    if deduplicates_in_storage {
+        if storage.is_none() {
+            warn!("No persistent storage system configured; webhooks are only deduplicated until Plaid restarts");
+        }
+        spawn(
+            deduplicator
+                .clone()
+                .purge_expired_periodically(cancellation_token.clone()),
+        );
+    }
+
+    // Logs that webhooks received are persisted until every module has processed them
+    let write_ahead_log = match &config.write_ahead_log {
+        Some(write_ahead_log_config) => {
+            if matches!(write_ahead_log_config, WriteAheadLogConfig::Storage(_))
+                && storage.is_none()
+            {
+                warn!("No persistent storage system configured; the write-ahead log will be lost on shutdown");
+            }
+            Some(Arc::new(WriteAheadLog::new(
+                write_ahead_log_config,
+                internal_storage.clone(),
+            )?))
+        }
+        None => None,
+    };
+
     // Workers upgrade this weak ref per message so idle threads hold no Message senders.
     let immediate_dispatch = Arc::new(exec_thread_pools.general_pool.sender.clone());
 
@@ -459,20 +799,77 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // requests for handling some configured get requests.
     let (executor, executor_threads) = Executor::new(
         exec_thread_pools.clone(),
-        modules.get_channels(),
-        api,
-        storage,
-        Some(cache),
-        els.clone(),
-        performance_sender.clone(),
-        module_execution_metrics.clone(),
+        live_modules.clone(),
+        ExecutionContext {
+            api,
+            storage,
+            cache: Some(cache),
+            els: els.clone(),
+            performance_mode: performance_sender.clone(),
+            module_execution_metrics: module_execution_metrics.clone(),
+            delayed_log_sender: delayed_log_sender.clone(),
+            cancellation_token: cancellation_token.clone(),
+            dead_letters: dead_letters.clone(),
+            scheduled_logbacks: Arc::new(ScheduledLogbacks::new(internal_storage.clone())),
+        },
         Arc::downgrade(&immediate_dispatch),
-        delayed_log_sender.clone(),
-        cancellation_token.clone(),
+        recorder.clone(),
+        write_ahead_log.clone(),
     );
 
     let executor = Arc::new(executor);
 
+    // Replay the logs that were received but not processed before Plaid last stopped, and the
+    // ones that were still queued when it last shut down, before accepting new ones
+    if let Some(write_ahead_log) = &write_ahead_log {
+        let unfinished = write_ahead_log.unfinished().await?;
+        if !unfinished.is_empty() {
+            info!(
+                "Replaying {} unfinished logs from the write-ahead log",
+                unfinished.len()
+            );
+        }
+        // Logs stay in the write-ahead log until they are processed
+        for message in unfinished {
+            if !requeue_message(&executor, message).await {
+                break;
+            }
+        }
+    }
+    match requeue_persisted_messages(&internal_storage, |message| {
+        requeue_message(&executor, message)
+    })
+    .await
+    {
+        Ok(0) => (),
+        Ok(requeued) => {
+            info!("Requeued {requeued} logs that were queued when Plaid last shut down")
+        }
+        Err(e) => {
+            error!("Failed to requeue the logs that were queued when Plaid last shut down: {e}")
+        }
+    }
+
+    if let (Some(dead_letters), Some(listen_addr)) = (
+        dead_letters,
+        config
+            .dead_letter
+            .as_ref()
+            .and_then(|dead_letter_config| dead_letter_config.admin_listen_address),
+    ) {
+        let routes = dead_letter_routes(dead_letters, live_modules.clone(), executor.clone());
+        let token = cancellation_token.clone();
+        info!("Started dead-letter admin server at: {listen_addr}");
+        server_tasks.spawn(async move {
+            let (_, server) =
+                warp::serve(routes).bind_with_graceful_shutdown(listen_addr, async move {
+                    token.cancelled().await;
+                });
+            server.await;
+            info!("Dead-letter admin server shut down");
+        });
+    }
+
     if roles.webhooks {
         info!("Configured Webhook Servers");
         for (server_name, config) in config.webhooks {
@@ -489,6 +886,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .and(warp::header::headers_cloned())
                 .and(with(webhooks))
                 .and(with(exec.clone()))
+                .and(with(deduplicator.clone()))
+                .and(with(write_ahead_log.clone()))
                 .then(post_handler);
 
             // This is a cache for get requests that are configured to be cached
@@ -503,10 +902,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .and(warp::body::stream())
                 .and(warp::header::headers_cloned())
                 .and(with(webhook_config.clone()))
-                .and(with(modules_by_name.clone()))
+                .and(with(live_modules.clone()))
                 .and(with(get_cache.clone()))
                 .and(with(webhook_server_get_log_sender.clone()))
-                .and_then(|webhook: String, query: HashMap<String, String>, body, headers: HeaderMap, webhook_config: Arc<WebhookServerConfiguration>, modules: Arc<HashMap<String, Arc<PlaidModule>>>, get_cache: Arc<RwLock<HashMap<String, (u64, String)>>>, log_sender: crossbeam_channel::Sender<Message>| async move {
+                .and_then(|webhook: String, query: HashMap<String, String>, body, headers: HeaderMap, webhook_config: Arc<WebhookServerConfiguration>, modules: Arc<LiveModules>, get_cache: Arc<RwLock<HashMap<String, (u64, String)>>>, log_sender: PoolSender| async move {
                     if let Some(webhook_configuration) = webhook_config.webhooks.get(&webhook) {
                         match &webhook_configuration.get_mode {
                             // Note that CacheMode is elided here as there is no caching for static data
@@ -533,7 +932,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             // kind of reponse significantly more complex.
                             Some(GetMode{ response_mode: ResponseMode::Rule(name), caching_mode}) => {
                                 // Ensure that the rule configured to generated the GET response actually exists
-                                let rule = if let Some(rule) = modules.get(name) {
+                                let rule = if let Some(rule) = modules.current().get_module(name) {
                                     rule
                                 } else {
                                     warn!("Got a get request to {webhook} but the rule [{name}] configured to handle it does not exist");
@@ -607,6 +1006,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                     Some(response_send),
                                     Some(rule.clone()));
 
+                                // Continue the trace of the sender, if it started one
+                                if let Some(trace) = headers.get("traceparent").and_then(|value| value.to_str().ok()).and_then(TraceContext::from_traceparent) {
+                                    message.trace = trace;
+                                }
+
                                 // Configure headers
                                 for requested_header in webhook_configuration.headers.iter() {
                                     if let Some(value) = headers.get(requested_header) {
@@ -714,14 +1118,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         log_join_result("webhook server", result);
     }
 
-    // Drop every Sender<Message> so worker threads exit once the queues drain.
+    // Drop every Sender<Message> so worker threads exit once the queues drain, or once the
+    // shutdown deadline passes.
     info!("Waiting for executor threads to drain...");
+    let queues = exec_thread_pools.receivers();
     drop(metrics);
     drop(log_sender);
     drop(exec_thread_pools);
     drop(immediate_dispatch);
     drop(executor);
-    executor_threads.join();
+    executor_threads.join_within(shutdown_deadline).await;
+
+    if let Some(recorder) = &recorder {
+        info!("Writing recorded messages...");
+        recorder.flush().await;
+    }
+
+    // Persist the logs the executor did not get to. Logs in the write-ahead log are replayed
+    // from there instead.
+    let persisted = persist_queued_messages(queues, &internal_storage, |message| {
+        write_ahead_log.is_some() && WriteAheadLog::is_logged(message)
+    })
+    .await;
+    if persisted > 0 {
+        info!("Persisted {persisted} queued logs to storage, they will run when Plaid next starts");
+    }
 
     // Persist any delayed logbacks still in the in-memory channel.
     info!("Flushing delayed logbacks to storage...");
@@ -0,0 +1,263 @@
+//! Replay recorded messages against a set of modules, offline. Modules are loaded the same
+//! way Plaid loads them, but no APIs are configured: calls to them are answered with the
+//! responses that were recorded along with the message, and calls that were not recorded
+//! are reported and fail. Storage is kept in memory, so modules can use it without touching
+//! real data.
+
+use std::process;
+use std::sync::Arc;
+
+use clap::{Arg, Command};
+use crossbeam_channel::{unbounded, Receiver};
+use plaid::apis::canned::CannedResponses;
+use plaid::apis::{Api, ApiConfigs};
+use plaid::config::read_and_interpolate;
+use plaid::data::{DelayedMessage, ScheduledLogbacks};
+use plaid::executor::{execute_standalone, Message, PoolSender, StandaloneContext};
+use plaid::filters::FilterInput;
+use plaid::loader::{self, PlaidModule, PlaidModules};
+use plaid::logging::{Log, Logger};
+use plaid::recording::{read_recording_file, read_recording_namespace, RecordedMessage};
+use plaid::storage::Storage;
+
+/// Run one recorded message through every module that would have processed it.
+/// Returns how many of the modules failed.
+fn replay_message(
+    recorded: &RecordedMessage,
+    modules: &Arc<PlaidModules>,
+    api: &Arc<Api>,
+    canned_responses: &CannedResponses,
+    storage: &Arc<Storage>,
+) -> usize {
+    let message = &recorded.message;
+    println!(
+        "[{}] message [{}] recorded at {} from {}",
+        message.type_, message.id, recorded.timestamp, message.source
+    );
+
+    let Some(channel) = modules.get_channel(&message.type_) else {
+        println!("  No modules are loaded for this log type");
+        return 0;
+    };
+
+    let filter_input = FilterInput::new(message);
+    let mut failures = 0;
+    for module in channel {
+        if let Some(filter) = &module.filter {
+            if !filter.matches(&filter_input) {
+                println!("  {}: skipped by its message filter", module.name);
+                continue;
+            }
+        }
+        // The module gets the responses the APIs gave it when the message was recorded
+        canned_responses.set_recorded(
+            recorded
+                .api_calls
+                .iter()
+                .filter(|call| call.module == module.name)
+                .map(|call| {
+                    (
+                        call.function.clone(),
+                        call.params.clone(),
+                        call.response.clone(),
+                    )
+                }),
+        );
+        if !replay_with_module(message, module, modules, api, storage) {
+            failures += 1;
+        }
+    }
+    failures
+}
+
+/// Run a message through a module and print what happened. Returns whether the module succeeded.
+fn replay_with_module(
+    message: &Message,
+    module: &Arc<PlaidModule>,
+    modules: &Arc<PlaidModules>,
+    api: &Arc<Api>,
+    storage: &Arc<Storage>,
+) -> bool {
+    let (els, logs) = Logger::capturing();
+    let (immediate_sender, immediate_logbacks) = PoolSender::unbounded();
+    let (delayed_log_sender, delayed_logbacks) = unbounded::<DelayedMessage>();
+
+    let execution = execute_standalone(
+        message.create_duplicate(),
+        module.clone(),
+        StandaloneContext {
+            api: api.clone(),
+            storage: Some(storage.clone()),
+            cache: None,
+            els,
+            immediate_sender: Some(immediate_sender),
+            delayed_log_sender,
+            scheduled_logbacks: Arc::new(ScheduledLogbacks::new(storage.clone())),
+            modules: modules.clone(),
+        },
+    );
+
+    let succeeded = match execution {
+        Ok(execution) => {
+            let summary = format!(
+                "computation used: {}, took {:?}",
+                execution.computation_used, execution.duration
+            );
+            match execution.response {
+                Ok(response) => {
+                    println!("  {}: OK ({summary})", module.name);
+                    if let Some(response) = response {
+                        println!("    response: {response}");
+                    }
+                    true
+                }
+                Err(e) => {
+                    println!("  {}: FAILED ({summary}): {e}", module.name);
+                    false
+                }
+            }
+        }
+        Err(e) => {
+            println!("  {}: FAILED to run: {e}", module.name);
+            false
+        }
+    };
+
+    print_side_effects(&logs, &immediate_logbacks, &delayed_logbacks);
+    succeeded
+}
+
+/// Print the host calls and logbacks a module attempted
+fn print_side_effects(
+    logs: &Receiver<Log>,
+    immediate_logbacks: &Receiver<Message>,
+    delayed_logbacks: &Receiver<DelayedMessage>,
+) {
+    for log in logs.try_iter() {
+        match log {
+            Log::HostFunctionCall { function, .. } => println!("    called {function}"),
+            Log::ModuleLog { level, message, .. } => println!("    logged {level:?}: {message}"),
+            Log::ModuleExecutionError { error, .. } => println!("    error: {error}"),
+            _ => (),
+        }
+    }
+    for logback in immediate_logbacks.try_iter() {
+        println!("    logged back to [{}]", logback.type_);
+    }
+    for logback in delayed_logbacks.try_iter() {
+        println!(
+            "    logged back to [{}] with a delay of {}s",
+            logback.message.type_, logback.delay
+        );
+    }
+}
+
+fn main() {
+    env_logger::init();
+    let matches = Command::new("Replay")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Replay recorded messages against modules, with the API responses recorded along with them")
+        .arg(
+            Arg::new("config")
+                .help("Path to the folder with configuration toml files")
+                .long("config")
+                .default_value("./plaid/resources/config"),
+        )
+        .arg(
+            Arg::new("secrets")
+                .help("Path to the secrets file")
+                .long("secrets")
+                .default_value("./plaid/private-resources/secrets.toml"),
+        )
+        .arg(
+            Arg::new("recording")
+                .help("Path to a file that messages were recorded to")
+                .long("recording")
+                .required_unless_present("namespace")
+                .conflicts_with("namespace"),
+        )
+        .arg(
+            Arg::new("namespace")
+                .help("Storage namespace that messages were recorded to, in the configured storage")
+                .long("namespace"),
+        )
+        .arg(
+            Arg::new("modules")
+                .help("Folder with the modules to replay against, instead of the configured one")
+                .long("modules"),
+        )
+        .arg(
+            Arg::new("log_type")
+                .help("Only replay messages of this log type")
+                .long("log-type"),
+        )
+        .get_matches();
+
+    let config_folder = matches.get_one::<String>("config").unwrap();
+    let secrets_path = matches.get_one::<String>("secrets").unwrap();
+    let mut config =
+        read_and_interpolate(config_folder, secrets_path, false).expect("Invalid config!");
+    if let Some(module_dir) = matches.get_one::<String>("modules") {
+        config.loading.module_dir = module_dir.clone();
+    }
+
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to start a Tokio runtime");
+
+    let recorded = match (
+        matches.get_one::<String>("recording"),
+        matches.get_one::<String>("namespace"),
+    ) {
+        (Some(path), _) => read_recording_file(path),
+        (None, Some(namespace)) => {
+            let Some(storage_config) = config.storage else {
+                eprintln!("No storage is configured to read recorded messages from");
+                process::exit(1);
+            };
+            runtime.block_on(async {
+                let storage = Storage::new(storage_config)
+                    .await
+                    .expect("Failed to configure storage");
+                read_recording_namespace(&storage, namespace).await
+            })
+        }
+        (None, None) => unreachable!("clap requires one of them"),
+    };
+    let recorded = match recorded {
+        Ok(recorded) => recorded,
+        Err(e) => {
+            eprintln!("Failed to read recorded messages: {e}");
+            process::exit(1);
+        }
+    };
+
+    let modules = match runtime.block_on(loader::load(&config.loading, None)) {
+        Ok(modules) => Arc::new(modules),
+        Err(()) => {
+            eprintln!("Failed to load modules");
+            process::exit(1);
+        }
+    };
+    let mut api = runtime
+        .block_on(Api::new(ApiConfigs::default()))
+        .expect("Failed to create API");
+    let canned_responses = Arc::new(CannedResponses::default());
+    api.canned_responses = Some(canned_responses.clone());
+    let api = Arc::new(api);
+    let storage = Arc::new(Storage::new_in_memory());
+
+    let log_type = matches.get_one::<String>("log_type");
+    let mut replayed = 0;
+    let mut failures = 0;
+    for recorded in recorded
+        .iter()
+        .filter(|recorded| log_type.is_none_or(|log_type| *log_type == recorded.message.type_))
+    {
+        failures += replay_message(recorded, &modules, &api, &canned_responses, &storage);
+        replayed += 1;
+    }
+
+    println!("Replayed {replayed} messages, {failures} module executions failed");
+    if failures > 0 {
+        process::exit(1);
+    }
+}
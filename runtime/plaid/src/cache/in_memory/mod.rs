@@ -1,70 +1,121 @@
 //! This module provides a way for Plaid to use an in-memory cache.
 
-use crate::cache::CacheError;
+use crate::cache::{CacheError, CacheProvider};
+use crate::loader::LimitedAmount;
 use async_trait::async_trait;
 use lru::LruCache;
+use std::sync::Arc;
 use std::{collections::HashMap, num::NonZeroUsize};
 use tokio::sync::RwLock;
 
+type ModuleCache = Arc<RwLock<LruCache<String, String>>>;
+
 /// A wrapper for the cache
 pub struct InMemoryCache {
     /// This is mapping module names to LruCache objects: one cache per module
-    caches: HashMap<String, RwLock<LruCache<String, String>>>,
+    caches: std::sync::RwLock<HashMap<String, ModuleCache>>,
+    /// How many entries each module's cache holds
+    cache_entries: LimitedAmount,
 }
 
 impl InMemoryCache {
     pub fn new(modules_and_logtypes: HashMap<String, String>, config: super::Config) -> Self {
-        // Create an LruCache for each module and collect everything into a HashMap
-        let caches: HashMap<String, RwLock<LruCache<String, String>>> = modules_and_logtypes
-            .iter()
-            .map(|(module, logtype)| {
-                // Figure out the capacity of the cache for this module
-                let mut capacity = config.cache_entries.default;
-                if let Some(v) = config.cache_entries.log_type.get(logtype) {
-                    capacity = *v;
-                }
-                if let Some(v) = config.cache_entries.module_overrides.get(module) {
-                    capacity = *v;
-                }
-
-                let module_cache =
-                    RwLock::new(LruCache::new(NonZeroUsize::new(capacity as usize).unwrap()));
-
-                (module.to_string(), module_cache)
-            })
-            .collect();
+        let cache = InMemoryCache {
+            caches: std::sync::RwLock::new(HashMap::new()),
+            cache_entries: config.cache_entries,
+        };
+        cache.add_modules(&modules_and_logtypes);
+        cache
+    }
 
-        InMemoryCache { caches }
+    /// Get the cache of a module
+    fn module_cache(&self, namespace: &str) -> Result<ModuleCache, CacheError> {
+        self.caches
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(namespace)
+            .cloned()
+            .ok_or(CacheError::CacheAccessError(format!(
+                "Cache not found for module {namespace}"
+            )))
     }
 }
 
 #[async_trait]
-impl super::CacheProvider for InMemoryCache {
+impl CacheProvider for InMemoryCache {
     async fn put(
         &self,
         namespace: &str,
         key: &str,
         value: &str,
     ) -> Result<Option<String>, CacheError> {
-        let module_cache = self
-            .caches
-            .get(namespace)
-            .ok_or(CacheError::CacheAccessError(format!(
-                "Cache not found for module {namespace}"
-            )))?;
-        Ok(module_cache
+        let module_cache = self.module_cache(namespace)?;
+        let previous = module_cache
             .write()
             .await
-            .put(key.to_string(), value.to_string()))
+            .put(key.to_string(), value.to_string());
+        Ok(previous)
     }
 
     async fn get(&self, namespace: &str, key: &str) -> Result<Option<String>, CacheError> {
-        let module_cache = self
-            .caches
-            .get(namespace)
-            .ok_or(CacheError::CacheAccessError(format!(
-                "Cache not found for module {namespace}"
-            )))?;
-        Ok(module_cache.write().await.get(&key.to_string()).cloned())
+        let module_cache = self.module_cache(namespace)?;
+        let value = module_cache.write().await.get(&key.to_string()).cloned();
+        Ok(value)
+    }
+
+    fn add_modules(&self, modules_and_logtypes: &HashMap<String, String>) {
+        let mut caches = self.caches.write().unwrap_or_else(|e| e.into_inner());
+        // Create an LruCache for each module which does not have one yet
+        for (module, logtype) in modules_and_logtypes {
+            if caches.contains_key(module) {
+                continue;
+            }
+
+            // Figure out the capacity of the cache for this module
+            let mut capacity = self.cache_entries.default;
+            if let Some(v) = self.cache_entries.log_type.get(logtype) {
+                capacity = *v;
+            }
+            if let Some(v) = self.cache_entries.module_overrides.get(module) {
+                capacity = *v;
+            }
+
+            let module_cache =
+                RwLock::new(LruCache::new(NonZeroUsize::new(capacity as usize).unwrap()));
+            caches.insert(module.to_string(), Arc::new(module_cache));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Config;
+    use super::*;
+
+    #[tokio::test]
+    async fn modules_added_later_get_a_cache() {
+        let config = Config {
+            cache_entries: LimitedAmount {
+                default: 10,
+                log_type: HashMap::new(),
+                module_overrides: HashMap::new(),
+            },
+            backend: None,
+        };
+        let module = |name: &str| HashMap::from([(name.to_string(), "test".to_string())]);
+        let cache = InMemoryCache::new(module("first.wasm"), config);
+        cache.put("first.wasm", "key", "value").await.unwrap();
+        assert!(cache.put("second.wasm", "key", "value").await.is_err());
+
+        // Modules which already have a cache keep their entries
+        cache.add_modules(&HashMap::from([
+            ("first.wasm".to_string(), "test".to_string()),
+            ("second.wasm".to_string(), "test".to_string()),
+        ]));
+        cache.put("second.wasm", "key", "value").await.unwrap();
+        assert_eq!(
+            cache.get("first.wasm", "key").await.unwrap().as_deref(),
+            Some("value")
+        );
     }
 }
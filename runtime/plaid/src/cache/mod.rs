@@ -80,6 +80,9 @@ pub trait CacheProvider {
     ) -> Result<Option<String>, CacheError>;
     /// Get a value from the cache, if present.
     async fn get(&self, namespace: &str, key: &str) -> Result<Option<String>, CacheError>;
+    /// Make room for the modules which were loaded after the cache was created, e.g. by a hot
+    /// reload. Only providers which set aside space for each module need to do anything.
+    fn add_modules(&self, _modules_and_logtypes: &HashMap<String, String>) {}
 }
 
 impl Cache {
@@ -127,4 +130,9 @@ impl Cache {
             .get(&namespace.to_string(), &key.to_string())
             .await
     }
+
+    /// Make room for modules which were loaded after the cache was created
+    pub fn add_modules(&self, modules_and_logtypes: &HashMap<String, String>) {
+        self.cache.add_modules(modules_and_logtypes)
+    }
 }
@@ -12,10 +12,16 @@ use crate::InstanceRoles;
 use super::apis::ApiConfigs;
 use super::cache::Config as CacheConfig;
 use super::data::DataConfig;
+use super::dead_letter::DeadLetterConfig;
+use super::deduplication::DeduplicationConfig;
+use super::executor::priority::PriorityConfig;
+use super::filters::MessageFilter;
 use super::loader::Configuration as LoaderConfiguration;
 use super::logging::LoggingConfiguration;
 use super::metrics::MetricsConfiguration;
+use super::recording::RecordingConfig;
 use super::storage::Config as StorageConfig;
+use super::write_ahead_log::WriteAheadLogConfig;
 
 /// How should responses to GET requests be cached.
 #[derive(Default, Deserialize, Clone)]
@@ -97,6 +103,26 @@ pub struct WebhookConfig {
     /// will be able to as well). If this is not set, it will default to Limited(0).
     #[serde(default)]
     pub logbacks_allowed: LogbacksAllowed,
+    /// If this is set, POST bodies which do not match the filter are dropped
+    /// before they reach any module.
+    pub filter: Option<MessageFilter>,
+    /// Send POST bodies to other logging channels based on their content. A body goes
+    /// to the log type of the first route whose filter it matches, or to `log_type`
+    /// if it matches none of them.
+    #[serde(default)]
+    pub routes: Vec<WebhookRoute>,
+    /// If this is set, POST bodies which the webhook already received, e.g. because the sender
+    /// retried a delivery, are dropped before they reach any module.
+    pub deduplication: Option<DeduplicationConfig>,
+}
+
+/// Sends the POST bodies of a webhook which match a filter to a different logging channel
+#[derive(Deserialize, Clone)]
+pub struct WebhookRoute {
+    /// The logging channel that matching POST bodies will be sent to
+    pub log_type: String,
+    /// Which POST bodies are sent to this route's logging channel
+    pub filter: MessageFilter,
 }
 
 /// Configuration for a webhook server
@@ -117,6 +143,21 @@ pub struct DedicatedThreadsConfig {
     pub log_queue_size: usize,
 }
 
+/// Configuration for log types that must never be processed concurrently.
+///
+/// Messages for these log types are executed by a single dedicated thread, and
+/// only on instances that have the `non_concurrent_rules` role. It is the
+/// responsibility of the admin to make sure that, in a multi-instance deployment,
+/// exactly one instance has that role.
+#[derive(Deserialize)]
+pub struct NonConcurrentConfig {
+    /// The log types that must be processed serially
+    pub log_types: Vec<String>,
+    /// The maximum number of logs in the queue of the non-concurrent thread
+    #[serde(default = "default_log_queue_size")]
+    pub log_queue_size: usize,
+}
+
 /// The configuration for the executor system
 #[derive(Deserialize)]
 pub struct ExecutorConfig {
@@ -132,6 +173,14 @@ pub struct ExecutorConfig {
     /// This is a mapping {log type --> num threads}.
     #[serde(default)]
     pub dedicated_threads: HashMap<String, DedicatedThreadsConfig>,
+    /// Log types that are executed serially, on a single thread. See `NonConcurrentConfig`.
+    pub non_concurrent: Option<NonConcurrentConfig>,
+    /// How long, in seconds, Plaid keeps executing queued logs when shutting down. Logs still
+    /// queued after that are persisted to storage and executed when Plaid next starts.
+    /// If not set, Plaid executes every queued log before exiting.
+    pub shutdown_deadline_secs: Option<u64>,
+    /// Priority lanes within the thread pools. See `PriorityConfig`.
+    pub priorities: Option<PriorityConfig>,
 }
 
 /// The full configuration of Plaid
@@ -164,6 +213,14 @@ pub struct Configuration {
     pub cache: CacheConfig,
     /// Optional Prometheus metrics endpoint configuration.
     pub metrics: Option<MetricsConfiguration>,
+    /// Optional dead-letter queue, which keeps the messages that modules failed to process
+    /// so they can be replayed later.
+    pub dead_letter: Option<DeadLetterConfig>,
+    /// Optional recording of incoming messages, so they can be replayed against modules offline
+    pub recording: Option<RecordingConfig>,
+    /// Optional write-ahead log, which keeps the messages received by webhooks until every
+    /// module has processed them, so they are not lost if Plaid stops before then
+    pub write_ahead_log: Option<WriteAheadLogConfig>,
 }
 
 /// Plaid's configuration augmented with the roles that this instance is playing.
@@ -172,6 +229,10 @@ pub struct ConfigurationWithRoles {
     pub config: Configuration,
     /// The roles that this instance has, i.e., what this instance is running
     pub roles: InstanceRoles,
+    /// The folder the configuration was read from
+    pub config_folder: String,
+    /// The secrets file interpolated into the configuration
+    pub secrets_path: String,
 }
 
 /// This function provides the default log queue size in the event that one isn't provided
@@ -204,6 +265,8 @@ pub enum ConfigurationError {
     ParsingError,
     ComputationLimitInvalid,
     ExecutionThreadsInvalid,
+    NonConcurrentLogTypeHasDedicatedThreads(String),
+    UnknownPriorityLane(String),
 }
 
 impl std::fmt::Display for ConfigurationError {
@@ -225,6 +288,15 @@ impl std::fmt::Display for ConfigurationError {
                     "The number of execution threads must be between 1 and 255"
                 )
             }
+            ConfigurationError::NonConcurrentLogTypeHasDedicatedThreads(log_type) => {
+                write!(
+                    f,
+                    "Log type [{log_type}] is marked as non-concurrent and cannot also have dedicated threads"
+                )
+            }
+            ConfigurationError::UnknownPriorityLane(lane) => {
+                write!(f, "Priority lane [{lane}] is used but not configured")
+            }
         }
     }
 }
@@ -304,7 +376,12 @@ pub fn configure() -> Result<ConfigurationWithRoles, ConfigurationError> {
         non_concurrent_rules: !*no_nonconcurrent,
     };
 
-    Ok(ConfigurationWithRoles { config, roles })
+    Ok(ConfigurationWithRoles {
+        config,
+        roles,
+        config_folder: config_folder.clone(),
+        secrets_path: secrets_path.clone(),
+    })
 }
 
 /// Reads configuration files from a given folder and a secrets file, concatenates the config files into one,
@@ -387,17 +464,46 @@ pub fn read_and_interpolate(
     }
 
     // Parse the TOML into our configuration structures
-    let config: Configuration = match toml::from_str(&config) {
+    let raw_config = config;
+    let mut config: Configuration = match toml::from_str(&raw_config) {
         Ok(config) => config,
         Err(e) => {
             error!("Encountered parsing error while reading configuration with interpolated secrets! Error: {e}");
             return Err(ConfigurationError::ParsingError);
         }
     };
+    // Keep the loading configuration as it was written, to tell which modules it changed
+    // when they are reloaded
+    config.loading.raw = toml::from_str::<toml::Value>(&raw_config)
+        .ok()
+        .and_then(|raw| raw.get("loading").cloned());
 
     if config.executor.execution_threads == 0 {
         return Err(ConfigurationError::ExecutionThreadsInvalid);
     }
 
+    // A non-concurrent log type is always executed by its own single thread, so it
+    // cannot also be assigned a pool of dedicated threads.
+    if let Some(non_concurrent) = &config.executor.non_concurrent {
+        if let Some(log_type) = non_concurrent
+            .log_types
+            .iter()
+            .find(|lt| config.executor.dedicated_threads.contains_key(*lt))
+        {
+            return Err(ConfigurationError::NonConcurrentLogTypeHasDedicatedThreads(
+                log_type.clone(),
+            ));
+        }
+    }
+
+    if let Some(lane) = config
+        .executor
+        .priorities
+        .as_ref()
+        .and_then(|priorities| priorities.unknown_lane())
+    {
+        return Err(ConfigurationError::UnknownPriorityLane(lane.to_string()));
+    }
+
     Ok(config)
 }
@@ -1,9 +1,9 @@
 use crate::apis::github::{build_github_clients, Authentication};
 use crate::apis::ApiError;
-use crate::executor::Message;
+use crate::executor::{Message, PoolSender};
+use crate::filters::MessageFilter;
 use crate::metrics::MetricsHandle;
 use crate::parse_duration;
-use crossbeam_channel::Sender;
 use lru::LruCache;
 use octocrab::{self, Octocrab};
 use plaid_stl::messages::{Generator, LogSource, LogbacksAllowed};
@@ -93,6 +93,8 @@ pub struct GithubConfig {
     /// Max number of seconds for the look-back window
     #[serde(default = "default_max_catchup")]
     max_catchup: u64,
+    /// If this is set, logs which do not match the filter are dropped before they reach any module
+    filter: Option<MessageFilter>,
 }
 
 impl GithubConfig {
@@ -108,6 +110,7 @@ impl GithubConfig {
             lru_cache_size: default_lru_cache_size(),
             max_since_until: default_since_until(),
             max_catchup: default_max_catchup(),
+            filter: None,
         }
     }
 }
@@ -166,7 +169,7 @@ pub struct Github {
     /// Timestamp of the last seen log we have processed
     last_seen: OffsetDateTime,
     /// The logger used to send logs to the execution system for processing
-    logger: Sender<Message>,
+    logger: PoolSender,
     /// An LRU where we store the UUIDs of logs that we have already seen and sent into the logging system.
     /// This, together with some overlapping queries to the GH API, helps us ensure that all logs are processed
     /// exactly once.
@@ -180,7 +183,7 @@ pub struct Github {
 impl Github {
     pub fn new(
         config: GithubConfig,
-        logger: Sender<Message>,
+        logger: PoolSender,
         metrics: Option<Arc<MetricsHandle>>,
     ) -> Result<Self, ApiError> {
         let default_client_auth: HashMap<String, Authentication> = [(
@@ -381,14 +384,20 @@ impl DataGenerator for Github {
     }
 
     fn send_for_processing(&self, payload: Vec<u8>) -> Result<(), ()> {
-        self.logger
-            .send(Message::new(
-                format!("github"),
-                payload,
-                LogSource::Generator(Generator::Github),
-                self.config.logbacks_allowed.clone(),
-            ))
-            .map_err(|_| ())?;
+        let message = Message::new(
+            format!("github"),
+            payload,
+            LogSource::Generator(Generator::Github),
+            self.config.logbacks_allowed.clone(),
+        );
+        if self
+            .config
+            .filter
+            .as_ref()
+            .is_none_or(|filter| filter.matches_message(&message))
+        {
+            self.logger.send(message).map_err(|_| ())?;
+        }
 
         if let Some(counter) = &self.logs_fetched {
             counter.inc();
@@ -1,6 +1,10 @@
-use crate::{executor::Message, storage::Storage};
+use crate::{
+    executor::{Message, PoolSender},
+    storage::{Storage, StorageError},
+};
 
 use crossbeam_channel::{bounded, Receiver, Sender, TrySendError};
+use plaid_stl::plaid::ScheduledLogback;
 
 use serde::{Deserialize, Serialize};
 
@@ -21,11 +25,28 @@ const CHANNEL_CAPACITY: usize = 4096;
 pub struct DelayedMessage {
     pub delay: u64,
     pub message: Message,
+    /// The module that scheduled this message with a logback, if it was scheduled by one.
+    /// Only that module can look the message up or cancel it before it is delivered.
+    #[serde(default)]
+    pub scheduled_by: Option<String>,
 }
 
 impl DelayedMessage {
     pub fn new(delay: u64, message: Message) -> Self {
-        Self { delay, message }
+        Self {
+            delay,
+            message,
+            scheduled_by: None,
+        }
+    }
+
+    /// A delayed logback that `module` can look up and cancel later
+    pub fn scheduled_by(delay: u64, message: Message, module: String) -> Self {
+        Self {
+            delay,
+            message,
+            scheduled_by: Some(module),
+        }
     }
 }
 
@@ -49,7 +70,7 @@ impl std::cmp::Ord for DelayedMessage {
     }
 }
 
-/// Persists incoming delayed logbacks to storage. Holds no `Sender<Message>` so the
+/// Persists incoming delayed logbacks to storage. Holds no `PoolSender` so the
 /// perpetual listener task cannot keep executor ingress channels alive.
 #[derive(Clone)]
 pub struct DelayedLogPersister {
@@ -70,15 +91,77 @@ impl DelayedLogPersister {
     }
 }
 
+/// Gives modules access to the delayed logbacks they scheduled, so they can check on
+/// them or cancel them before they are delivered. A logback's ID is the ID of its message.
+///
+/// Delayed logbacks are persisted asynchronously, so a logback can only be found here
+/// once the internal logback listener has stored it, which is usually within a second.
+pub struct ScheduledLogbacks {
+    storage: Arc<Storage>,
+}
+
+impl ScheduledLogbacks {
+    pub fn new(storage: Arc<Storage>) -> Self {
+        Self { storage }
+    }
+
+    async fn get_scheduled(
+        &self,
+        module: &str,
+        id: &str,
+    ) -> Result<Option<DelayedMessage>, StorageError> {
+        let Some(value) = self.storage.get(LOGBACK_NS, id).await? else {
+            return Ok(None);
+        };
+
+        match serde_json::from_slice::<DelayedMessage>(&value) {
+            // Modules can only see the logbacks they scheduled themselves
+            Ok(log) if log.scheduled_by.as_deref() == Some(module) => Ok(Some(log)),
+            Ok(_) => Ok(None),
+            Err(e) => {
+                warn!("Persisted logback [{id}] could not be deserialized [{e}]");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Get a logback that `module` scheduled and which has not been delivered yet
+    pub async fn get(
+        &self,
+        module: &str,
+        id: &str,
+    ) -> Result<Option<ScheduledLogback>, StorageError> {
+        Ok(self
+            .get_scheduled(module, id)
+            .await?
+            .map(|log| ScheduledLogback {
+                id: log.message.id,
+                log_type: log.message.type_,
+                fires_at: log.delay,
+                data: log.message.data,
+            }))
+    }
+
+    /// Cancel a logback that `module` scheduled. Returns `false` if there was no such
+    /// logback waiting to be delivered.
+    pub async fn cancel(&self, module: &str, id: &str) -> Result<bool, StorageError> {
+        if self.get_scheduled(module, id).await?.is_none() {
+            return Ok(false);
+        }
+
+        Ok(self.storage.delete(LOGBACK_NS, id).await?.is_some())
+    }
+}
+
 pub struct Internal {
-    sender: Sender<Message>,
+    sender: PoolSender,
     internal_sender: Sender<DelayedMessage>,
     storage: Arc<Storage>,
 }
 
 impl Internal {
     pub fn new(
-        log_sender: Sender<Message>,
+        log_sender: PoolSender,
         storage: Arc<Storage>,
     ) -> Result<(Self, DelayedLogPersister), DataError> {
         let (internal_sender, receiver) = bounded(CHANNEL_CAPACITY);
@@ -172,7 +255,7 @@ async fn fill_heap_from_db(
                     continue;
                 }
             };
-            log_heap.push(Reverse(DelayedMessage { delay, message }));
+            log_heap.push(Reverse(DelayedMessage::new(delay, message)));
         } else {
             warn!("Empty value for logback with key {key}, skipping it.");
         }
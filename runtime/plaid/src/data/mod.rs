@@ -8,7 +8,7 @@ mod websocket;
 
 use crate::{
     apis::ApiError,
-    executor::Message,
+    executor::PoolSender,
     logging::Logger,
     metrics::MetricsHandle,
     storage::{Storage, StorageError},
@@ -28,7 +28,7 @@ use time::OffsetDateTime;
 use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 
-pub use self::internal::{DelayedLogPersister, DelayedMessage};
+pub use self::internal::{DelayedLogPersister, DelayedMessage, ScheduledLogbacks};
 
 const DATA_GENERATOR_STORAGE_PREFIX: &str = "__DATA_GENERATOR";
 const LAST_SEEN_KEY: &str = "last_seen";
@@ -82,7 +82,7 @@ impl std::error::Error for DataError {}
 impl DataInternal {
     async fn new(
         config: DataConfig,
-        logger: Sender<Message>,
+        logger: PoolSender,
         storage: Arc<Storage>,
         els: Logger,
         metrics: Option<Arc<MetricsHandle>>,
@@ -134,7 +134,7 @@ impl DataInternal {
 impl Data {
     pub async fn start(
         config: DataConfig,
-        sender: Sender<Message>,
+        sender: PoolSender,
         storage: Arc<Storage>,
         els: Logger,
         roles: &InstanceRoles,
@@ -1,5 +1,7 @@
-use crate::{data::DataGeneratorLog, executor::Message, metrics::MetricsHandle, parse_duration};
-use crossbeam_channel::Sender;
+use crate::{
+    data::DataGeneratorLog, executor::{Message, PoolSender}, filters::MessageFilter, metrics::MetricsHandle,
+    parse_duration,
+};
 use lru::LruCache;
 use plaid_stl::messages::{Generator, LogSource, LogbacksAllowed};
 use prometheus::IntCounter;
@@ -51,6 +53,8 @@ pub struct OktaConfig {
     /// Max number of seconds for the look-back window
     #[serde(default = "default_max_catchup")]
     max_catchup: u64,
+    /// If this is set, logs which do not match the filter are dropped before they reach any module
+    filter: Option<MessageFilter>,
 }
 
 /// Custom parser for limit. Returns an error if a limit = 0 or limit > 1000 is given
@@ -114,7 +118,7 @@ pub struct Okta {
     /// Timestamp of the last seen log we have processed
     last_seen: OffsetDateTime,
     /// Sending channel used to send logs into the execution system
-    logger: Sender<Message>,
+    logger: PoolSender,
     /// An LRU where we store the UUIDs of Okta logs that we have already seen and sent into the logging system.
     /// This, together with some overlapping queries to the Okta API, helps us ensure that all Okta logs are processed
     /// exactly once.
@@ -128,7 +132,7 @@ pub struct Okta {
 impl Okta {
     pub fn new(
         config: OktaConfig,
-        logger: Sender<Message>,
+        logger: PoolSender,
         metrics: Option<Arc<MetricsHandle>>,
     ) -> Self {
         let client = reqwest::Client::builder()
@@ -329,14 +333,20 @@ impl DataGenerator for Okta {
     }
 
     fn send_for_processing(&self, payload: Vec<u8>) -> Result<(), ()> {
-        self.logger
-            .send(Message::new(
-                "okta".to_string(),
-                payload,
-                LogSource::Generator(Generator::Okta),
-                self.config.logbacks_allowed.clone(),
-            ))
-            .map_err(|_| ())?;
+        let message = Message::new(
+            "okta".to_string(),
+            payload,
+            LogSource::Generator(Generator::Okta),
+            self.config.logbacks_allowed.clone(),
+        );
+        if self
+            .config
+            .filter
+            .as_ref()
+            .is_none_or(|filter| filter.matches_message(&message))
+        {
+            self.logger.send(message).map_err(|_| ())?;
+        }
 
         if let Some(counter) = &self.logs_fetched {
             counter.inc();
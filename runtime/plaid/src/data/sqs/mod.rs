@@ -1,12 +1,15 @@
 use aws_sdk_sqs::Client;
-use crossbeam_channel::Sender;
 use lru::LruCache;
 use plaid_stl::messages::{Generator, LogSource, LogbacksAllowed};
 use serde::Deserialize;
 use std::num::NonZeroUsize;
 use std::time::Duration;
 
-use crate::{executor::Message, get_aws_sdk_config, parse_duration, AwsAuthentication};
+use crate::{
+    executor::{Message, PoolSender},
+    filters::MessageFilter,
+    get_aws_sdk_config, parse_duration, AwsAuthentication,
+};
 
 #[derive(Deserialize)]
 pub struct SQSConfig {
@@ -28,6 +31,8 @@ pub struct SQSConfig {
     pub max_num_messages: i32,
     #[serde(default = "default_wait_time_seconds")]
     pub wait_time_seconds: i32,
+    /// If this is set, logs which do not match the filter are dropped before they reach any module
+    filter: Option<MessageFilter>,
 }
 
 /// This function provides the default sleep duration.
@@ -54,7 +59,7 @@ pub struct SQS {
     /// API client
     client: Client,
     /// The logger used to send logs to the execution system for processing
-    logger: Sender<Message>,
+    logger: PoolSender,
     /// SQS sends messages 'at least once' so we use this cache to dedup messages
     /// An LRU where we store the UUIDs of messages that we have already seen and sent into the logging system.
     /// This LRU has a limited capacity: when this is reached, the least-recently-used item is removed to make space for a new insertion.
@@ -63,7 +68,7 @@ pub struct SQS {
 }
 
 impl SQS {
-    pub async fn new(config: SQSConfig, logger: Sender<Message>) -> Self {
+    pub async fn new(config: SQSConfig, logger: PoolSender) -> Self {
         let sdk_config = get_aws_sdk_config(&config.authentication).await;
         let client = aws_sdk_sqs::Client::new(&sdk_config);
 
@@ -154,18 +159,23 @@ impl SQS {
     }
 
     fn send_for_processing(&self, payload: Vec<u8>) -> Result<(), String> {
-        self.logger
-            .send(Message::new(
-                format!("sqs/{}", self.config.name),
-                payload,
-                LogSource::Generator(Generator::SQS(self.config.name.clone())),
-                self.config.logbacks_allowed.clone(),
-            ))
-            .map_err(|e| {
-                format!(
-                    "sqs/{} send_for_processing failed. error: {e}",
-                    self.config.name
-                )
-            })
+        let message = Message::new(
+            format!("sqs/{}", self.config.name),
+            payload,
+            LogSource::Generator(Generator::SQS(self.config.name.clone())),
+            self.config.logbacks_allowed.clone(),
+        );
+        if let Some(filter) = &self.config.filter {
+            if !filter.matches_message(&message) {
+                return Ok(());
+            }
+        }
+
+        self.logger.send(message).map_err(|e| {
+            format!(
+                "sqs/{} send_for_processing failed. error: {e}",
+                self.config.name
+            )
+        })
     }
 }
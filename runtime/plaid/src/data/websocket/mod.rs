@@ -1,7 +1,11 @@
 mod selector;
 
-use crate::{executor::Message, logging::Logger, parse_duration};
-use crossbeam_channel::Sender;
+use crate::{
+    executor::{Message, PoolSender},
+    filters::MessageFilter,
+    logging::Logger,
+    parse_duration,
+};
 use futures_util::{
     stream::{SplitSink, SplitStream},
     SinkExt, StreamExt,
@@ -83,6 +87,8 @@ pub struct WebSocket {
     #[serde(default = "max_retry_duration")]
     #[serde(deserialize_with = "parse_duration")]
     max_retry_duration: Duration,
+    /// If this is set, messages which do not match the filter are dropped before they reach any module
+    filter: Option<MessageFilter>,
 }
 
 /// Represents the configuration of a message to be sent over a WebSocket connection.
@@ -187,7 +193,7 @@ pub struct WebsocketGenerator {
 /// # Returns
 /// A new `WebsocketGenerator` instance.
 impl WebsocketGenerator {
-    pub fn new(config: WebSocketDataGenerator, sender: Sender<Message>, logger: Logger) -> Self {
+    pub fn new(config: WebSocketDataGenerator, sender: PoolSender, logger: Logger) -> Self {
         let clients = config
             .websockets
             .into_iter()
@@ -268,7 +274,7 @@ struct WebSocketClient {
     /// The configuration of the client
     configuration: WebSocket,
     /// The sending channel to send logs to the executor.
-    sender: Sender<Message>,
+    sender: PoolSender,
     /// The name of the WebSocket as defined in the configuration.
     name: String,
     /// Manages a list of URI entries and handles the selection and retry logic for connection attempts.
@@ -286,7 +292,7 @@ impl WebSocketClient {
     /// with the provided parameters.
     fn new(
         configuration: WebSocket,
-        sender: Sender<Message>,
+        sender: PoolSender,
         name: String,
         max_message_size: usize,
         max_frame_size: usize,
@@ -409,6 +415,12 @@ impl WebSocketClient {
                     self.configuration.logbacks_allowed.clone(),
                 );
 
+                if let Some(filter) = &self.configuration.filter {
+                    if !filter.matches_message(&log_message) {
+                        return true;
+                    }
+                }
+
                 if let Err(e) = self.sender.send(log_message) {
                     error!(
                         "Failed to send log generated from WebSocket {} ({uri_name}) to executor: {e}",
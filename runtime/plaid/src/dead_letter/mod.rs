@@ -0,0 +1,440 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crossbeam_channel::TrySendError;
+use plaid_stl::messages::LogSource;
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+
+use crate::executor::{Executor, Message};
+use crate::loader::LiveModules;
+use crate::storage::{Storage, StorageError};
+
+/// The storage namespace where failed messages are kept
+const DEAD_LETTER_NS: &str = "dead_letter_internal";
+/// How often expired dead letters are removed from storage
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+fn default_retention_secs() -> u64 {
+    // One week
+    7 * 24 * 60 * 60
+}
+
+/// Configuration for the dead-letter queue. When configured, messages that a module failed to
+/// process are persisted so that they can be inspected and replayed to the same module later.
+#[derive(Deserialize)]
+pub struct DeadLetterConfig {
+    /// How many seconds a failed message is kept for before it is removed. Defaults to one week.
+    #[serde(default = "default_retention_secs")]
+    pub retention_secs: u64,
+    /// If this value is set, Plaid serves an admin endpoint on this address to list and replay
+    /// dead letters. This endpoint is not authenticated, so it must only be reachable by operators.
+    pub admin_listen_address: Option<SocketAddr>,
+}
+
+/// A message that a module failed to process, along with why and when it failed
+#[derive(Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// Unique ID of this dead letter
+    pub id: String,
+    /// The module that failed to process the message
+    pub module: String,
+    /// The error the module failed with
+    pub error: String,
+    /// When the failure happened, in seconds since the epoch
+    pub timestamp: u64,
+    /// The message that failed to be processed
+    pub message: Message,
+}
+
+/// What the admin endpoint shows about a dead letter. The message's data is left out
+/// because it can be large and contain sensitive information.
+#[derive(Serialize)]
+pub struct DeadLetterSummary {
+    pub id: String,
+    pub module: String,
+    pub error: String,
+    pub timestamp: u64,
+    pub log_type: String,
+    pub source: LogSource,
+}
+
+impl From<&DeadLetter> for DeadLetterSummary {
+    fn from(dead_letter: &DeadLetter) -> Self {
+        Self {
+            id: dead_letter.id.clone(),
+            module: dead_letter.module.clone(),
+            error: dead_letter.error.clone(),
+            timestamp: dead_letter.timestamp,
+            log_type: dead_letter.message.type_.clone(),
+            source: dead_letter.message.source.clone(),
+        }
+    }
+}
+
+/// Errors encountered while using the dead-letter queue
+#[derive(Debug)]
+pub enum DeadLetterError {
+    StorageError(StorageError),
+    SerializationError(serde_json::Error),
+    NotFound(String),
+    ModuleNotLoaded(String),
+    QueueFull,
+    ExecutorUnavailable,
+}
+
+impl std::fmt::Display for DeadLetterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StorageError(e) => write!(f, "Storage error: {e}"),
+            Self::SerializationError(e) => write!(f, "Could not (de)serialize dead letter: {e}"),
+            Self::NotFound(id) => write!(f, "No dead letter with ID [{id}]"),
+            Self::ModuleNotLoaded(module) => {
+                write!(
+                    f,
+                    "Module [{module}] is not loaded so it cannot be replayed to"
+                )
+            }
+            Self::QueueFull => write!(f, "The execution queue is full"),
+            Self::ExecutorUnavailable => {
+                write!(f, "The execution system is no longer accepting messages")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeadLetterError {}
+
+impl From<StorageError> for DeadLetterError {
+    fn from(e: StorageError) -> Self {
+        Self::StorageError(e)
+    }
+}
+
+impl From<serde_json::Error> for DeadLetterError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::SerializationError(e)
+    }
+}
+
+/// Keeps the messages that modules failed to process in a dedicated storage namespace
+pub struct DeadLetterQueue {
+    storage: Arc<Storage>,
+    retention: Duration,
+}
+
+impl DeadLetterQueue {
+    pub fn new(config: &DeadLetterConfig, storage: Arc<Storage>) -> Self {
+        Self {
+            storage,
+            retention: Duration::from_secs(config.retention_secs),
+        }
+    }
+
+    /// Persist a message that `module` failed to process
+    pub async fn record(&self, module: &str, error: String, message: Message) {
+        let dead_letter = DeadLetter {
+            id: uuid::Uuid::new_v4().to_string(),
+            module: module.to_string(),
+            error,
+            timestamp: get_time(),
+            message,
+        };
+
+        let db_item = match serde_json::to_vec(&dead_letter) {
+            Ok(db_item) => db_item,
+            Err(e) => {
+                error!("Failed to serialize dead letter for [{module}]. Error: {e}");
+                return;
+            }
+        };
+
+        match self
+            .storage
+            .insert(DEAD_LETTER_NS.to_string(), dead_letter.id.clone(), db_item)
+            .await
+        {
+            Ok(_) => info!(
+                "Message that [{module}] failed to process stored as dead letter [{}]",
+                dead_letter.id
+            ),
+            Err(e) => {
+                error!("Storage system could not persist dead letter for [{module}]. Error: {e}")
+            }
+        }
+    }
+
+    /// Get all the dead letters, oldest first
+    pub async fn list(&self) -> Result<Vec<DeadLetter>, DeadLetterError> {
+        let mut dead_letters = vec![];
+        for (key, value) in self.storage.fetch_all(DEAD_LETTER_NS, None).await? {
+            let Some(value) = value else {
+                warn!("Empty value for dead letter with key {key}, skipping it.");
+                continue;
+            };
+            match serde_json::from_slice::<DeadLetter>(&value) {
+                Ok(dead_letter) => dead_letters.push(dead_letter),
+                Err(e) => {
+                    warn!("Skipping dead letter [{key}] which could not be deserialized [{e}]")
+                }
+            }
+        }
+        dead_letters.sort_by_key(|dead_letter| dead_letter.timestamp);
+
+        Ok(dead_letters)
+    }
+
+    /// Send a dead letter back to the module that failed to process it. The dead letter is
+    /// removed once it is queued for execution: if the module fails again, a new one is recorded.
+    pub async fn replay(
+        &self,
+        id: &str,
+        modules: &LiveModules,
+        executor: &Executor,
+    ) -> Result<(), DeadLetterError> {
+        let value = self
+            .storage
+            .get(DEAD_LETTER_NS, id)
+            .await?
+            .ok_or_else(|| DeadLetterError::NotFound(id.to_string()))?;
+        let dead_letter = serde_json::from_slice::<DeadLetter>(&value)?;
+
+        self.replay_dead_letter(dead_letter, modules, executor)
+            .await
+    }
+
+    /// Replay all dead letters, or only the ones of a given module. Returns how many were replayed
+    /// and the errors encountered for the others.
+    pub async fn replay_all(
+        &self,
+        module: Option<&str>,
+        modules: &LiveModules,
+        executor: &Executor,
+    ) -> Result<(usize, Vec<(String, DeadLetterError)>), DeadLetterError> {
+        let mut replayed = 0;
+        let mut errors = vec![];
+        for dead_letter in self.list().await? {
+            if module.is_some_and(|module| module != dead_letter.module) {
+                continue;
+            }
+
+            let id = dead_letter.id.clone();
+            match self
+                .replay_dead_letter(dead_letter, modules, executor)
+                .await
+            {
+                Ok(()) => replayed += 1,
+                // There is no point in trying the rest if the executor cannot take them
+                Err(e @ (DeadLetterError::QueueFull | DeadLetterError::ExecutorUnavailable)) => {
+                    errors.push((id, e));
+                    break;
+                }
+                Err(e) => errors.push((id, e)),
+            }
+        }
+
+        Ok((replayed, errors))
+    }
+
+    async fn replay_dead_letter(
+        &self,
+        dead_letter: DeadLetter,
+        modules: &LiveModules,
+        executor: &Executor,
+    ) -> Result<(), DeadLetterError> {
+        let module = modules
+            .current()
+            .get_module(&dead_letter.module)
+            .ok_or_else(|| DeadLetterError::ModuleNotLoaded(dead_letter.module.clone()))?;
+
+        // Setting the module means only that module runs, not the whole log type's channel.
+        // A replay starts over from the first attempt as far as the retry policy is concerned.
+        let mut message = dead_letter.message;
+        message.module = Some(module);
+        message.retry = None;
+
+        match executor.execute_webhook_message(message) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => return Err(DeadLetterError::QueueFull),
+            Err(TrySendError::Disconnected(_)) => return Err(DeadLetterError::ExecutorUnavailable),
+        }
+
+        info!(
+            "Replayed dead letter [{}] to [{}]",
+            dead_letter.id, dead_letter.module
+        );
+        self.storage.delete(DEAD_LETTER_NS, &dead_letter.id).await?;
+        Ok(())
+    }
+
+    /// Remove the dead letters which are older than the retention period
+    async fn purge_expired(&self) -> Result<(), DeadLetterError> {
+        let cutoff = get_time().saturating_sub(self.retention.as_secs());
+        for dead_letter in self.list().await? {
+            if dead_letter.timestamp < cutoff {
+                self.storage.delete(DEAD_LETTER_NS, &dead_letter.id).await?;
+                info!(
+                    "Dead letter [{}] for [{}] expired and was removed",
+                    dead_letter.id, dead_letter.module
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Periodically remove expired dead letters until the cancellation token is cancelled
+    pub async fn purge_expired_periodically(
+        self: Arc<Self>,
+        cancellation_token: CancellationToken,
+    ) {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {
+                    if let Err(e) = self.purge_expired().await {
+                        error!("Failed to remove expired dead letters: {e}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn get_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    use super::*;
+    use crate::apis::Api;
+    use crate::config::ExecutorConfig;
+    use crate::executor::thread_pools::ExecutionThreadPools;
+    use crate::executor::PoolSender;
+    use crate::loader::PlaidModules;
+    use crate::testing::fixtures;
+    use crate::InstanceRoles;
+
+    fn queue(storage: Arc<Storage>) -> DeadLetterQueue {
+        let config = DeadLetterConfig {
+            retention_secs: 60,
+            admin_listen_address: None,
+        };
+        DeadLetterQueue::new(&config, storage)
+    }
+
+    fn message() -> Message {
+        fixtures::message("failing", b"data")
+    }
+
+    /// Wait until the dead letters are not the ones in `previous`
+    fn wait_for_new(queue: &DeadLetterQueue, api: &Api, previous: &[String]) -> Vec<DeadLetter> {
+        let begin = Instant::now();
+        loop {
+            let dead_letters = api.runtime.block_on(queue.list()).unwrap();
+            if !dead_letters.is_empty() && dead_letters.iter().all(|d| !previous.contains(&d.id)) {
+                return dead_letters;
+            }
+            assert!(begin.elapsed() < Duration::from_secs(10));
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn failed_messages_are_kept_and_replayed() {
+        let storage = Arc::new(Storage::new_in_memory());
+        let api = fixtures::api();
+
+        // A module without a retry policy which fails on every message
+        let module = fixtures::wat_module(
+            "failing",
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "entrypoint") (result i32) i32.const 1))"#,
+        );
+        let mut modules = PlaidModules::default();
+        modules.insert(Arc::new(module));
+        let modules = Arc::new(LiveModules::new(modules));
+
+        let config: ExecutorConfig = toml::from_str("execution_threads = 1").unwrap();
+        let thread_pools = ExecutionThreadPools::new(&config, &InstanceRoles::default());
+        let dead_letters = Arc::new(queue(storage.clone()));
+        // The logs must be received, or the execution thread stops
+        let (mut context, _logs) = fixtures::execution_context(api.clone(), storage.clone());
+        context.dead_letters = Some(dead_letters.clone());
+        let immediate_sender = Arc::new(PoolSender::unbounded().0);
+        let (executor, threads) = Executor::new(
+            thread_pools,
+            modules.clone(),
+            context,
+            Arc::downgrade(&immediate_sender),
+            None,
+            None,
+        );
+
+        // The message is kept once the module failed to process it
+        executor.execute_webhook_message(message()).unwrap();
+        let dead_letters_before = wait_for_new(&dead_letters, &api, &[]);
+        assert_eq!(dead_letters_before.len(), 1);
+        let failed = &dead_letters_before[0];
+        assert_eq!(failed.module, "failing.wasm");
+        assert_eq!(failed.message.data, b"data");
+
+        // Replaying it removes it, and the module fails again so it is kept again
+        api.runtime
+            .block_on(dead_letters.replay(&failed.id, &modules, &executor))
+            .unwrap();
+        let dead_letters_after =
+            wait_for_new(&dead_letters, &api, std::slice::from_ref(&failed.id));
+        assert_eq!(dead_letters_after.len(), 1);
+        assert_eq!(dead_letters_after[0].module, "failing.wasm");
+
+        assert!(matches!(
+            api.runtime
+                .block_on(dead_letters.replay(&failed.id, &modules, &executor)),
+            Err(DeadLetterError::NotFound(_))
+        ));
+
+        drop(executor);
+        threads.join();
+    }
+
+    #[tokio::test]
+    async fn expired_dead_letters_are_removed() {
+        let storage = Arc::new(Storage::new_in_memory());
+        let queue = queue(storage.clone());
+
+        queue
+            .record("module.wasm", "error".to_string(), message())
+            .await;
+        let expired = DeadLetter {
+            id: "expired".to_string(),
+            module: "module.wasm".to_string(),
+            error: "error".to_string(),
+            timestamp: get_time() - 61,
+            message: message(),
+        };
+        storage
+            .insert(
+                DEAD_LETTER_NS.to_string(),
+                expired.id.clone(),
+                serde_json::to_vec(&expired).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(queue.list().await.unwrap().len(), 2);
+
+        queue.purge_expired().await.unwrap();
+        let remaining = queue.list().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_ne!(remaining[0].id, "expired");
+    }
+}
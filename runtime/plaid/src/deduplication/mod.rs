@@ -0,0 +1,361 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Deserialize;
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+
+use crate::cache::{Cache, CacheError};
+use crate::storage::{Storage, StorageError};
+
+/// The storage namespace and cache namespace where the keys of received messages are kept
+const DEDUPLICATION_NS: &str = "webhook_deduplication_internal";
+/// How often expired keys are removed from storage
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+fn default_retention_secs() -> u64 {
+    // One day
+    24 * 60 * 60
+}
+
+/// Where the key identifying a delivery is read from
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum DeduplicationKey {
+    /// A header of the request, e.g. `X-GitHub-Delivery`. The header does not need to be
+    /// forwarded to modules.
+    Header(String),
+    /// A JSON pointer (RFC 6901) into the request's body, e.g. `/event_id`
+    JsonPointer(String),
+}
+
+/// Where the keys of the messages a webhook received are kept
+#[derive(Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeduplicationBackend {
+    /// Plaid's storage. With a persistent storage backend, this deduplicates messages across
+    /// all the instances using it.
+    #[default]
+    Storage,
+    /// Plaid's cache. Only a shared cache (Redis) can be used, and keys are only removed by
+    /// the cache's own eviction.
+    Cache,
+}
+
+/// Configuration for dropping the messages a webhook already received, e.g. because the
+/// sender retried a delivery
+#[derive(Deserialize, Clone)]
+pub struct DeduplicationConfig {
+    /// What identifies a delivery. Messages without a key are never dropped.
+    pub key: DeduplicationKey,
+    /// For how long after a message is received, in seconds, messages with the same key are
+    /// dropped. Defaults to one day.
+    #[serde(default = "default_retention_secs")]
+    pub retention_secs: u64,
+    /// Where received keys are kept. Defaults to `Storage`.
+    #[serde(default)]
+    pub backend: DeduplicationBackend,
+}
+
+impl DeduplicationConfig {
+    /// Get the key of a message from its body, or from one of the request's headers with
+    /// `header`, if it has one
+    pub fn key(&self, body: &[u8], header: impl Fn(&str) -> Option<String>) -> Option<String> {
+        match &self.key {
+            DeduplicationKey::Header(name) => header(name),
+            DeduplicationKey::JsonPointer(pointer) => {
+                let body: Value = serde_json::from_slice(body).ok()?;
+                match body.pointer(pointer)? {
+                    Value::String(s) => Some(s.clone()),
+                    Value::Null => None,
+                    other => Some(other.to_string()),
+                }
+            }
+        }
+    }
+}
+
+/// Errors encountered while checking whether a message was already received
+#[derive(Debug)]
+pub enum DeduplicationError {
+    StorageError(StorageError),
+    CacheError(CacheError),
+}
+
+impl std::fmt::Display for DeduplicationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StorageError(e) => write!(f, "Storage error: {e}"),
+            Self::CacheError(e) => write!(f, "Cache error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for DeduplicationError {}
+
+impl From<StorageError> for DeduplicationError {
+    fn from(e: StorageError) -> Self {
+        Self::StorageError(e)
+    }
+}
+
+impl From<CacheError> for DeduplicationError {
+    fn from(e: CacheError) -> Self {
+        Self::CacheError(e)
+    }
+}
+
+/// Keeps track of the keys of the messages webhooks received, so the ones received again can
+/// be dropped. A key is stored with when it expires, and writing it returns the previous
+/// value, so when several instances receive the same message at once only one processes it.
+/// Duplicates do not write the key, so the retention period starts with the first message.
+pub struct WebhookDeduplicator {
+    storage: Arc<Storage>,
+    cache: Option<Arc<Cache>>,
+}
+
+impl WebhookDeduplicator {
+    pub fn new(storage: Arc<Storage>, cache: Option<Arc<Cache>>) -> Self {
+        Self { storage, cache }
+    }
+
+    /// Record that `webhook` received a message with `key`. Returns whether it already received
+    /// one with the same key within the retention period.
+    pub async fn check_and_record(
+        &self,
+        webhook: &str,
+        key: &str,
+        config: &DeduplicationConfig,
+    ) -> Result<bool, DeduplicationError> {
+        let now = get_time();
+        let entry_key = entry_key(webhook, key);
+        let expired =
+            |expires_at: Option<u64>| expires_at.is_none_or(|expires_at| expires_at <= now);
+        if !expired(self.get(config.backend, &entry_key).await?) {
+            return Ok(true);
+        }
+
+        // Another instance can record the key between the read and the write
+        let previous = self
+            .put(config.backend, &entry_key, now + config.retention_secs)
+            .await?;
+        Ok(!expired(previous))
+    }
+
+    /// Forget that `webhook` received a message with `key`, e.g. because it could not be
+    /// processed and the sender must be able to retry it
+    pub async fn forget(
+        &self,
+        webhook: &str,
+        key: &str,
+        config: &DeduplicationConfig,
+    ) -> Result<(), DeduplicationError> {
+        let entry_key = entry_key(webhook, key);
+        match config.backend {
+            DeduplicationBackend::Storage => {
+                self.storage.delete(DEDUPLICATION_NS, &entry_key).await?;
+            }
+            // The cache cannot delete entries, so the key is marked as expired instead
+            DeduplicationBackend::Cache => {
+                self.put(DeduplicationBackend::Cache, &entry_key, 0).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get when a key expires, if it is stored
+    async fn get(
+        &self,
+        backend: DeduplicationBackend,
+        entry_key: &str,
+    ) -> Result<Option<u64>, DeduplicationError> {
+        let expires_at = match backend {
+            DeduplicationBackend::Storage => self
+                .storage
+                .get(DEDUPLICATION_NS, entry_key)
+                .await?
+                .and_then(|expires_at| String::from_utf8(expires_at).ok()),
+            DeduplicationBackend::Cache => {
+                let cache = self.cache.as_ref().ok_or(CacheError::NoCacheConfigured)?;
+                cache.get(DEDUPLICATION_NS, entry_key).await?
+            }
+        };
+        Ok(expires_at.and_then(|expires_at| expires_at.parse().ok()))
+    }
+
+    /// Store when a key expires, and get when it previously expired if it was already stored
+    async fn put(
+        &self,
+        backend: DeduplicationBackend,
+        entry_key: &str,
+        expires_at: u64,
+    ) -> Result<Option<u64>, DeduplicationError> {
+        let previous = match backend {
+            DeduplicationBackend::Storage => self
+                .storage
+                .insert(
+                    DEDUPLICATION_NS.to_string(),
+                    entry_key.to_string(),
+                    expires_at.to_string().into_bytes(),
+                )
+                .await?
+                .and_then(|previous| String::from_utf8(previous).ok()),
+            DeduplicationBackend::Cache => {
+                let cache = self.cache.as_ref().ok_or(CacheError::NoCacheConfigured)?;
+                cache.put(DEDUPLICATION_NS, entry_key, expires_at).await?
+            }
+        };
+        Ok(previous.and_then(|previous| previous.parse().ok()))
+    }
+
+    async fn purge_expired(&self) -> Result<(), DeduplicationError> {
+        let now = get_time();
+        for (key, value) in self.storage.fetch_all(DEDUPLICATION_NS, None).await? {
+            let expires_at = value
+                .and_then(|value| String::from_utf8(value).ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or_default();
+            if expires_at <= now {
+                self.storage.delete(DEDUPLICATION_NS, &key).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Periodically remove expired keys from storage until the cancellation token is cancelled
+    pub async fn purge_expired_periodically(
+        self: Arc<Self>,
+        cancellation_token: CancellationToken,
+    ) {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {
+                    if let Err(e) = self.purge_expired().await {
+                        error!("Failed to remove expired webhook deduplication keys: {e}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Keys are kept per webhook, since different senders can use the same keys
+fn entry_key(webhook: &str, key: &str) -> String {
+    format!("{webhook}/{key}")
+}
+
+fn get_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(key: DeduplicationKey) -> DeduplicationConfig {
+        DeduplicationConfig {
+            key,
+            retention_secs: 60,
+            backend: DeduplicationBackend::Storage,
+        }
+    }
+
+    #[test]
+    fn keys_are_read_from_headers_and_body() {
+        let header = |name: &str| (name == "x-github-delivery").then(|| "abc".to_string());
+        let by_header = config(DeduplicationKey::Header("x-github-delivery".to_string()));
+        assert_eq!(by_header.key(b"", header).as_deref(), Some("abc"));
+
+        let by_pointer = config(DeduplicationKey::JsonPointer("/event/id".to_string()));
+        let no_header = |_: &str| None;
+        assert_eq!(
+            by_pointer
+                .key(br#"{"event": {"id": "evt_1"}}"#, no_header)
+                .as_deref(),
+            Some("evt_1")
+        );
+        assert_eq!(
+            by_pointer
+                .key(br#"{"event": {"id": 42}}"#, no_header)
+                .as_deref(),
+            Some("42")
+        );
+        assert_eq!(by_pointer.key(br#"{"event": {}}"#, no_header), None);
+        assert_eq!(by_pointer.key(b"not json", no_header), None);
+    }
+
+    #[tokio::test]
+    async fn messages_received_again_are_duplicates() {
+        let deduplicator = WebhookDeduplicator::new(Arc::new(Storage::new_in_memory()), None);
+        let config = config(DeduplicationKey::Header("x-github-delivery".to_string()));
+
+        assert!(!deduplicator
+            .check_and_record("github", "abc", &config)
+            .await
+            .unwrap());
+        assert!(deduplicator
+            .check_and_record("github", "abc", &config)
+            .await
+            .unwrap());
+        // Keys are per webhook
+        assert!(!deduplicator
+            .check_and_record("other", "abc", &config)
+            .await
+            .unwrap());
+
+        deduplicator.forget("github", "abc", &config).await.unwrap();
+        assert!(!deduplicator
+            .check_and_record("github", "abc", &config)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn duplicates_do_not_extend_the_retention_period() {
+        let storage = Arc::new(Storage::new_in_memory());
+        let deduplicator = WebhookDeduplicator::new(storage.clone(), None);
+        let config = config(DeduplicationKey::Header("x-github-delivery".to_string()));
+        let expires_at = || async {
+            deduplicator
+                .get(DeduplicationBackend::Storage, &entry_key("github", "abc"))
+                .await
+                .unwrap()
+        };
+
+        // The key was received half of the retention period ago
+        let first = get_time() + 30;
+        storage
+            .insert(
+                DEDUPLICATION_NS.to_string(),
+                entry_key("github", "abc"),
+                first.to_string().into_bytes(),
+            )
+            .await
+            .unwrap();
+        assert!(deduplicator
+            .check_and_record("github", "abc", &config)
+            .await
+            .unwrap());
+        assert_eq!(expires_at().await, Some(first));
+
+        // Once it expired, the next message is not a duplicate and is kept for the whole period
+        storage
+            .insert(
+                DEDUPLICATION_NS.to_string(),
+                entry_key("github", "abc"),
+                b"0".to_vec(),
+            )
+            .await
+            .unwrap();
+        assert!(!deduplicator
+            .check_and_record("github", "abc", &config)
+            .await
+            .unwrap());
+        assert!(expires_at().await.unwrap() > first);
+    }
+}
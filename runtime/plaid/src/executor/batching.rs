@@ -0,0 +1,364 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use plaid_stl::messages::{BatchItem, BatchResult};
+use serde::Deserialize;
+
+use super::{Message, ModuleExecutionError};
+
+/// How a module receives the messages of its log type in batches. A batch is delivered as
+/// soon as it has `max_messages` messages, or once its oldest message has waited for
+/// `max_wait_ms` milliseconds.
+#[derive(Deserialize, Clone)]
+pub struct BatchConfig {
+    /// The most messages in a batch
+    pub max_messages: NonZeroUsize,
+    /// The longest a message waits for its batch to fill up
+    pub max_wait_ms: u64,
+}
+
+/// A batch which is not full yet
+struct PendingBatch {
+    /// When the batch is delivered, even if it is not full
+    due: Instant,
+    messages: Vec<Message>,
+}
+
+#[derive(Default)]
+struct State {
+    /// The batches being filled, by module
+    batches: HashMap<String, PendingBatch>,
+    /// The messages from the write-ahead log which are still being processed, with how many
+    /// threads and batches are holding them
+    holds: HashMap<String, usize>,
+}
+
+/// The batches being filled by the threads of a thread pool. Messages of a log type always
+/// go to the same pool, so the pool's threads are the only ones that can fill its batches.
+#[derive(Default)]
+pub struct PendingBatches {
+    state: Mutex<State>,
+}
+
+impl PendingBatches {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Add a message to a module's batch. The messages of a batch share their headers, query
+    /// parameters and source, since the module sees them once for the whole batch, so a
+    /// message which differs closes the module's batch and starts the next one. Returns the
+    /// batches which are ready: the closed one, and the new one if it is full.
+    pub fn add(&self, module: &str, config: &BatchConfig, message: Message) -> Vec<Vec<Message>> {
+        let mut state = self.lock();
+        let mut ready = vec![];
+        if let Some(batch) = state.batches.get(module) {
+            if !in_same_batch(&batch.messages[0], &message) {
+                ready.extend(state.batches.remove(module).map(|batch| batch.messages));
+            }
+        }
+
+        let batch = state
+            .batches
+            .entry(module.to_string())
+            .or_insert_with(|| PendingBatch {
+                due: Instant::now() + Duration::from_millis(config.max_wait_ms),
+                messages: vec![],
+            });
+        batch.messages.push(message);
+
+        if batch.messages.len() >= config.max_messages.get() {
+            ready.extend(state.batches.remove(module).map(|batch| batch.messages));
+        }
+        ready
+    }
+
+    /// Take the batches which waited long enough, by module
+    pub fn take_due(&self) -> Vec<(String, Vec<Message>)> {
+        let now = Instant::now();
+        let mut state = self.lock();
+        let due: Vec<String> = state
+            .batches
+            .iter()
+            .filter(|(_, batch)| batch.due <= now)
+            .map(|(module, _)| module.clone())
+            .collect();
+
+        due.into_iter()
+            .filter_map(|module| {
+                let batch = state.batches.remove(&module)?;
+                Some((module, batch.messages))
+            })
+            .collect()
+    }
+
+    /// Take all the batches, by module, whether they waited long enough or not
+    pub fn take_all(&self) -> Vec<(String, Vec<Message>)> {
+        self.lock()
+            .batches
+            .drain()
+            .map(|(module, batch)| (module, batch.messages))
+            .collect()
+    }
+
+    /// How long until the next batch is due, if there is one
+    pub fn next_due(&self) -> Option<Duration> {
+        let now = Instant::now();
+        self.lock()
+            .batches
+            .values()
+            .map(|batch| batch.due.saturating_duration_since(now))
+            .min()
+    }
+
+    /// Keep a message in the write-ahead log until it is released as many times as it is held
+    pub fn hold(&self, id: &str) {
+        *self.lock().holds.entry(id.to_string()).or_default() += 1;
+    }
+
+    /// Release a message held with [`Self::hold`]. Returns whether it is not held anymore, in
+    /// which case it can be removed from the write-ahead log.
+    pub fn release(&self, id: &str) -> bool {
+        let mut state = self.lock();
+        let Some(holds) = state.holds.get_mut(id) else {
+            return true;
+        };
+        *holds -= 1;
+        if *holds > 0 {
+            return false;
+        }
+        state.holds.remove(id);
+        true
+    }
+}
+
+/// Whether two messages can be delivered in the same batch
+fn in_same_batch(first: &Message, other: &Message) -> bool {
+    first.headers == other.headers
+        && first.query_params == other.query_params
+        && first.source == other.source
+}
+
+/// Check that a message can be delivered in a batch. Its log is a string in the batch's
+/// JSON, so it has to be valid UTF-8.
+pub fn check_batchable(message: &Message) -> Result<(), ModuleExecutionError> {
+    std::str::from_utf8(&message.data)
+        .map(|_| ())
+        .map_err(|e| ModuleExecutionError::NotBatchable(format!("the log is not valid UTF-8: {e}")))
+}
+
+/// Build the message a module receives for a batch. Its data is the list of the batch's
+/// messages, as [`BatchItem`]s in JSON. Everything else, like the logback budget, is taken
+/// from the first message of the batch. The headers, query parameters and source are the same
+/// for every message of the batch.
+///
+/// Every message must have passed [`check_batchable`].
+pub fn batch_message(messages: &[Message]) -> Message {
+    let items: Vec<BatchItem> = messages
+        .iter()
+        .map(|message| BatchItem {
+            id: message.id.clone(),
+            log: String::from_utf8(message.data.clone())
+                .expect("batched logs are checked to be valid UTF-8"),
+            source: message.source.clone(),
+        })
+        .collect();
+
+    let mut batch = messages[0].create_duplicate();
+    batch.id = uuid::Uuid::new_v4().to_string();
+    batch.data = serde_json::to_vec(&items).expect("batch items can always be serialized");
+    batch
+}
+
+/// The messages of a batch which the module failed to process, with their error. Results are
+/// matched to messages by ID: a message the module reported no result for failed, and so did
+/// every message of the batch if the module's results could not be read.
+pub fn batch_failures(
+    batch: &[Message],
+    results: Option<Result<Vec<BatchResult>, String>>,
+) -> Vec<(&Message, String)> {
+    let (mut results, missing): (HashMap<String, Option<String>>, String) = match results {
+        Some(Ok(results)) => (
+            results
+                .into_iter()
+                .map(|result| (result.id, result.error))
+                .collect(),
+            "The module reported no result for this message".to_string(),
+        ),
+        Some(Err(e)) => (
+            HashMap::new(),
+            format!("The module's batch results could not be read: {e}"),
+        ),
+        None => (
+            HashMap::new(),
+            "The module reported no results for its batch".to_string(),
+        ),
+    };
+
+    batch
+        .iter()
+        .filter_map(|message| match results.remove(&message.id) {
+            Some(None) => None,
+            Some(Some(error)) => Some((message, error)),
+            None => Some((message, missing.clone())),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::executor::process_message_with_module;
+    use crate::loader::PlaidModules;
+    use crate::storage::Storage;
+    use crate::testing::fixtures;
+
+    fn message(data: &str) -> Message {
+        fixtures::message("test", data.as_bytes())
+    }
+
+    #[test]
+    fn batches_are_delivered_when_full_or_due() {
+        let batches = PendingBatches::default();
+        let config = BatchConfig {
+            max_messages: NonZeroUsize::new(2).unwrap(),
+            max_wait_ms: 0,
+        };
+        assert!(batches.next_due().is_none());
+
+        assert!(batches.add("first", &config, message("a")).is_empty());
+        let full = batches.add("first", &config, message("b")).pop().unwrap();
+        assert_eq!(full.len(), 2);
+        assert!(batches.take_due().is_empty());
+
+        assert!(batches.add("second", &config, message("c")).is_empty());
+        assert_eq!(batches.next_due(), Some(Duration::ZERO));
+        let due = batches.take_due();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].0, "second");
+
+        let batch = batch_message(&full);
+        let items: Vec<BatchItem> = serde_json::from_slice(&batch.data).unwrap();
+        let logs: Vec<&str> = items.iter().map(|item| item.log.as_str()).collect();
+        assert_eq!(logs, vec!["a", "b"]);
+        assert_eq!(items[1].id, full[1].id);
+    }
+
+    #[test]
+    fn messages_with_different_headers_are_not_batched_together() {
+        let batches = PendingBatches::default();
+        let config = BatchConfig {
+            max_messages: NonZeroUsize::new(3).unwrap(),
+            max_wait_ms: 60_000,
+        };
+        let with_header = |data: &str, value: &str| {
+            let mut message = message(data);
+            message
+                .headers
+                .insert("x-tenant".to_string(), value.as_bytes().to_vec());
+            message
+        };
+
+        assert!(batches
+            .add("module", &config, with_header("a", "one"))
+            .is_empty());
+        assert!(batches
+            .add("module", &config, with_header("b", "one"))
+            .is_empty());
+        let closed = batches.add("module", &config, with_header("c", "two"));
+        assert_eq!(closed.len(), 1);
+        let logs: Vec<&[u8]> = closed[0].iter().map(|m| m.data.as_slice()).collect();
+        assert_eq!(logs, vec![b"a", b"b"]);
+        assert_eq!(
+            batch_message(&closed[0]).headers.get("x-tenant"),
+            Some(&b"one".to_vec())
+        );
+
+        let pending = batches.take_all();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].1.len(), 1);
+        assert_eq!(
+            batch_message(&pending[0].1).headers.get("x-tenant"),
+            Some(&b"two".to_vec())
+        );
+    }
+
+    #[test]
+    fn batch_results_are_matched_to_messages_by_id() {
+        let batch = vec![message("a"), message("b"), message("c")];
+        let result = |message: &Message, error: Option<&str>| BatchResult {
+            id: message.id.clone(),
+            error: error.map(str::to_string),
+        };
+        let failed_logs = |failures: Vec<(&Message, String)>| -> Vec<Vec<u8>> {
+            failures.into_iter().map(|(m, _)| m.data.clone()).collect()
+        };
+
+        // Out of order, with a result missing and one for a message not in the batch
+        let results = vec![
+            result(&batch[2], None),
+            result(&message("other"), Some("unknown")),
+            result(&batch[0], Some("failed")),
+        ];
+        let failures = batch_failures(&batch, Some(Ok(results)));
+        assert_eq!(failures[0].1, "failed");
+        assert_eq!(failed_logs(failures), vec![b"a".to_vec(), b"b".to_vec()]);
+
+        // Results which could not be read, or no results at all, fail the whole batch
+        let failures = batch_failures(&batch, Some(Err("malformed".to_string())));
+        assert!(failures[0].1.contains("malformed"));
+        assert_eq!(failures.len(), 3);
+        assert_eq!(batch_failures(&batch, None).len(), 3);
+    }
+
+    #[test]
+    fn held_messages_are_released_once() {
+        let batches = PendingBatches::default();
+        batches.hold("message");
+        batches.hold("message");
+        assert!(!batches.release("message"));
+        assert!(batches.release("message"));
+    }
+
+    #[test]
+    fn only_logs_which_are_valid_utf8_can_be_batched() {
+        assert!(check_batchable(&message("log")).is_ok());
+        let binary = fixtures::message("test", &[0xff, 0xfe]);
+        assert!(matches!(
+            check_batchable(&binary),
+            Err(ModuleExecutionError::NotBatchable(_))
+        ));
+    }
+
+    #[test]
+    fn a_get_request_in_a_batch_gets_its_response() {
+        let module = fixtures::wat_module(
+            "test",
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "entrypoint") (result i32) i32.const 0))"#,
+        );
+        let (context, _logs) =
+            fixtures::execution_context(fixtures::api(), Arc::new(Storage::new_in_memory()));
+        let (sender, mut response) = tokio::sync::oneshot::channel();
+        let mut request = message("a");
+        request.response_sender = Some(sender);
+
+        process_message_with_module(
+            batch_message(std::slice::from_ref(&request)),
+            Arc::new(module),
+            &context,
+            None,
+            Arc::new(PlaidModules::default()),
+            Some(vec![request]),
+        )
+        .unwrap_or_else(|e| panic!("{e}"));
+
+        // The module set no response, which the request is told instead of being dropped
+        assert!(matches!(response.try_recv(), Ok(None)));
+    }
+}
@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use plaid_stl::plaid::metrics::{MetricOperation, MetricUpdate};
+use prometheus::core::{Collector, Desc};
+use prometheus::proto::MetricFamily;
+use prometheus::{CounterVec, GaugeVec, HistogramOpts, HistogramVec, Opts};
+
+/// How many distinct label sets a metric can have if its module has no configured limit
+pub const DEFAULT_SERIES_LIMIT: usize = 100;
+/// How many different metrics a single module can define
+const MAX_METRICS_PER_MODULE: usize = 100;
+
+/// Why an update to a module's metric was not applied
+#[derive(Debug)]
+pub enum MetricRejection {
+    InvalidName(String),
+    InvalidLabelName(String),
+    TypeMismatch(String),
+    LabelMismatch(String),
+    NegativeIncrement(String),
+    TooManyMetrics,
+    TooManySeries(String, usize),
+    NameTaken(String),
+    PrometheusError(prometheus::Error),
+}
+
+impl std::fmt::Display for MetricRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "[{name}] is not a valid metric name"),
+            Self::InvalidLabelName(name) => write!(f, "[{name}] is not a valid label name"),
+            Self::TypeMismatch(name) => {
+                write!(f, "[{name}] was already used as a different type of metric")
+            }
+            Self::LabelMismatch(name) => {
+                write!(f, "[{name}] was already used with different label names")
+            }
+            Self::NegativeIncrement(name) => {
+                write!(
+                    f,
+                    "Counter [{name}] cannot be incremented by a negative value"
+                )
+            }
+            Self::TooManyMetrics => write!(
+                f,
+                "The module cannot define more than {MAX_METRICS_PER_MODULE} metrics"
+            ),
+            Self::TooManySeries(name, limit) => write!(
+                f,
+                "[{name}] already has {limit} label sets, which is the most the module is allowed"
+            ),
+            Self::NameTaken(name) => write!(
+                f,
+                "[{name}] has the same full name as a metric of another module"
+            ),
+            Self::PrometheusError(e) => write!(f, "Prometheus error: {e}"),
+        }
+    }
+}
+
+impl From<prometheus::Error> for MetricRejection {
+    fn from(e: prometheus::Error) -> Self {
+        Self::PrometheusError(e)
+    }
+}
+
+enum CustomMetric {
+    Counter(CounterVec),
+    Gauge(GaugeVec),
+    Histogram(HistogramVec),
+}
+
+impl CustomMetric {
+    fn new(
+        operation: &MetricOperation,
+        name: &str,
+        module: &str,
+        label_names: &[&str],
+    ) -> Result<Self, prometheus::Error> {
+        let help = format!("Custom metric emitted by module [{module}]");
+        Ok(match operation {
+            MetricOperation::IncrementCounter(_) => {
+                Self::Counter(CounterVec::new(Opts::new(name, help), label_names)?)
+            }
+            MetricOperation::SetGauge(_) => {
+                Self::Gauge(GaugeVec::new(Opts::new(name, help), label_names)?)
+            }
+            MetricOperation::ObserveHistogram(_) => Self::Histogram(HistogramVec::new(
+                HistogramOpts::new(name, help),
+                label_names,
+            )?),
+        })
+    }
+
+    fn apply(&self, operation: &MetricOperation, label_values: &[&str]) -> Option<()> {
+        match (self, operation) {
+            (Self::Counter(counter), MetricOperation::IncrementCounter(value)) => {
+                counter.with_label_values(label_values).inc_by(*value)
+            }
+            (Self::Gauge(gauge), MetricOperation::SetGauge(value)) => {
+                gauge.with_label_values(label_values).set(*value)
+            }
+            (Self::Histogram(histogram), MetricOperation::ObserveHistogram(value)) => {
+                histogram.with_label_values(label_values).observe(*value)
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        match self {
+            Self::Counter(counter) => counter.collect(),
+            Self::Gauge(gauge) => gauge.collect(),
+            Self::Histogram(histogram) => histogram.collect(),
+        }
+    }
+}
+
+struct RegisteredMetric {
+    metric: CustomMetric,
+    label_names: Vec<String>,
+    /// The label values of every series of this metric
+    series: HashSet<Vec<String>>,
+}
+
+/// The metrics that modules record themselves. Metric names are prefixed with the
+/// name of the module, so modules cannot collide with Plaid's own metrics. Two modules can
+/// still end up with the same full name (e.g. `a.wasm` recording `b_c` and `a_b.wasm`
+/// recording `c`), in which case the module that recorded it first keeps it.
+/// Metrics are created the first time they are updated, so this is a single collector whose
+/// contents change over time rather than a set of collectors registered up front.
+#[derive(Clone, Default)]
+pub struct ModuleMetrics {
+    /// Module name -> full metric name -> metric
+    modules: Arc<Mutex<HashMap<String, HashMap<String, RegisteredMetric>>>>,
+}
+
+impl ModuleMetrics {
+    /// Apply an update from `module` to one of its metrics. `series_limit` is how many
+    /// distinct label sets each of the module's metrics can have.
+    pub fn record(
+        &self,
+        module: &str,
+        series_limit: usize,
+        update: &MetricUpdate,
+    ) -> Result<(), MetricRejection> {
+        if !is_valid_name(&update.name) {
+            return Err(MetricRejection::InvalidName(update.name.clone()));
+        }
+        if let Some(label) = update
+            .labels
+            .keys()
+            .find(|label| !is_valid_name(label) || label.starts_with("__"))
+        {
+            return Err(MetricRejection::InvalidLabelName(label.clone()));
+        }
+        if let MetricOperation::IncrementCounter(value) = update.operation {
+            if value < 0.0 {
+                return Err(MetricRejection::NegativeIncrement(update.name.clone()));
+            }
+        }
+
+        let full_name = format!("plaid_custom_{}_{}", module_prefix(module), update.name);
+        // Labels are sorted by name, so the same label set always gives the same values
+        let label_names: Vec<&str> = update.labels.keys().map(String::as_str).collect();
+        let label_values: Vec<&str> = update.labels.values().map(String::as_str).collect();
+
+        let mut modules = self.modules.lock().unwrap_or_else(|e| e.into_inner());
+        let is_new = !modules
+            .get(module)
+            .is_some_and(|metrics| metrics.contains_key(&full_name));
+        if is_new
+            && modules
+                .values()
+                .any(|metrics| metrics.contains_key(&full_name))
+        {
+            return Err(MetricRejection::NameTaken(update.name.clone()));
+        }
+        let metrics = modules.entry(module.to_string()).or_default();
+
+        let metric = match metrics.get_mut(&full_name) {
+            Some(metric) => metric,
+            None => {
+                if metrics.len() >= MAX_METRICS_PER_MODULE {
+                    return Err(MetricRejection::TooManyMetrics);
+                }
+                let metric =
+                    CustomMetric::new(&update.operation, &full_name, module, &label_names)?;
+                metrics
+                    .entry(full_name.clone())
+                    .or_insert(RegisteredMetric {
+                        metric,
+                        label_names: label_names.iter().map(|name| name.to_string()).collect(),
+                        series: HashSet::new(),
+                    })
+            }
+        };
+
+        if metric.label_names != label_names {
+            return Err(MetricRejection::LabelMismatch(update.name.clone()));
+        }
+        let series: Vec<String> = label_values.iter().map(|value| value.to_string()).collect();
+        if !metric.series.contains(&series) && metric.series.len() >= series_limit {
+            return Err(MetricRejection::TooManySeries(
+                update.name.clone(),
+                series_limit,
+            ));
+        }
+
+        metric
+            .metric
+            .apply(&update.operation, &label_values)
+            .ok_or_else(|| MetricRejection::TypeMismatch(update.name.clone()))?;
+        metric.series.insert(series);
+        Ok(())
+    }
+}
+
+impl Collector for ModuleMetrics {
+    fn desc(&self) -> Vec<&Desc> {
+        // The metrics are not known in advance so they cannot be described
+        vec![]
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        let modules = self.modules.lock().unwrap_or_else(|e| e.into_inner());
+        modules
+            .values()
+            .flat_map(|metrics| metrics.values())
+            .flat_map(|metric| metric.metric.collect())
+            .collect()
+    }
+}
+
+/// Turn a module's filename into something that can be part of a metric name
+/// (e.g. `my-rule.wasm` becomes `my_rule`)
+fn module_prefix(module: &str) -> String {
+    module
+        .trim_end_matches(".wasm")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn update(name: &str, labels: &[(&str, &str)], operation: MetricOperation) -> MetricUpdate {
+        MetricUpdate {
+            name: name.to_string(),
+            labels: labels
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<_, _>>(),
+            operation,
+        }
+    }
+
+    #[test]
+    fn metrics_are_prefixed_with_the_module() {
+        let metrics = ModuleMetrics::default();
+        metrics
+            .record(
+                "my-rule.wasm",
+                10,
+                &update(
+                    "events_total",
+                    &[("kind", "push")],
+                    MetricOperation::IncrementCounter(2.0),
+                ),
+            )
+            .unwrap();
+
+        let families = metrics.collect();
+        assert_eq!(families.len(), 1);
+        assert_eq!(families[0].name(), "plaid_custom_my_rule_events_total");
+        assert_eq!(families[0].get_metric()[0].get_counter().value(), 2.0);
+    }
+
+    #[test]
+    fn mismatched_updates_are_rejected() {
+        let metrics = ModuleMetrics::default();
+        let record = |update: MetricUpdate| metrics.record("rule.wasm", 10, &update);
+
+        record(update(
+            "queue",
+            &[("a", "1")],
+            MetricOperation::SetGauge(1.0),
+        ))
+        .unwrap();
+        assert!(matches!(
+            record(update(
+                "queue",
+                &[("a", "1")],
+                MetricOperation::ObserveHistogram(1.0)
+            )),
+            Err(MetricRejection::TypeMismatch(_))
+        ));
+        assert!(matches!(
+            record(update(
+                "queue",
+                &[("b", "1")],
+                MetricOperation::SetGauge(1.0)
+            )),
+            Err(MetricRejection::LabelMismatch(_))
+        ));
+        assert!(matches!(
+            record(update("9lives", &[], MetricOperation::SetGauge(1.0))),
+            Err(MetricRejection::InvalidName(_))
+        ));
+        assert!(matches!(
+            record(update(
+                "count",
+                &[],
+                MetricOperation::IncrementCounter(-1.0)
+            )),
+            Err(MetricRejection::NegativeIncrement(_))
+        ));
+    }
+
+    #[test]
+    fn series_are_limited() {
+        let metrics = ModuleMetrics::default();
+        let record = |user: &str| {
+            metrics.record(
+                "rule.wasm",
+                2,
+                &update(
+                    "logins_total",
+                    &[("user", user)],
+                    MetricOperation::IncrementCounter(1.0),
+                ),
+            )
+        };
+
+        record("alice").unwrap();
+        record("bob").unwrap();
+        assert!(matches!(
+            record("carol"),
+            Err(MetricRejection::TooManySeries(_, 2))
+        ));
+        // Existing series can still be updated
+        record("alice").unwrap();
+    }
+
+    #[test]
+    fn modules_cannot_take_each_others_names() {
+        let metrics = ModuleMetrics::default();
+        let record = |module: &str, name: &str| {
+            metrics.record(
+                module,
+                10,
+                &update(name, &[], MetricOperation::IncrementCounter(1.0)),
+            )
+        };
+
+        record("a.wasm", "b_c").unwrap();
+        assert!(matches!(
+            record("a_b.wasm", "c"),
+            Err(MetricRejection::NameTaken(_))
+        ));
+        assert!(matches!(
+            record("a-b.wasm", "c"),
+            Err(MetricRejection::NameTaken(_))
+        ));
+        // The module which has the name can keep using it
+        record("a.wasm", "b_c").unwrap();
+
+        let families = metrics.collect();
+        assert_eq!(families.len(), 1);
+        assert_eq!(families[0].get_metric()[0].get_counter().value(), 2.0);
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use plaid_stl::messages::{LogSource, LogbacksAllowed};
+use wasmer::sys::{FunctionMiddleware, MiddlewareError, ModuleMiddleware};
+use wasmer::{
+    ExportIndex, FunctionEnv, Global, Instance, LocalFunctionIndex, Mutability, Store,
+    TypedFunction, Value,
+};
+use wasmer_middlewares::metering::set_remaining_points;
+use wasmer_types::ModuleInfo;
+
+use super::{Env, Message};
+use crate::loader::{PlaidModule, PlaidModules};
+
+/// The prefix of the names under which [`ExportMutableGlobals`] exports globals
+const SNAPSHOT_GLOBAL_PREFIX: &str = "plaid_snapshot_global_";
+
+/// The module the environment of an idle instance points to instead of its own. An idle
+/// instance is owned by its module's pool, so it must not keep its module alive.
+static VACANT_MODULE: LazyLock<Arc<PlaidModule>> = LazyLock::new(|| {
+    let empty = b"\0asm\x01\0\0\0".to_vec();
+    let module = PlaidModule::compile_standalone("vacant", "vacant", empty, 0, 0)
+        .expect("an empty module always compiles");
+    Arc::new(module)
+});
+
+/// A middleware which exports every mutable global a module defines, so that an instance's
+/// snapshot can see the ones the module does not export itself
+#[derive(Debug)]
+pub struct ExportMutableGlobals;
+
+#[derive(Debug)]
+struct Unchanged;
+
+impl FunctionMiddleware for Unchanged {}
+
+impl ModuleMiddleware for ExportMutableGlobals {
+    fn generate_function_middleware(&self, _: LocalFunctionIndex) -> Box<dyn FunctionMiddleware> {
+        Box::new(Unchanged)
+    }
+
+    fn transform_module_info(&self, module_info: &mut ModuleInfo) -> Result<(), MiddlewareError> {
+        let exported: HashSet<_> = module_info
+            .exports
+            .values()
+            .filter_map(|export| match export {
+                ExportIndex::Global(index) => Some(*index),
+                _ => None,
+            })
+            .collect();
+        let unexported: Vec<_> = module_info
+            .globals
+            .iter()
+            .filter(|(index, global)| {
+                module_info.local_global_index(*index).is_some()
+                    && global.mutability == Mutability::Var
+                    && !exported.contains(index)
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        for index in unexported {
+            module_info.exports.insert(
+                format!("{SNAPSHOT_GLOBAL_PREFIX}{}", index.as_u32()),
+                ExportIndex::Global(index),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// An instance of a module which is linked, instantiated and ready to be called
+pub struct PreparedInstance {
+    pub store: Store,
+    pub instance: Instance,
+    pub entrypoint: TypedFunction<(), i32>,
+    pub env: FunctionEnv<Env>,
+    /// The state of the instance right after it was instantiated. Instances which have
+    /// a snapshot can be reset and reused for another message.
+    snapshot: Option<InstanceSnapshot>,
+}
+
+/// What is needed to bring an instance back to the state it was in right after instantiation
+struct InstanceSnapshot {
+    memory: Vec<u8>,
+    globals: Vec<(Global, Value)>,
+}
+
+impl PreparedInstance {
+    pub fn new(
+        store: Store,
+        instance: Instance,
+        entrypoint: TypedFunction<(), i32>,
+        env: FunctionEnv<Env>,
+    ) -> Self {
+        Self {
+            store,
+            instance,
+            entrypoint,
+            env,
+            snapshot: None,
+        }
+    }
+
+    /// Record the current state of the instance's memory and mutable globals so the
+    /// instance can be reset to it later. This must be called before the instance is run.
+    /// Modules are compiled with [`ExportMutableGlobals`], so every mutable global is exported.
+    pub fn take_snapshot(&mut self) {
+        let Some(memory) = self.env.as_ref(&self.store).memory.clone() else {
+            return;
+        };
+        let memory = match memory.view(&self.store).copy_to_vec() {
+            Ok(memory) => memory,
+            Err(e) => {
+                warn!("Failed to snapshot module memory, the instance will not be reused: {e}");
+                return;
+            }
+        };
+
+        let globals: Vec<Global> = self
+            .instance
+            .exports
+            .iter()
+            .globals()
+            .filter(|(_, global)| global.ty(&self.store).mutability == Mutability::Var)
+            .map(|(_, global)| global.clone())
+            .collect();
+        let globals = globals
+            .into_iter()
+            .map(|global| {
+                let value = global.get(&mut self.store);
+                (global, value)
+            })
+            .collect();
+
+        self.snapshot = Some(InstanceSnapshot { memory, globals });
+    }
+
+    /// Bring the instance back to the state of its snapshot and give it a fresh computation
+    /// budget. Returns `false` if the instance cannot be reset, in which case it must not be reused.
+    fn reset(&mut self, computation_limit: u64) -> bool {
+        let Some(snapshot) = &self.snapshot else {
+            return false;
+        };
+        let Some(memory) = self.env.as_ref(&self.store).memory.clone() else {
+            return false;
+        };
+
+        // Memory cannot shrink, so an instance whose memory grew cannot be brought back
+        let view = memory.view(&self.store);
+        if view.data_size() != snapshot.memory.len() as u64 {
+            return false;
+        }
+        if view.write(0, &snapshot.memory).is_err() {
+            return false;
+        }
+
+        for (global, value) in &snapshot.globals {
+            if global.set(&mut self.store, value.clone()).is_err() {
+                return false;
+            }
+        }
+
+        set_remaining_points(&mut self.store, &self.instance, computation_limit);
+        true
+    }
+}
+
+/// A pool of idle instances of a module. Linking and instantiating a module is often more
+/// expensive than running it, so modules which process a lot of small messages can keep
+/// instances around and only reset their state between messages.
+pub struct InstancePool {
+    /// How many idle instances are kept at most
+    capacity: usize,
+    /// The computation budget an instance is given when it is reset
+    computation_limit: u64,
+    idle: Mutex<Vec<PreparedInstance>>,
+}
+
+impl InstancePool {
+    pub fn new(capacity: usize, computation_limit: u64) -> Self {
+        Self {
+            capacity,
+            computation_limit,
+            idle: Mutex::new(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Take an idle instance out of the pool and reset it, if there is one. Instances which
+    /// cannot be reset are dropped.
+    pub fn take(&self) -> Option<PreparedInstance> {
+        loop {
+            let mut instance = self.idle.lock().unwrap_or_else(|e| e.into_inner()).pop()?;
+            if instance.reset(self.computation_limit) {
+                return Some(instance);
+            }
+        }
+    }
+
+    /// Keep an instance which ran to completion for a later message. The instance is
+    /// dropped instead if it has no snapshot to be reset to or if the pool is already full.
+    pub fn put_back(&self, mut instance: PreparedInstance) {
+        if instance.snapshot.is_none() {
+            return;
+        }
+        vacate(instance.env.as_mut(&mut instance.store));
+
+        let mut idle = self.idle.lock().unwrap_or_else(|e| e.into_inner());
+        if idle.len() < self.capacity {
+            idle.push(instance);
+        }
+    }
+}
+
+/// Let go of everything the environment of an instance holds for the message it processed.
+/// The pool is owned by the module, so an idle environment which held the module, or the
+/// modules it could invoke, would keep them alive once they are unloaded. The environment
+/// is built again when the instance is taken out of the pool.
+fn vacate(env: &mut Env) {
+    env.module = VACANT_MODULE.clone();
+    env.modules = Arc::new(PlaidModules::default());
+    env.message = Message::new(
+        String::new(),
+        vec![],
+        LogSource::Logback(String::new()),
+        LogbacksAllowed::Limited(0),
+    );
+    env.storage = None;
+    env.cache = None;
+    env.immediate_sender = None;
+    env.response = None;
+    env.execution_error_context = None;
+    env.invocation_response = None;
+    env.side_effects = None;
+    env.storage_before_writes.clear();
+    env.batch_results = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::executor::process_message_with_module;
+    use crate::loader::{PlaidModule, PlaidModules};
+    use crate::storage::Storage;
+    use crate::testing::fixtures::{api, execution_context, message, wat_module};
+
+    /// A module which changes its memory and two mutable globals, one of which it does not
+    /// export, and then does what the first byte of the message says: grow its memory (g),
+    /// trap (t), return an error (e) or nothing else.
+    const MODULE: &str = r#"(module
+        (import "env" "fetch_data" (func $fetch_data (param i32 i32) (result i32)))
+        (memory (export "memory") 1)
+        (global $counter (export "counter") (mut i32) (i32.const 0))
+        (global $hidden (mut i32) (i32.const 0))
+        (func (export "entrypoint") (result i32)
+            (drop (call $fetch_data (i32.const 100) (i32.const 1)))
+            (i32.store8 (i32.const 0) (i32.const 1))
+            (global.set $counter (i32.const 1))
+            (global.set $hidden (i32.const 1))
+            (if (i32.eq (i32.load8_u (i32.const 100)) (i32.const 103))
+                (then (drop (memory.grow (i32.const 1)))))
+            (if (i32.eq (i32.load8_u (i32.const 100)) (i32.const 116))
+                (then unreachable))
+            (i32.eq (i32.load8_u (i32.const 100)) (i32.const 101))))"#;
+
+    fn pooled_module() -> Arc<PlaidModule> {
+        let mut module = wat_module("pooled", MODULE);
+        module.instance_pool = Some(InstancePool::new(2, module.computation_limit));
+        Arc::new(module)
+    }
+
+    /// Run the module on a message with `data`, like the execution loop does
+    fn run(module: &Arc<PlaidModule>, data: &str) {
+        let (context, _logs) = execution_context(api(), Arc::new(Storage::new_in_memory()));
+        process_message_with_module(
+            message(&module.logtype, data.as_bytes()),
+            module.clone(),
+            &context,
+            None,
+            Arc::new(PlaidModules::default()),
+            None,
+        )
+        .unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    fn idle(module: &PlaidModule) -> usize {
+        let pool = module.instance_pool.as_ref().unwrap();
+        pool.idle.lock().unwrap().len()
+    }
+
+    /// Take an instance out of the pool and check it is back to its initial state
+    fn assert_reset(module: &PlaidModule) {
+        let mut instance = module.instance_pool.as_ref().unwrap().take().unwrap();
+        let memory = instance.env.as_ref(&instance.store).memory.clone().unwrap();
+        let mut changed = [0u8; 1];
+        let mut message = [0u8; 1];
+        let view = memory.view(&instance.store);
+        view.read(0, &mut changed).unwrap();
+        view.read(100, &mut message).unwrap();
+        assert_eq!((changed[0], message[0]), (0, 0));
+
+        for name in ["counter", &format!("{SNAPSHOT_GLOBAL_PREFIX}1")] {
+            let global = instance.instance.exports.get_global(name).unwrap();
+            assert_eq!(global.get(&mut instance.store), Value::I32(0));
+        }
+    }
+
+    #[test]
+    fn changed_memory_and_globals_are_reset() {
+        let module = pooled_module();
+        run(&module, "w");
+        assert_eq!(idle(&module), 1);
+        assert_reset(&module);
+    }
+
+    #[test]
+    fn idle_instances_do_not_keep_their_module_alive() {
+        let module = pooled_module();
+        run(&module, "w");
+        assert_eq!(idle(&module), 1);
+        assert_eq!(Arc::strong_count(&module), 1);
+    }
+
+    #[test]
+    fn instances_whose_module_returned_an_error_are_reset() {
+        let module = pooled_module();
+        run(&module, "e");
+        assert_eq!(idle(&module), 1);
+        assert_reset(&module);
+    }
+
+    #[test]
+    fn instances_whose_memory_grew_are_discarded() {
+        let module = pooled_module();
+        run(&module, "g");
+        assert_eq!(idle(&module), 1);
+        // Memory cannot shrink back, so the instance is dropped instead of being reused
+        assert!(module.instance_pool.as_ref().unwrap().take().is_none());
+        assert_eq!(idle(&module), 0);
+    }
+
+    #[test]
+    fn trapped_instances_are_discarded() {
+        let module = pooled_module();
+        run(&module, "t");
+        assert_eq!(idle(&module), 0);
+
+        // A reused instance that traps is not put back either
+        run(&module, "w");
+        assert_eq!(idle(&module), 1);
+        run(&module, "t");
+        assert_eq!(idle(&module), 0);
+    }
+}
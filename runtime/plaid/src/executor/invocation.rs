@@ -0,0 +1,297 @@
+use std::sync::Arc;
+
+use plaid_stl::messages::{LogSource, LogbacksAllowed};
+use wasmer_middlewares::metering::set_remaining_points;
+
+use super::{
+    execution_span, prepare_for_execution, run_prepared_instance, Env, Message,
+    ModuleExecutionError,
+};
+
+/// How many modules can be waiting on each other at once. With a depth of 4, a module
+/// processing a message can invoke a module, which invokes a module, and so on, 4 times.
+pub const MAX_INVOCATION_DEPTH: u32 = 4;
+
+/// Reasons a module could not be invoked at all
+#[derive(Debug)]
+pub enum InvocationError {
+    /// The caller is not allowed to invoke this module
+    NotAllowed(String),
+    /// There is no module with this name
+    NotLoaded(String),
+    /// The caller is in test mode but the module it wants to invoke is not
+    TestMode(String),
+    /// Invoking the module would go over the maximum invocation depth
+    DepthExceeded,
+    /// The module could not be instantiated
+    PreparationFailed(String),
+}
+
+impl std::fmt::Display for InvocationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotAllowed(module) => write!(f, "Not allowed to invoke [{module}]"),
+            Self::NotLoaded(module) => write!(f, "[{module}] is not loaded"),
+            Self::TestMode(module) => {
+                write!(
+                    f,
+                    "Cannot invoke [{module}] from test mode because it is not in test mode"
+                )
+            }
+            Self::DepthExceeded => write!(
+                f,
+                "Modules cannot invoke each other more than {MAX_INVOCATION_DEPTH} levels deep"
+            ),
+            Self::PreparationFailed(e) => write!(f, "Failed to prepare module: {e}"),
+        }
+    }
+}
+
+/// The result of running an invoked module
+pub struct InvocationOutcome {
+    /// The response the module set, or why it failed
+    pub response: Result<Option<String>, ModuleExecutionError>,
+    /// How much computation the module used, which is charged to the caller
+    pub computation_used: u64,
+}
+
+/// Run the module `callee` on `payload` on behalf of the module whose environment is `caller`,
+/// and return the response that `callee` set. The invoked module can use at most
+/// `computation_limit` computation and has to finish before the caller's execution deadline.
+///
+/// The invoked module gets a message of its own log type, whose source is the caller. It
+/// cannot log back, and the response it sets is returned rather than persisted.
+pub fn invoke_module(
+    caller: &Env,
+    callee: &str,
+    payload: Vec<u8>,
+    computation_limit: u64,
+) -> Result<InvocationOutcome, InvocationError> {
+    if !caller
+        .module
+        .invocable_modules
+        .iter()
+        .any(|module| module == callee)
+    {
+        return Err(InvocationError::NotAllowed(callee.to_string()));
+    }
+    if caller.invocation_depth >= MAX_INVOCATION_DEPTH {
+        return Err(InvocationError::DepthExceeded);
+    }
+    let callee: Arc<_> = caller
+        .modules
+        .get_module(callee)
+        .ok_or_else(|| InvocationError::NotLoaded(callee.to_string()))?;
+    // A module in test mode must not be able to cause side effects through another module
+    if caller.module.test_mode && !callee.test_mode {
+        return Err(InvocationError::TestMode(callee.name.clone()));
+    }
+
+    let mut message = Message::new(
+        callee.logtype.clone(),
+        payload,
+        LogSource::ModuleInvocation(caller.module.name.clone()),
+        LogbacksAllowed::Limited(0),
+    );
+    message.trace = caller.message.trace.child(&caller.span_id);
+    let els = caller.external_logging_system.clone();
+    let mut prepared = prepare_for_execution(
+        message,
+        callee.clone(),
+        caller.api.clone(),
+        caller.storage.clone(),
+        caller.cache.clone(),
+        els.clone(),
+        callee.get_persistent_response_data(),
+        caller.immediate_sender.clone(),
+        caller.delayed_log_sender.clone(),
+        caller.cancellation_token.clone(),
+        caller.metrics.clone(),
+        caller.scheduled_logbacks.clone(),
+        caller.modules.clone(),
+    )
+    .map_err(|e| InvocationError::PreparationFailed(e.to_string()))?;
+
+    let computation_limit = computation_limit.min(callee.computation_limit);
+    set_remaining_points(&mut prepared.store, &prepared.instance, computation_limit);
+    let env = prepared.env.as_mut(&mut prepared.store);
+    env.invocation_depth = caller.invocation_depth + 1;
+    // The invoked module has to finish in time for the caller to finish too
+    env.execution_deadline = match (env.execution_deadline, caller.execution_deadline) {
+        (Some(own), Some(caller)) => Some(own.min(caller)),
+        (own, caller) => own.or(caller),
+    };
+
+    let span = els
+        .tracing_enabled()
+        .then(|| execution_span(&callee.name, &env.message, env.span_id.clone()));
+
+    let (response, computation_used) = run_prepared_instance(&mut prepared, computation_limit);
+
+    if let Some(span) = span {
+        let span = span.finish(response.as_ref().err().map(|e| e.to_string()));
+        if let Err(e) = els.record_span(span) {
+            error!("Logging system is not working!!: {:?}", e);
+        }
+    }
+
+    Ok(InvocationOutcome {
+        response,
+        computation_used,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::executor::{execute_standalone, PreparedInstance};
+    use crate::loader::{PlaidModule, PlaidModules};
+    use crate::storage::Storage;
+    use crate::testing::fixtures::{
+        self, api, execution_context, standalone_context, stuck_storage, wat_module,
+    };
+
+    /// A module which responds with "pong"
+    const CALLEE: &str = r#"(module
+        (import "env" "set_response" (func $set_response (param i32 i32)))
+        (memory (export "memory") 1)
+        (data (i32.const 0) "pong")
+        (func (export "entrypoint") (result i32)
+            (call $set_response (i32.const 0) (i32.const 4))
+            i32.const 0))"#;
+
+    /// A module which invokes callee.wasm and fails if it cannot
+    const CALLER: &str = r#"(module
+        (import "env" "invoke_module" (func $invoke_module (param i32 i32 i32 i32) (result i32)))
+        (memory (export "memory") 1)
+        (data (i32.const 0) "callee.wasm")
+        (func (export "entrypoint") (result i32)
+            (i32.lt_s
+                (call $invoke_module (i32.const 0) (i32.const 11) (i32.const 0) (i32.const 0))
+                (i32.const 0))))"#;
+
+    /// A module which waits on storage until it is stopped
+    const STUCK: &str = r#"(module
+        (import "env" "storage_get" (func $storage_get (param i32 i32 i32 i32) (result i32)))
+        (memory (export "memory") 1)
+        (data (i32.const 0) "key")
+        (func (export "entrypoint") (result i32)
+            (drop (call $storage_get (i32.const 0) (i32.const 3) (i32.const 1024) (i32.const 1024)))
+            i32.const 0))"#;
+
+    fn message(module: &PlaidModule) -> Message {
+        fixtures::message(&module.logtype, b"")
+    }
+
+    /// Prepare `caller` to run with `callee` loaded next to it, without running it
+    fn prepare_caller(caller: PlaidModule, callee: PlaidModule) -> PreparedInstance {
+        let mut modules = PlaidModules::default();
+        modules.insert(Arc::new(callee));
+        let (context, _logs) = execution_context(api(), Arc::new(Storage::new_in_memory()));
+
+        prepare_for_execution(
+            message(&caller),
+            Arc::new(caller),
+            context.api,
+            context.storage,
+            None,
+            context.els,
+            None,
+            None,
+            context.delayed_log_sender,
+            context.cancellation_token,
+            None,
+            context.scheduled_logbacks,
+            Arc::new(modules),
+        )
+        .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn invoke(prepared: &PreparedInstance) -> Result<InvocationOutcome, InvocationError> {
+        invoke_module(
+            prepared.env.as_ref(&prepared.store),
+            "callee.wasm",
+            vec![],
+            1_000_000,
+        )
+    }
+
+    #[test]
+    fn invocations_are_limited_in_depth() {
+        let mut caller = wat_module("caller", CALLER);
+        caller.invocable_modules = vec!["callee.wasm".to_string()];
+        let mut prepared = prepare_caller(caller, wat_module("callee", CALLEE));
+
+        prepared.env.as_mut(&mut prepared.store).invocation_depth = MAX_INVOCATION_DEPTH - 1;
+        let outcome = invoke(&prepared).unwrap();
+        assert_eq!(
+            outcome.response.unwrap_or_else(|e| panic!("{e}")),
+            Some("pong".to_string())
+        );
+
+        prepared.env.as_mut(&mut prepared.store).invocation_depth = MAX_INVOCATION_DEPTH;
+        assert!(matches!(
+            invoke(&prepared),
+            Err(InvocationError::DepthExceeded)
+        ));
+    }
+
+    #[test]
+    fn only_allowed_modules_can_be_invoked() {
+        let mut caller = wat_module("caller", CALLER);
+        caller.invocable_modules = vec!["other.wasm".to_string()];
+        let prepared = prepare_caller(caller, wat_module("callee", CALLEE));
+
+        assert!(matches!(
+            invoke(&prepared),
+            Err(InvocationError::NotAllowed(callee)) if callee == "callee.wasm"
+        ));
+    }
+
+    #[test]
+    fn modules_in_test_mode_can_only_invoke_modules_in_test_mode() {
+        let mut caller = wat_module("caller", CALLER);
+        caller.invocable_modules = vec!["callee.wasm".to_string()];
+        caller.test_mode = true;
+        let prepared = prepare_caller(caller, wat_module("callee", CALLEE));
+        assert!(matches!(
+            invoke(&prepared),
+            Err(InvocationError::TestMode(callee)) if callee == "callee.wasm"
+        ));
+
+        let mut caller = wat_module("caller", CALLER);
+        caller.invocable_modules = vec!["callee.wasm".to_string()];
+        caller.test_mode = true;
+        let mut callee = wat_module("callee", CALLEE);
+        callee.test_mode = true;
+        let prepared = prepare_caller(caller, callee);
+        assert!(invoke(&prepared).unwrap().response.is_ok());
+    }
+
+    #[test]
+    fn invoked_modules_stop_at_the_callers_deadline() {
+        let mut caller = wat_module("caller", CALLER);
+        caller.invocable_modules = vec!["callee.wasm".to_string()];
+        caller.execution_timeout = Some(Duration::from_secs(1));
+        // The invoked module has no timeout of its own, so only the caller's can stop it
+        let callee = wat_module("callee", STUCK);
+        let mut modules = PlaidModules::default();
+        modules.insert(Arc::new(callee));
+        let (mut context, _logs) = standalone_context(api(), Arc::new(Storage::new_in_memory()));
+        context.storage = Some(Arc::new(stuck_storage()));
+        context.modules = Arc::new(modules);
+
+        let begin = Instant::now();
+        let response = execute_standalone(message(&caller), Arc::new(caller), context)
+            .unwrap_or_else(|e| panic!("{e}"))
+            .response;
+
+        assert!(matches!(
+            response,
+            Err(ModuleExecutionError::TimeoutExceeded(_))
+        ));
+        assert!(begin.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -1,19 +1,33 @@
 use std::collections::HashMap;
 
-use crossbeam_channel::Sender;
+use plaid_stl::plaid::metrics::MetricUpdate;
 use prometheus::core::{Collector, Desc};
 use prometheus::proto::MetricFamily;
-use prometheus::{GaugeVec, HistogramOpts, HistogramVec, IntGaugeVec, Opts};
+use prometheus::{GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts};
 
+use crate::functions::FunctionErrors;
 use crate::metrics::MetricsHandle;
 
-use super::thread_pools::ExecutionThreadPools;
-use super::Message;
+use super::custom_metrics::{MetricRejection, ModuleMetrics};
+use super::thread_pools::{ExecutionThreadPools, PoolReceiver};
+use super::throttle::ThrottleReason;
 
-/// Histograms for per-module execution stats, updated after each successful run.
+/// Histograms for per-module execution stats, updated after each successful run,
+/// a count of the executions stopped for exceeding their execution timeout, and how
+/// long it takes to get an instance ready to run, depending on whether it came from
+/// the module's instance pool or had to be instantiated. Executions held back by a module's
+/// execution limits are counted too, as are the API calls modules make: how many, how long they
+/// take and which ones fail. This also holds the metrics that modules record themselves.
 pub struct ModuleExecutionMetrics {
     computation_percentage: HistogramVec,
     execution_duration_seconds: HistogramVec,
+    execution_timeouts: IntCounterVec,
+    instance_preparation_seconds: HistogramVec,
+    throttled_executions: IntCounterVec,
+    host_calls: IntCounterVec,
+    host_call_errors: IntCounterVec,
+    host_call_duration_seconds: HistogramVec,
+    custom: ModuleMetrics,
 }
 
 impl ModuleExecutionMetrics {
@@ -37,16 +51,104 @@ impl ModuleExecutionMetrics {
         )
         .expect("valid metric definition");
 
+        let execution_timeouts = IntCounterVec::new(
+            Opts::new(
+                "plaid_module_execution_timeouts_total",
+                "Number of module executions stopped for exceeding their execution timeout",
+            ),
+            &["module"],
+        )
+        .expect("valid metric definition");
+
+        let instance_preparation_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "plaid_module_instance_preparation_seconds",
+                "Time spent getting a module instance ready to process a message",
+            )
+            .buckets(vec![
+                0.00001, 0.00005, 0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5,
+            ]),
+            &["module", "instance"],
+        )
+        .expect("valid metric definition");
+
+        let throttled_executions = IntCounterVec::new(
+            Opts::new(
+                "plaid_module_throttled_executions_total",
+                "Number of module executions held back by the module's execution limits",
+            ),
+            &["module", "reason", "action"],
+        )
+        .expect("valid metric definition");
+
+        let host_calls = IntCounterVec::new(
+            Opts::new(
+                "plaid_host_calls_total",
+                "Number of API calls modules made, by module and host function",
+            ),
+            &["module", "function"],
+        )
+        .expect("valid metric definition");
+
+        let host_call_errors = IntCounterVec::new(
+            Opts::new(
+                "plaid_host_call_errors_total",
+                "Number of API calls modules made that failed, by the error code returned to the module",
+            ),
+            &["module", "function", "code"],
+        )
+        .expect("valid metric definition");
+
+        let host_call_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "plaid_host_call_duration_seconds",
+                "Wall-clock duration of the API calls modules made",
+            ),
+            &["module", "function"],
+        )
+        .expect("valid metric definition");
+
         handle
             .register(Box::new(computation_percentage.clone()))
             .expect("expected unique collector");
         handle
             .register(Box::new(execution_duration_seconds.clone()))
             .expect("expected unique collector");
+        handle
+            .register(Box::new(execution_timeouts.clone()))
+            .expect("expected unique collector");
+        handle
+            .register(Box::new(instance_preparation_seconds.clone()))
+            .expect("expected unique collector");
+        handle
+            .register(Box::new(throttled_executions.clone()))
+            .expect("expected unique collector");
+
+        handle
+            .register(Box::new(host_calls.clone()))
+            .expect("expected unique collector");
+        handle
+            .register(Box::new(host_call_errors.clone()))
+            .expect("expected unique collector");
+        handle
+            .register(Box::new(host_call_duration_seconds.clone()))
+            .expect("expected unique collector");
+
+        let custom = ModuleMetrics::default();
+        handle
+            .register(Box::new(custom.clone()))
+            .expect("expected unique collector");
 
         Self {
             computation_percentage,
             execution_duration_seconds,
+            execution_timeouts,
+            instance_preparation_seconds,
+            throttled_executions,
+            host_calls,
+            host_call_errors,
+            host_call_duration_seconds,
+            custom,
         }
     }
 
@@ -63,15 +165,69 @@ impl ModuleExecutionMetrics {
             .with_label_values(&[module])
             .observe(duration.as_secs_f64());
     }
+
+    pub fn record_timeout(&self, module: &str) {
+        self.execution_timeouts.with_label_values(&[module]).inc();
+    }
+
+    pub fn record_instance_preparation(
+        &self,
+        module: &str,
+        from_pool: bool,
+        duration: std::time::Duration,
+    ) {
+        let instance = if from_pool { "pooled" } else { "fresh" };
+        self.instance_preparation_seconds
+            .with_label_values(&[module, instance])
+            .observe(duration.as_secs_f64());
+    }
+
+    /// `action` is what happened to the message: it was `queued`, `dropped` or `delayed`
+    pub fn record_throttled(&self, module: &str, reason: ThrottleReason, action: &str) {
+        self.throttled_executions
+            .with_label_values(&[module, reason.as_str(), action])
+            .inc();
+    }
+
+    /// Record an API call a module made, and the error it got if the call failed
+    pub fn record_host_call(
+        &self,
+        module: &str,
+        function: &str,
+        duration: std::time::Duration,
+        error: Option<&FunctionErrors>,
+    ) {
+        self.host_calls.with_label_values(&[module, function]).inc();
+        self.host_call_duration_seconds
+            .with_label_values(&[module, function])
+            .observe(duration.as_secs_f64());
+        if let Some(error) = error {
+            let code = (*error as i32).to_string();
+            self.host_call_errors
+                .with_label_values(&[module, function, &code])
+                .inc();
+        }
+    }
+
+    /// Apply an update that a module made to one of its own metrics
+    pub fn record_custom(
+        &self,
+        module: &str,
+        series_limit: usize,
+        update: &MetricUpdate,
+    ) -> Result<(), MetricRejection> {
+        self.custom.record(module, series_limit, update)
+    }
 }
 
-/// Reports depth and percentage capacity of each execution queue. Values are
-/// read from the live channel senders at scrape time, so there are no writers.
+/// Reports depth and percentage capacity of each execution queue, and the depth of each of
+/// their priority lanes. Values are read from the queues at scrape time, so there are no writers.
 pub struct QueueMetrics {
-    /// One sender per queue: "general" + each dedicated log type.
-    senders: HashMap<String, Sender<Message>>,
+    /// One receiver per queue: "general" + each dedicated log type + "non_concurrent" (if running).
+    receivers: HashMap<String, PoolReceiver>,
     depth: IntGaugeVec,
     capacity_percentage: GaugeVec,
+    lane_depth: IntGaugeVec,
 }
 
 impl QueueMetrics {
@@ -82,10 +238,13 @@ impl QueueMetrics {
     }
 
     fn new(pools: &ExecutionThreadPools) -> Self {
-        let mut senders = HashMap::new();
-        senders.insert("general".to_string(), pools.general_pool.sender.clone());
+        let mut receivers = HashMap::new();
+        receivers.insert("general".to_string(), pools.general_pool.receiver.clone());
         for (log_type, tp) in &pools.dedicated_pools {
-            senders.insert(log_type.clone(), tp.sender.clone());
+            receivers.insert(log_type.clone(), tp.receiver.clone());
+        }
+        if let Some(tp) = &pools.non_concurrent_pool {
+            receivers.insert("non_concurrent".to_string(), tp.receiver.clone());
         }
 
         let depth = IntGaugeVec::new(
@@ -106,10 +265,20 @@ impl QueueMetrics {
         )
         .expect("valid metric definition");
 
+        let lane_depth = IntGaugeVec::new(
+            Opts::new(
+                "plaid_execution_queue_lane_depth",
+                "Number of messages currently queued for execution in a priority lane of the queue",
+            ),
+            &["queue", "lane"],
+        )
+        .expect("valid metric definition");
+
         Self {
-            senders,
+            receivers,
             depth,
             capacity_percentage,
+            lane_depth,
         }
     }
 }
@@ -118,13 +287,13 @@ impl Collector for QueueMetrics {
     fn desc(&self) -> Vec<&Desc> {
         let mut descs = self.depth.desc();
         descs.extend(self.capacity_percentage.desc());
+        descs.extend(self.lane_depth.desc());
         descs
     }
 
     fn collect(&self) -> Vec<MetricFamily> {
-        for (queue, sender) in &self.senders {
-            let depth = sender.len();
-            let capacity = sender.capacity().unwrap_or(0);
+        for (queue, receiver) in &self.receivers {
+            let (depth, capacity) = receiver.depth_and_capacity();
             let pct = if capacity == 0 {
                 0.0
             } else {
@@ -134,10 +303,110 @@ impl Collector for QueueMetrics {
             self.capacity_percentage
                 .with_label_values(&[queue])
                 .set(pct);
+            for (lane, depth) in receiver.lane_depths() {
+                self.lane_depth
+                    .with_label_values(&[queue, &lane])
+                    .set(depth as i64);
+            }
         }
 
         let mut families = self.depth.collect();
         families.extend(self.capacity_percentage.collect());
+        families.extend(self.lane_depth.collect());
         families
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::apis::Api;
+    use crate::executor::process_message_with_module;
+    use crate::loader::PlaidModules;
+    use crate::storage::Storage;
+    use crate::testing::fixtures::{api, execution_context, message, wat_module};
+
+    /// A module in test mode which adds a user to a repository
+    const ADD_USER: &str = r#"(module
+        (import "env" "github_add_user_to_repo" (func $add_user (param i32 i32) (result i32)))
+        (memory (export "memory") 1)
+        (data (i32.const 0) "{}")
+        (func (export "entrypoint") (result i32)
+            (drop (call $add_user (i32.const 0) (i32.const 2)))
+            i32.const 0))"#;
+
+    const FUNCTION: &str = "github_add_user_to_repo";
+
+    /// Run the module once, with its blocked calls succeeding or not
+    fn add_user(api: &Arc<Api>, metrics: &Arc<ModuleExecutionMetrics>, synthetic_success: bool) {
+        let mut module = wat_module("metered", ADD_USER);
+        module.test_mode = true;
+        module.test_mode_journal = Some(
+            serde_json::from_value(serde_json::json!({ "synthetic_success": synthetic_success }))
+                .unwrap(),
+        );
+        let (mut context, _logs) =
+            execution_context(api.clone(), Arc::new(Storage::new_in_memory()));
+        context.module_execution_metrics = Some(metrics.clone());
+
+        process_message_with_module(
+            message(&module.logtype, b""),
+            Arc::new(module),
+            &context,
+            None,
+            Arc::new(PlaidModules::default()),
+            None,
+        )
+        .unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    #[test]
+    fn host_calls_are_counted_by_module_function_and_error() {
+        let api = api();
+        let metrics = Arc::new(ModuleExecutionMetrics::register(&MetricsHandle::new()));
+        let labels = ["metered.wasm", FUNCTION];
+
+        add_user(&api, &metrics, true);
+        assert_eq!(metrics.host_calls.with_label_values(&labels).get(), 1);
+        assert_eq!(
+            metrics
+                .host_call_duration_seconds
+                .with_label_values(&labels)
+                .get_sample_count(),
+            1
+        );
+        assert!(metrics.host_call_errors.collect()[0]
+            .get_metric()
+            .is_empty());
+
+        add_user(&api, &metrics, false);
+        assert_eq!(metrics.host_calls.with_label_values(&labels).get(), 2);
+        assert_eq!(
+            metrics
+                .host_call_duration_seconds
+                .with_label_values(&labels)
+                .get_sample_count(),
+            2
+        );
+        let errors = metrics.host_call_errors.collect();
+        let errors = errors[0].get_metric();
+        assert_eq!(errors.len(), 1);
+        let error_labels: Vec<(&str, &str)> = errors[0]
+            .get_label()
+            .iter()
+            .map(|label| (label.name(), label.value()))
+            .collect();
+        let code = (FunctionErrors::TestMode as i32).to_string();
+        assert_eq!(
+            error_labels,
+            vec![
+                ("code", code.as_str()),
+                ("function", FUNCTION),
+                ("module", "metered.wasm"),
+            ]
+        );
+        assert_eq!(errors[0].get_counter().value(), 1.0);
+    }
+}
@@ -1,34 +1,61 @@
+mod batching;
+mod custom_metrics;
+mod instance_pool;
+mod invocation;
 pub mod metrics;
+pub mod priority;
+mod shadow;
+mod shutdown;
+mod standalone;
 pub mod thread_pools;
+mod throttle;
 
 use crate::apis::Api;
 
 use crate::cache::Cache;
-use crate::data::DelayedMessage;
+use crate::data::{DelayedMessage, ScheduledLogbacks};
+use crate::dead_letter::DeadLetterQueue;
+use crate::filters::FilterInput;
 use crate::functions::{
     create_bindgen_externref_xform, create_bindgen_placeholder, link_functions_to_module, LinkError,
 };
-use crate::loader::PlaidModule;
+use crate::loader::{LiveModules, PlaidModule, PlaidModules, RetryInfo, RetryPolicy};
 use crate::logging::{Logger, LoggingError, Severity};
 use crate::performance::ModulePerformanceMetadata;
+use crate::recording::MessageRecorder;
 use crate::storage::Storage;
+use crate::trace::{new_span_id, Span, TraceContext};
+use crate::write_ahead_log::WriteAheadLog;
 
-use crossbeam_channel::{Receiver, RecvError, Sender, TrySendError};
+pub use batching::BatchConfig;
+use batching::{batch_failures, batch_message, check_batchable, PendingBatches};
+use crossbeam_channel::{RecvError, RecvTimeoutError, Sender, TrySendError};
+pub use custom_metrics::DEFAULT_SERIES_LIMIT as DEFAULT_CUSTOM_METRIC_SERIES_LIMIT;
+use instance_pool::PreparedInstance;
+pub use instance_pool::{ExportMutableGlobals, InstancePool};
+pub use invocation::{invoke_module, InvocationError, InvocationOutcome};
 use metrics::ModuleExecutionMetrics;
-use thread_pools::ExecutionThreadPools;
+use shadow::ShadowRun;
+pub use shadow::{Shadow, ShadowComparison, SideEffect};
+pub use shutdown::{persist_queued_messages, requeue_persisted_messages};
+pub use standalone::{execute_standalone, StandaloneContext, StandaloneExecution};
+pub use thread_pools::PoolSender;
+use thread_pools::{ExecutionThreadPools, NonConcurrentRouting, PoolReceiver};
+use throttle::ExecutionPermit;
+pub use throttle::{ExecutionLimits, LimitBehavior, ModuleThrottle, RateLimit, RateLimiter};
 use tokio::sync::oneshot::Sender as OneShotSender;
 use tokio_util::sync::CancellationToken;
 
-use plaid_stl::messages::{LogSource, LogbacksAllowed};
+use plaid_stl::messages::{BatchResult, LogSource, LogbacksAllowed};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_with::{serde_as, DeserializeAs, SerializeAs};
-use wasmer::{FunctionEnv, Imports, Instance, Memory, RuntimeError, Store, TypedFunction};
+use wasmer::{FunctionEnv, Imports, Instance, Memory, RuntimeError, Store};
 use wasmer_middlewares::metering::{get_remaining_points, MeteringPoints};
 
 use std::collections::HashMap;
 use std::sync::{Arc, Weak};
 use std::thread::{self, JoinHandle};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// When a rule is used to generate a response to a GET request, this structure
 /// is what is passed from the executor to the async webhook runtime.
@@ -127,6 +154,15 @@ pub struct Message {
     /// be run to generate a response.
     #[serde(skip)]
     pub module: Option<Arc<PlaidModule>>,
+    /// If this is some, the message will only be run by the module it names. This is used for
+    /// retries of messages a module failed to process, and for messages deferred by a module's
+    /// execution limits.
+    #[serde(default)]
+    pub retry: Option<RetryInfo>,
+    /// The trace this message is part of. Messages sent because of this one, like logbacks,
+    /// are part of the same trace.
+    #[serde(default = "TraceContext::new_root")]
+    pub trace: TraceContext,
 }
 
 impl Message {
@@ -146,6 +182,8 @@ impl Message {
             logbacks_allowed,
             response_sender: None,
             module: None,
+            retry: None,
+            trace: TraceContext::new_root(),
         }
     }
 
@@ -169,6 +207,8 @@ impl Message {
             logbacks_allowed,
             response_sender,
             module,
+            retry: None,
+            trace: TraceContext::new_root(),
         }
     }
 
@@ -185,8 +225,16 @@ impl Message {
             logbacks_allowed: self.logbacks_allowed.clone(),
             response_sender: None,
             module: None,
+            retry: self.retry.clone(),
+            trace: self.trace.clone(),
         }
     }
+
+    /// Which attempt at processing this message this is. This is 1 unless the
+    /// message is being retried.
+    pub fn attempt(&self) -> u32 {
+        self.retry.as_ref().map_or(1, |retry| retry.attempt)
+    }
 }
 
 /// Environment for executing a module on a message
@@ -211,13 +259,41 @@ pub struct Env {
     // Context about error encountered by the module during its execution
     pub execution_error_context: Option<String>,
     /// Available for immediate logback during normal operation; `None` during shutdown drain.
-    pub immediate_sender: Option<Sender<Message>>,
+    pub immediate_sender: Option<PoolSender>,
     /// Sender for delayed logbacks (`delay > 0`), and for immediate logbacks coerced
     /// during shutdown. Messages are persisted by the internal logback listener and
     /// injected into the executor queue once their delay elapses.
     pub delayed_log_sender: Sender<DelayedMessage>,
     /// Shared with async tasks; set when shutdown begins.
     pub cancellation_token: CancellationToken,
+    /// The point in time after which the module is stopped, if it has an execution timeout
+    pub execution_deadline: Option<Instant>,
+    /// Where the module's custom metrics are recorded, if metrics are enabled
+    pub metrics: Option<Arc<ModuleExecutionMetrics>>,
+    /// The delayed logbacks that modules scheduled and which are waiting to be delivered
+    pub scheduled_logbacks: Arc<ScheduledLogbacks>,
+    /// The modules that were loaded when the message started being processed. These are
+    /// the modules that this module can invoke.
+    pub modules: Arc<PlaidModules>,
+    /// The running instance of the module, so host functions can manage its computation budget
+    pub instance: Option<Instance>,
+    /// How many modules are waiting on this one: 0 if the module is processing a message,
+    /// 1 if it was invoked by a module processing a message, etc.
+    pub invocation_depth: u32,
+    /// The response of the last module this module invoked, until the module fetches it
+    pub invocation_response: Option<String>,
+    /// The ID of the span of this execution, which is the parent of the spans of the
+    /// host functions it calls and of the messages it sends
+    pub span_id: String,
+    /// The side effects the module attempted, if they are being recorded. They are recorded
+    /// for modules that have a shadow, and for the shadow itself.
+    pub side_effects: Option<Vec<SideEffect>>,
+    /// The value each key of the module's namespace had before the module first wrote it,
+    /// while side effects are recorded. The shadow starts from the namespace as it was then.
+    pub storage_before_writes: HashMap<String, Option<Vec<u8>>>,
+    /// The results of the batch the module is processing, once the module reports them, or
+    /// why they could not be read
+    pub batch_results: Option<Result<Vec<BatchResult>, String>>,
 }
 
 /// The executor that processes messages
@@ -228,6 +304,8 @@ pub struct Executor {
 /// Join handles for executor worker threads.
 pub struct ExecutorThreads {
     thread_handles: Vec<JoinHandle<()>>,
+    /// Cancelled to make the threads stop taking messages from their queues
+    stop: CancellationToken,
 }
 
 impl ExecutorThreads {
@@ -241,6 +319,25 @@ impl ExecutorThreads {
     }
 }
 
+/// What the execution threads use to run modules on messages
+#[derive(Clone)]
+pub struct ExecutionContext {
+    pub api: Arc<Api>,
+    pub storage: Option<Arc<Storage>>,
+    pub cache: Option<Arc<Cache>>,
+    /// Where the modules' logs and errors go
+    pub els: Logger,
+    /// Where the performance of each run goes, if performance monitoring is enabled
+    pub performance_mode: Option<Sender<ModulePerformanceMetadata>>,
+    pub module_execution_metrics: Option<Arc<ModuleExecutionMetrics>>,
+    pub delayed_log_sender: Sender<DelayedMessage>,
+    /// Cancelled when Plaid shuts down, after which modules cannot send logbacks anymore
+    pub cancellation_token: CancellationToken,
+    /// Where the messages that modules failed to process for good are kept
+    pub dead_letters: Option<Arc<DeadLetterQueue>>,
+    pub scheduled_logbacks: Arc<ScheduledLogbacks>,
+}
+
 /// Errors encountered by the executor while trying to execute a module
 pub enum ExecutorError {
     ExternalLoggingError(LoggingError),
@@ -274,6 +371,7 @@ impl std::fmt::Display for ExecutorError {
 /// Error encountered during the execution of a module
 pub enum ModuleExecutionError {
     ComputationExhausted(u64),
+    TimeoutExceeded(Duration),
     ModuleError(String),
     PersistentResponseNotAllowed,
     PersistentResponseTooLarge {
@@ -282,6 +380,8 @@ pub enum ModuleExecutionError {
     },
     LockingError(String),
     UnknownExecutionError(String),
+    /// The log cannot be delivered to the module in a batch
+    NotBatchable(String),
 }
 
 impl Into<ExecutorError> for ModuleExecutionError {
@@ -296,6 +396,13 @@ impl std::fmt::Display for ModuleExecutionError {
             ModuleExecutionError::ComputationExhausted(limit) => {
                 write!(f, "Computation Exhausted. Limit: [{limit}]")
             }
+            ModuleExecutionError::TimeoutExceeded(limit) => {
+                write!(
+                    f,
+                    "Execution Timeout Exceeded. Limit: [{}s]",
+                    limit.as_secs()
+                )
+            }
             ModuleExecutionError::ModuleError(context) => {
                 write!(
                     f,
@@ -317,6 +424,9 @@ impl std::fmt::Display for ModuleExecutionError {
             ModuleExecutionError::LockingError(error) => {
                 write!(f, "CRITICAL Locking error. Error: [{error}]")
             }
+            ModuleExecutionError::NotBatchable(error) => {
+                write!(f, "Log cannot be delivered in a batch. Error: [{error}]")
+            }
         }
     }
 }
@@ -327,6 +437,51 @@ impl From<LoggingError> for ExecutorError {
     }
 }
 
+/// Build the environment a module runs in to process a message
+fn new_env(
+    message: Message,
+    plaid_module: Arc<PlaidModule>,
+    api: Arc<Api>,
+    storage: Option<Arc<Storage>>,
+    cache: Option<Arc<Cache>>,
+    els: Logger,
+    response: Option<String>,
+    immediate_sender: Option<PoolSender>,
+    delayed_log_sender: Sender<DelayedMessage>,
+    cancellation_token: CancellationToken,
+    metrics: Option<Arc<ModuleExecutionMetrics>>,
+    scheduled_logbacks: Arc<ScheduledLogbacks>,
+    modules: Arc<PlaidModules>,
+) -> Env {
+    Env {
+        execution_deadline: plaid_module
+            .execution_timeout
+            .map(|timeout| Instant::now() + timeout),
+        module: plaid_module,
+        message: message.create_duplicate(),
+        api,
+        storage,
+        cache,
+        external_logging_system: els,
+        memory: None,
+        response,
+        execution_error_context: None,
+        immediate_sender,
+        delayed_log_sender,
+        cancellation_token,
+        metrics,
+        scheduled_logbacks,
+        modules,
+        instance: None,
+        invocation_depth: 0,
+        invocation_response: None,
+        span_id: new_span_id(),
+        side_effects: None,
+        storage_before_writes: HashMap::new(),
+        batch_results: None,
+    }
+}
+
 /// Take a message, a module, and an executor and get an instance back that is ready to run
 /// the provided module.
 fn prepare_for_execution(
@@ -337,10 +492,13 @@ fn prepare_for_execution(
     cache: Option<Arc<Cache>>,
     els: Logger,
     response: Option<String>,
-    immediate_sender: Option<Sender<Message>>,
+    immediate_sender: Option<PoolSender>,
     delayed_log_sender: Sender<DelayedMessage>,
     cancellation_token: CancellationToken,
-) -> Result<(Store, Instance, TypedFunction<(), i32>, FunctionEnv<Env>), ExecutorError> {
+    metrics: Option<Arc<ModuleExecutionMetrics>>,
+    scheduled_logbacks: Arc<ScheduledLogbacks>,
+    modules: Arc<PlaidModules>,
+) -> Result<PreparedInstance, ExecutorError> {
     // Prepare the structure for functions the module will use
     // AKA: Host Functions
     let mut imports = Imports::new();
@@ -349,20 +507,21 @@ fn prepare_for_execution(
     // for this message only.
     let mut store = Store::new(plaid_module.engine.clone());
 
-    let env = Env {
-        module: plaid_module.clone(),
-        message: message.create_duplicate(),
-        api: api.clone(),
-        storage: storage.clone(),
-        cache: cache.clone(),
-        external_logging_system: els.clone(),
-        memory: None,
+    let env = new_env(
+        message.create_duplicate(),
+        plaid_module.clone(),
+        api,
+        storage,
+        cache,
+        els.clone(),
         response,
-        execution_error_context: None,
         immediate_sender,
         delayed_log_sender,
         cancellation_token,
-    };
+        metrics,
+        scheduled_logbacks,
+        modules,
+    );
 
     let env = FunctionEnv::new(&mut store, env);
 
@@ -415,6 +574,7 @@ fn prepare_for_execution(
             return Err(ExecutorError::MemoryError(e.to_string()));
         }
     };
+    data_mut.instance = Some(instance.clone());
 
     let envr = env_mut.as_ref();
     // Get the entrypoint of the module
@@ -425,20 +585,16 @@ fn prepare_for_execution(
         .typed::<(), i32>(&mut store)
         .map_err(|_| ExecutorError::InvalidEntrypoint)?;
 
-    Ok((store, instance, ep, envr))
+    Ok(PreparedInstance::new(store, instance, ep, envr))
 }
 
 /// Update a module's persistent response
 fn update_persistent_response(
     plaid_module: &Arc<PlaidModule>,
-    env: &FunctionEnv<Env>,
-    mut store: &mut Store,
+    response: Option<String>,
     response_sender: Option<OneShotSender<Option<ResponseMessage>>>,
 ) -> Result<(), ExecutorError> {
-    match (
-        env.as_mut(&mut store).response.clone(),
-        &plaid_module.persistent_response,
-    ) {
+    match (response, &plaid_module.persistent_response) {
         (None, _) => {
             // We need to check if there might be a tokio task serving a GET
             // that is waiting on this response. If the rule doesn't give one, we
@@ -498,6 +654,10 @@ fn update_persistent_response(
 
 /// This runs a message through a module and will handle module level errors.
 ///
+/// If `batch` is set, the module processes these messages as a batch and `message` is the
+/// message built for the batch. Each message of the batch that the module failed to process
+/// is then handled like a message the module failed to process on its own.
+///
 /// If there is a runtime level error then this function returns an error which
 /// will stop Plaid. This means that a module should NEVER be able to cause such
 /// an error. The only time this should return an error is if the runtime itself
@@ -505,53 +665,116 @@ fn update_persistent_response(
 fn process_message_with_module(
     message: Message,
     module: Arc<PlaidModule>,
-    api: Arc<Api>,
-    storage: Option<Arc<Storage>>,
-    cache: Option<Arc<Cache>>,
-    els: Logger,
-    performance_mode: Option<Sender<ModulePerformanceMetadata>>,
-    module_execution_metrics: Option<Arc<ModuleExecutionMetrics>>,
-    immediate_sender: Option<Sender<Message>>,
-    delayed_log_sender: Sender<DelayedMessage>,
-    cancellation_token: CancellationToken,
+    context: &ExecutionContext,
+    immediate_sender: Option<PoolSender>,
+    modules: Arc<PlaidModules>,
+    mut batch: Option<Vec<Message>>,
 ) -> Result<(), ExecutorError> {
+    let ExecutionContext {
+        api,
+        storage,
+        cache,
+        els,
+        performance_mode,
+        module_execution_metrics,
+        delayed_log_sender,
+        cancellation_token,
+        dead_letters,
+        scheduled_logbacks,
+    } = context.clone();
     // TODO @obelisk: This will quietly swallow locking errors on the persistent response
     // This will eventually be caught if something tries to update the response but I don't
     // know if that's good enough.
     let persistent_response = module.get_persistent_response_data();
+    let preparation_begin = Instant::now();
+    let pooled_instance = module.instance_pool.as_ref().and_then(|pool| pool.take());
+    let from_pool = pooled_instance.is_some();
     // Message needs to be cloned because of the logback budget
     // which is separate for every rule running the same message.
-    let (mut store, instance, entrypoint, env) = match prepare_for_execution(
-        message.create_duplicate(),
-        module.clone(),
-        api.clone(),
-        storage.clone(),
-        cache.clone(),
-        els.clone(),
-        persistent_response,
-        immediate_sender,
-        delayed_log_sender,
-        cancellation_token,
-    ) {
-        Ok((store, instance, ep, env)) => (store, instance, ep, env),
-        Err(e) => {
-            els.log_module_error(
-                module.name.clone(),
-                format!("Failed to prepare for execution: {e}"),
-                message.data.clone(),
-            )?;
-            return Ok(());
+    let mut prepared = match pooled_instance {
+        // The instance is already linked and instantiated: it only needs
+        // an environment for this message.
+        Some(mut prepared) => {
+            let env = prepared.env.as_mut(&mut prepared.store);
+            let memory = env.memory.take();
+            let instance = env.instance.take();
+            *env = new_env(
+                message.create_duplicate(),
+                module.clone(),
+                api.clone(),
+                storage.clone(),
+                cache.clone(),
+                els.clone(),
+                persistent_response,
+                immediate_sender,
+                delayed_log_sender.clone(),
+                cancellation_token,
+                module_execution_metrics.clone(),
+                scheduled_logbacks.clone(),
+                modules.clone(),
+            );
+            env.memory = memory;
+            env.instance = instance;
+            prepared
         }
+        None => match prepare_for_execution(
+            message.create_duplicate(),
+            module.clone(),
+            api.clone(),
+            storage.clone(),
+            cache.clone(),
+            els.clone(),
+            persistent_response,
+            immediate_sender,
+            delayed_log_sender.clone(),
+            cancellation_token,
+            module_execution_metrics.clone(),
+            scheduled_logbacks.clone(),
+            modules.clone(),
+        ) {
+            Ok(mut prepared) => {
+                if module.instance_pool.is_some() {
+                    prepared.take_snapshot();
+                }
+                prepared
+            }
+            Err(e) => {
+                els.log_module_error(
+                    module.name.clone(),
+                    format!("Failed to prepare for execution: {e}"),
+                    message.data.clone(),
+                )?;
+                return Ok(());
+            }
+        },
     };
 
+    if let Some(metrics) = &module_execution_metrics {
+        metrics.record_instance_preparation(&module.name, from_pool, preparation_begin.elapsed());
+    }
+
+    // Record what the module attempts, to compare it with what its shadow attempts
+    if module.shadow.is_some() {
+        prepared.env.as_mut(&mut prepared.store).side_effects = Some(vec![]);
+    }
+
     let computation_limit = module.computation_limit;
+    let span = els.tracing_enabled().then(|| {
+        execution_span(
+            &module.name,
+            &message,
+            prepared.env.as_ref(&prepared.store).span_id.clone(),
+        )
+    });
     // Call the entrypoint
     let begin = Instant::now();
-    let error = match entrypoint.call(&mut store) {
+    let error = match prepared.entrypoint.call(&mut prepared.store) {
         Ok(n) => {
             if n != 0 {
                 Some(ModuleExecutionError::ModuleError(
-                    env.as_ref(&store)
+                    prepared
+                        .env
+                        .as_ref(&prepared.store)
                         .execution_error_context
                         .clone()
                         .unwrap_or("None".to_string()),
@@ -560,7 +783,7 @@ fn process_message_with_module(
                 // This should always work because when computation is exhausted,
                 // we end up in the RuntimeError block.
                 if let MeteringPoints::Remaining(remaining) =
-                    get_remaining_points(&mut store, &instance)
+                    get_remaining_points(&mut prepared.store, &prepared.instance)
                 {
                     let computation_remaining_percentage =
                         (remaining as f32 / computation_limit as f32) * 100.0;
@@ -592,106 +815,527 @@ fn process_message_with_module(
         Err(e) => Some(determine_error(
             e,
             computation_limit,
-            &instance,
-            &mut store,
-            &env,
+            &prepared.instance,
+            &mut prepared.store,
+            &prepared.env,
         )),
     };
 
+    if let Some(span) = span {
+        els.record_span(span.finish(error.as_ref().map(|e| e.to_string())))?;
+    }
+
+    // Take what the module left in its environment, then the instance is not needed anymore
+    let env = prepared.env.as_mut(&mut prepared.store);
+    let execution_error_context = env.execution_error_context.take();
+    let response = env.response.take();
+    let side_effects = env.side_effects.take();
+    let storage_before_writes = std::mem::take(&mut env.storage_before_writes);
+    let batch_results = env.batch_results.take();
+
+    // An instance which was stopped by a trap can be in any state, but one which
+    // returned from its entrypoint can be reset and reused.
+    if let (None | Some(ModuleExecutionError::ModuleError(_)), Some(pool)) =
+        (&error, &module.instance_pool)
+    {
+        pool.put_back(prepared);
+    }
+
+    // The shadow runs on its own thread once the primary is done with the message, so it
+    // delays neither the response to a GET request nor the next message
+    let shadow_message = module.shadow.as_ref().map(|_| message.create_duplicate());
+    let compare_with_shadow = |primary: Result<Option<String>, String>| {
+        if let (Some(shadow), Some(shadow_message)) = (&module.shadow, shadow_message) {
+            shadow.submit(ShadowRun {
+                module: module.name.clone(),
+                message: shadow_message,
+                primary,
+                primary_side_effects: side_effects.unwrap_or_default(),
+                primary_storage_before_writes: storage_before_writes,
+                api: api.clone(),
+                storage,
+                cache,
+                els: els.clone(),
+                scheduled_logbacks,
+                modules,
+            });
+        }
+    };
+
     // If there was an error then log that it happened to the els
     if let Some(error) = error {
-        els.log_module_error(
-            module.name.clone(),
-            format!("{error}"),
-            message.data.clone(),
-        )?;
+        if let (ModuleExecutionError::TimeoutExceeded(_), Some(metrics)) =
+            (&error, &module_execution_metrics)
+        {
+            metrics.record_timeout(&module.name);
+        }
+
+        // When a batch fails as a whole, every message in it failed
+        for failed in batch.as_deref().unwrap_or(std::slice::from_ref(&message)) {
+            handle_failure(
+                &error,
+                failed,
+                &module,
+                &api,
+                &delayed_log_sender,
+                &dead_letters,
+                &els,
+            )?;
+        }
+        compare_with_shadow(Err(error.to_string()));
 
         // Stop processing this log and move on to the next one
         return Ok(());
     }
 
+    // The module reports which messages of a batch it failed to process
+    if let Some(batch) = &batch {
+        for (failed, error) in batch_failures(batch, batch_results) {
+            handle_failure(
+                &ModuleExecutionError::ModuleError(error),
+                failed,
+                &module,
+                &api,
+                &delayed_log_sender,
+                &dead_letters,
+                &els,
+            )?;
+        }
+    }
+
     // Check to see if there is data in the error context even if the module didn't report an error
     // Modules can do this to return warnings it wants to surface without affecting error metrics
-    if let Some(return_message) = &env.as_ref(&store).execution_error_context {
+    if let Some(return_message) = &execution_error_context {
         let _ = els.log_internal_message(
             Severity::Info,
             format!("Module [{}] returned: {}", module.name, return_message),
         );
     }
 
-    // Update the persistent response
-    if let Err(e) = update_persistent_response(&module, &env, &mut store, message.response_sender) {
+    // Update the persistent response. A GET request to a module which takes batches is
+    // waiting on the message it sent, which is in the batch.
+    let response_sender = message.response_sender.or_else(|| {
+        batch
+            .as_mut()
+            .and_then(|batch| batch.iter_mut().find_map(|m| m.response_sender.take()))
+    });
+    let primary = module.shadow.as_ref().map(|_| Ok(response.clone()));
+    if let Err(e) = update_persistent_response(&module, response, response_sender) {
         let _ = els.log_module_error(
             module.name.clone(),
             format!("Failed to update persistent response: {e}"),
             message.data.clone(),
         );
     }
+    if let Some(primary) = primary {
+        compare_with_shadow(primary);
+    }
 
     Ok(())
 }
 
-fn execution_loop(
-    receiver: Receiver<Message>,
-    modules: HashMap<String, Vec<Arc<PlaidModule>>>,
-    api: Arc<Api>,
-    storage: Option<Arc<Storage>>,
-    cache: Option<Arc<Cache>>,
-    els: Logger,
-    performance_monitoring_mode: Option<Sender<ModulePerformanceMetadata>>,
-    module_execution_metrics: Option<Arc<ModuleExecutionMetrics>>,
-    immediate_sender: Weak<Sender<Message>>,
-    delayed_log_sender: Sender<DelayedMessage>,
-    cancellation_token: CancellationToken,
+/// Handle a message that `module` failed to process: retry it if the module's retry policy
+/// allows it, keep it as a dead letter otherwise, and log the failure
+fn handle_failure(
+    error: &ModuleExecutionError,
+    message: &Message,
+    module: &PlaidModule,
+    api: &Api,
+    delayed_log_sender: &Sender<DelayedMessage>,
+    dead_letters: &Option<Arc<DeadLetterQueue>>,
+    els: &Logger,
 ) -> Result<(), ExecutorError> {
-    loop {
-        let message = match receiver.recv() {
-            Ok(message) => message,
-            Err(RecvError) => return Ok(()),
+    // Messages serving a GET request are neither retried nor kept: nobody will be
+    // waiting for their response anymore.
+    if message.response_sender.is_none() {
+        let retried = match &module.retry_policy {
+            Some(policy) => schedule_retry(policy, error, message, module, delayed_log_sender),
+            None => false,
         };
 
-        let immediate_sender = if cancellation_token.is_cancelled() {
+        // Keep the message so it can be replayed once the cause of the failure is fixed
+        if let (false, Some(dead_letters)) = (retried, dead_letters) {
+            api.runtime.block_on(dead_letters.record(
+                &module.name,
+                error.to_string(),
+                message.create_duplicate(),
+            ));
+        }
+    }
+
+    els.log_module_error(
+        module.name.clone(),
+        format!("{error}"),
+        message.data.clone(),
+    )?;
+    Ok(())
+}
+
+/// Start the span of `module` processing `message`
+fn execution_span(module: &str, message: &Message, span_id: String) -> Span {
+    Span::start(
+        module,
+        &message.trace.trace_id,
+        span_id,
+        message.trace.parent_span_id.clone(),
+    )
+    .with_attribute("plaid.module", module)
+    .with_attribute("plaid.log_type", &message.type_)
+    .with_attribute("plaid.message_id", &message.id)
+    .with_attribute("plaid.source", &message.source)
+    .with_attribute("plaid.attempt", message.attempt())
+}
+
+/// Queue another attempt at processing a message that `module` failed to process, if its
+/// retry policy allows it. Retries go through the delayed logback system so they are persisted
+/// until it is time to run them.
+///
+/// Returns whether a retry was scheduled.
+fn schedule_retry(
+    policy: &RetryPolicy,
+    error: &ModuleExecutionError,
+    message: &Message,
+    module: &PlaidModule,
+    delayed_log_sender: &Sender<DelayedMessage>,
+) -> bool {
+    let attempt = message.attempt();
+    let Some(delay) = policy.next_retry_delay(error, attempt) else {
+        return false;
+    };
+
+    // The retry is a new message as far as the delayed logback system is concerned,
+    // so it needs its own ID.
+    let mut retry = message.create_duplicate();
+    retry.id = uuid::Uuid::new_v4().to_string();
+    retry.retry = Some(RetryInfo {
+        module: module.name.clone(),
+        attempt: attempt + 1,
+    });
+
+    match delayed_log_sender.try_send(DelayedMessage::new(delay, retry)) {
+        Ok(()) => {
+            info!(
+                "Attempt {attempt} of {} by [{}] failed. Retrying in {delay} seconds",
+                policy.max_attempts, module.name
+            );
+            true
+        }
+        Err(e) => {
+            error!(
+                "Failed to schedule a retry for [{}]. Error: {e}",
+                module.name
+            );
+            false
+        }
+    }
+}
+
+/// Check a module's execution limits before it runs on a message. Returns the permission to run
+/// the module, or `None` if the message was dropped or deferred because the module is at a limit.
+fn check_execution_limits(
+    module: &Arc<PlaidModule>,
+    message: &Message,
+    delayed_log_sender: &Sender<DelayedMessage>,
+    module_execution_metrics: &Option<Arc<ModuleExecutionMetrics>>,
+) -> Option<ExecutionPermit> {
+    let Some(throttle) = &module.throttle else {
+        return Some(ExecutionPermit::unlimited());
+    };
+
+    // Someone is waiting on the response to a GET request, so it can only be queued
+    let behavior = if message.response_sender.is_some() {
+        LimitBehavior::Queue
+    } else {
+        throttle.on_limit()
+    };
+
+    let throttled = match throttle.acquire(behavior == LimitBehavior::Queue) {
+        Ok(permit) => {
+            if let (Some(reason), Some(metrics)) = (permit.waited_for, module_execution_metrics) {
+                metrics.record_throttled(&module.name, reason, "queued");
+            }
+            return Some(permit);
+        }
+        Err(throttled) => throttled,
+    };
+
+    let action = match behavior {
+        LimitBehavior::Delay => {
+            let delay = (throttled.retry_after.as_secs_f64().ceil() as u64).max(1);
+            // The deferred message is a new message as far as the delayed logback system
+            // is concerned. It is still meant for this module only.
+            let mut deferred = message.create_duplicate();
+            deferred.id = uuid::Uuid::new_v4().to_string();
+            deferred.retry = Some(RetryInfo {
+                module: module.name.clone(),
+                attempt: message.attempt(),
+            });
+
+            match delayed_log_sender.try_send(DelayedMessage::new(delay, deferred)) {
+                Ok(()) => {
+                    debug!(
+                        "[{}] is at its {} limit. Deferred a [{}] log by {delay} seconds",
+                        module.name,
+                        throttled.reason.as_str(),
+                        message.type_
+                    );
+                    "delayed"
+                }
+                Err(e) => {
+                    error!(
+                        "[{}] is at its {} limit and a [{}] log could not be deferred, dropping it. Error: {e}",
+                        module.name,
+                        throttled.reason.as_str(),
+                        message.type_
+                    );
+                    "dropped"
+                }
+            }
+        }
+        LimitBehavior::Queue | LimitBehavior::Drop => {
+            warn!(
+                "[{}] is at its {} limit. Dropping a [{}] log",
+                module.name,
+                throttled.reason.as_str(),
+                message.type_
+            );
+            "dropped"
+        }
+    };
+
+    if let Some(metrics) = module_execution_metrics {
+        metrics.record_throttled(&module.name, throttled.reason, action);
+    }
+    None
+}
+
+fn execution_loop(
+    receiver: PoolReceiver,
+    modules: Arc<LiveModules>,
+    context: ExecutionContext,
+    immediate_sender: Weak<PoolSender>,
+    non_concurrent_routing: NonConcurrentRouting,
+    recorder: Option<Arc<MessageRecorder>>,
+    write_ahead_log: Option<Arc<WriteAheadLog>>,
+    stop: CancellationToken,
+    batches: Arc<PendingBatches>,
+) -> Result<(), ExecutorError> {
+    let ExecutionContext {
+        api,
+        els,
+        delayed_log_sender,
+        module_execution_metrics,
+        cancellation_token,
+        dead_letters,
+        ..
+    } = &context;
+    let current_immediate_sender = || {
+        if cancellation_token.is_cancelled() {
             None
         } else {
             immediate_sender.upgrade().map(|sender| (*sender).clone())
+        }
+    };
+
+    // Run a module on a batch of messages, then let go of the ones from the write-ahead log
+    let deliver_batch = |module: &str, messages: Vec<Message>| -> Result<(), ExecutorError> {
+        let current_modules = modules.current();
+        match current_modules.get_module(module) {
+            Some(module) => {
+                // A batch can be neither dropped nor deferred as a whole, so it waits until
+                // the module is within its limits
+                let _permit = module
+                    .throttle
+                    .as_ref()
+                    .map(|throttle| throttle.acquire(true));
+                process_message_with_module(
+                    batch_message(&messages),
+                    module,
+                    &context,
+                    current_immediate_sender(),
+                    current_modules.clone(),
+                    Some(messages.iter().map(Message::create_duplicate).collect()),
+                )?;
+            }
+            None => warn!(
+                "Dropping a batch of {} logs meant for module [{module}]: it is no longer loaded",
+                messages.len()
+            ),
+        }
+
+        if let Some(write_ahead_log) = &write_ahead_log {
+            for message in messages.iter().filter(|m| WriteAheadLog::is_logged(m)) {
+                if batches.release(&message.id) {
+                    api.runtime.block_on(write_ahead_log.complete(&message.id));
+                }
+            }
+        }
+        Ok(())
+    };
+
+    loop {
+        // Past the shutdown deadline, the messages left in the queue are persisted instead.
+        // The ones already taken into batches are delivered.
+        if stop.is_cancelled() {
+            for (module, messages) in batches.take_all() {
+                deliver_batch(&module, messages)?;
+            }
+            return Ok(());
+        }
+
+        for (module, messages) in batches.take_due() {
+            deliver_batch(&module, messages)?;
+        }
+
+        // Wake up in time to deliver the next batch, if one is being filled
+        let message = match receiver.recv(batches.next_due()) {
+            Ok(message) => message,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => {
+                for (module, messages) in batches.take_all() {
+                    deliver_batch(&module, messages)?;
+                }
+                return Ok(());
+            }
         };
 
+        // Non-concurrent log types can only be run by the non-concurrent thread
+        let mut message = match non_concurrent_routing.route(message) {
+            Some(message) => message,
+            None => continue,
+        };
+
+        // Retries and deferred messages were already recorded the first time around
+        if let (Some(recorder), None) = (&recorder, &message.retry) {
+            recorder.record(&message);
+        }
+
+        let immediate_sender = current_immediate_sender();
+
+        // Modules can be reloaded at any time: take the ones loaded right now and use
+        // them for the whole message.
+        let current_modules = modules.current();
+
+        // A retried or deferred message is only run by the module it is meant for
+        if let (None, Some(retry)) = (&message.module, &message.retry) {
+            match current_modules.get_module(&retry.module) {
+                Some(module) => message.module = Some(module),
+                None => {
+                    warn!(
+                        "Dropping [{}] log meant for module [{}]: it is no longer loaded",
+                        message.type_, retry.module
+                    );
+                    continue;
+                }
+            }
+        }
+
+        // Logs from webhooks stay in the write-ahead log until every module has processed
+        // them, including the modules which take them into batches
+        let logged = write_ahead_log
+            .as_ref()
+            .filter(|_| WriteAheadLog::is_logged(&message))
+            .map(|write_ahead_log| (write_ahead_log, message.id.clone()));
+        if let Some((_, id)) = &logged {
+            batches.hold(id);
+        }
+
         // Check that we know what modules to send this new log to
-        match (&message.module, modules.get(&message.type_)) {
+        match (&message.module, current_modules.get_channel(&message.type_)) {
             // If this message has a response sender, we only
             // want to run it on that rule, not any defined logging
             // channel.
             (Some(ref module), _) => {
                 let module = module.clone();
+                if let (Some(_), Err(error)) = (&module.batching, check_batchable(&message)) {
+                    handle_failure(
+                        &error,
+                        &message,
+                        &module,
+                        api,
+                        delayed_log_sender,
+                        dead_letters,
+                        els,
+                    )?;
+                    continue;
+                }
+                let Some(_permit) = check_execution_limits(
+                    &module,
+                    &message,
+                    delayed_log_sender,
+                    module_execution_metrics,
+                ) else {
+                    continue;
+                };
+                // A module which takes batches only has a batch entrypoint, so a message
+                // meant for it alone is a batch of one
+                let (message, batch) = match module.batching {
+                    Some(_) => (
+                        batch_message(std::slice::from_ref(&message)),
+                        Some(vec![message]),
+                    ),
+                    None => (message, None),
+                };
                 process_message_with_module(
                     message,
                     module,
-                    api.clone(),
-                    storage.clone(),
-                    cache.clone(),
-                    els.clone(),
-                    performance_monitoring_mode.clone(),
-                    module_execution_metrics.clone(),
+                    &context,
                     immediate_sender.clone(),
-                    delayed_log_sender.clone(),
-                    cancellation_token.clone(),
+                    current_modules.clone(),
+                    batch,
                 )?;
             }
             (None, Some(modules)) => {
+                let filter_input = FilterInput::new(&message);
                 // For every module that operates on that log type
                 for module in modules {
+                    // Skip modules which are not subscribed to this message, before paying
+                    // for an instance of them
+                    if let Some(filter) = &module.filter {
+                        if !filter.matches(&filter_input) {
+                            continue;
+                        }
+                    }
+
+                    // The module runs once its batch is full or has waited long enough
+                    if let Some(batching) = &module.batching {
+                        if let Err(error) = check_batchable(&message) {
+                            handle_failure(
+                                &error,
+                                &message,
+                                module,
+                                api,
+                                delayed_log_sender,
+                                dead_letters,
+                                els,
+                            )?;
+                            continue;
+                        }
+                        if let Some((_, id)) = &logged {
+                            batches.hold(id);
+                        }
+                        for batch in batches.add(&module.name, batching, message.create_duplicate())
+                        {
+                            deliver_batch(&module.name, batch)?;
+                        }
+                        continue;
+                    }
+
+                    let Some(_permit) = check_execution_limits(
+                        module,
+                        &message,
+                        delayed_log_sender,
+                        module_execution_metrics,
+                    ) else {
+                        continue;
+                    };
                     process_message_with_module(
                         message.create_duplicate(),
                         module.clone(),
-                        api.clone(),
-                        storage.clone(),
-                        cache.clone(),
-                        els.clone(),
-                        performance_monitoring_mode.clone(),
-                        module_execution_metrics.clone(),
+                        &context,
                         immediate_sender.clone(),
-                        delayed_log_sender.clone(),
-                        cancellation_token.clone(),
+                        current_modules.clone(),
+                        None,
                     )?;
                 }
             }
@@ -700,12 +1344,52 @@ fn execution_loop(
                     "Got logs of a type we have no modules for? Type was: {}",
                     message.type_
                 );
-                continue;
             }
         };
+
+        // Every module has processed the message, so it does not need to be replayed
+        if let Some((write_ahead_log, id)) = logged {
+            if batches.release(&id) {
+                api.runtime.block_on(write_ahead_log.complete(&id));
+            }
+        }
     }
 }
 
+/// Run a prepared instance's entrypoint and return the response the module set, or why it
+/// failed, along with how much of `computation_limit` it used
+fn run_prepared_instance(
+    prepared: &mut PreparedInstance,
+    computation_limit: u64,
+) -> (Result<Option<String>, ModuleExecutionError>, u64) {
+    let result = prepared.entrypoint.call(&mut prepared.store);
+    let computation_used = match get_remaining_points(&mut prepared.store, &prepared.instance) {
+        MeteringPoints::Remaining(remaining) => computation_limit.saturating_sub(remaining),
+        MeteringPoints::Exhausted => computation_limit,
+    };
+
+    let response = match result {
+        Ok(0) => Ok(prepared.env.as_mut(&mut prepared.store).response.take()),
+        Ok(_) => Err(ModuleExecutionError::ModuleError(
+            prepared
+                .env
+                .as_ref(&prepared.store)
+                .execution_error_context
+                .clone()
+                .unwrap_or("None".to_string()),
+        )),
+        Err(e) => Err(determine_error(
+            e,
+            computation_limit,
+            &prepared.instance,
+            &mut prepared.store,
+            &prepared.env,
+        )),
+    };
+
+    (response, computation_used)
+}
+
 fn determine_error(
     e: RuntimeError,
     computation_limit: u64,
@@ -718,6 +1402,17 @@ fn determine_error(
         return ModuleExecutionError::ComputationExhausted(computation_limit);
     }
 
+    // Then check if the module was stopped because it ran out of time
+    let env_data = env.as_ref(&store);
+    if let (Some(deadline), Some(timeout)) = (
+        env_data.execution_deadline,
+        env_data.module.execution_timeout,
+    ) {
+        if Instant::now() >= deadline {
+            return ModuleExecutionError::TimeoutExceeded(timeout);
+        }
+    }
+
     // If all else fails, it's an unknown error
     ModuleExecutionError::UnknownExecutionError(format!(
         "{e}. Additional context: {}",
@@ -731,46 +1426,39 @@ fn determine_error(
 impl Executor {
     pub fn new(
         thread_pools: ExecutionThreadPools,
-        modules: HashMap<String, Vec<Arc<PlaidModule>>>,
-        api: Arc<Api>,
-        storage: Option<Arc<Storage>>,
-        cache: Option<Arc<Cache>>,
-        els: Logger,
-        performance_monitoring_mode: Option<Sender<ModulePerformanceMetadata>>,
-        module_execution_metrics: Option<Arc<ModuleExecutionMetrics>>,
-        immediate_sender: Weak<Sender<Message>>,
-        delayed_log_sender: Sender<DelayedMessage>,
-        cancellation_token: CancellationToken,
+        modules: Arc<LiveModules>,
+        context: ExecutionContext,
+        immediate_sender: Weak<PoolSender>,
+        recorder: Option<Arc<MessageRecorder>>,
+        write_ahead_log: Option<Arc<WriteAheadLog>>,
     ) -> (Self, ExecutorThreads) {
         let mut thread_handles = Vec::new();
+        let stop = CancellationToken::new();
 
         // General processing
+        let batches = Arc::new(PendingBatches::default());
         for i in 0..thread_pools.general_pool.num_threads {
             info!("Starting Execution Thread {i} Dedicated to General Processing");
             let receiver = thread_pools.general_pool.receiver.clone();
-            let api = api.clone();
-            let storage = storage.clone();
-            let cache = cache.clone();
             let modules = modules.clone();
-            let els = els.clone();
-            let performance_sender = performance_monitoring_mode.clone();
-            let module_execution_metrics = module_execution_metrics.clone();
+            let context = context.clone();
             let immediate_sender = immediate_sender.clone();
-            let delayed_log_sender = delayed_log_sender.clone();
-            let cancellation_token = cancellation_token.clone();
+            let non_concurrent_routing = thread_pools.non_concurrent_routing();
+            let recorder = recorder.clone();
+            let write_ahead_log = write_ahead_log.clone();
+            let stop = stop.clone();
+            let batches = batches.clone();
             let handle = thread::spawn(move || {
                 if let Err(e) = execution_loop(
-                    receiver.clone(),
-                    modules.clone(),
-                    api.clone(),
-                    storage.clone(),
-                    cache.clone(),
-                    els.clone(),
-                    performance_sender.clone(),
-                    module_execution_metrics.clone(),
-                    immediate_sender.clone(),
-                    delayed_log_sender.clone(),
-                    cancellation_token.clone(),
+                    receiver,
+                    modules,
+                    context,
+                    immediate_sender,
+                    non_concurrent_routing,
+                    recorder,
+                    write_ahead_log,
+                    stop,
+                    batches,
                 ) {
                     error!("General execution thread {i} exited with error: {e}");
                 }
@@ -780,33 +1468,30 @@ impl Executor {
 
         // Dedicated processing
         for (log_type, thread_pool) in &thread_pools.dedicated_pools {
+            let batches = Arc::new(PendingBatches::default());
             for i in 0..thread_pool.num_threads {
                 info!("Starting Execution Thread {i} Dedicated to {log_type}");
                 let receiver = thread_pool.receiver.clone();
-                let api = api.clone();
-                let storage = storage.clone();
-                let cache = cache.clone();
                 let modules = modules.clone();
-                let els = els.clone();
-                let performance_sender = performance_monitoring_mode.clone();
-                let module_execution_metrics = module_execution_metrics.clone();
+                let context = context.clone();
                 let log_type = log_type.clone();
                 let immediate_sender = immediate_sender.clone();
-                let delayed_log_sender = delayed_log_sender.clone();
-                let cancellation_token = cancellation_token.clone();
+                let non_concurrent_routing = thread_pools.non_concurrent_routing();
+                let recorder = recorder.clone();
+                let write_ahead_log = write_ahead_log.clone();
+                let stop = stop.clone();
+                let batches = batches.clone();
                 let handle = thread::spawn(move || {
                     if let Err(e) = execution_loop(
-                        receiver.clone(),
-                        modules.clone(),
-                        api.clone(),
-                        storage.clone(),
-                        cache.clone(),
-                        els.clone(),
-                        performance_sender.clone(),
-                        module_execution_metrics.clone(),
-                        immediate_sender.clone(),
-                        delayed_log_sender.clone(),
-                        cancellation_token.clone(),
+                        receiver,
+                        modules,
+                        context,
+                        immediate_sender,
+                        non_concurrent_routing,
+                        recorder,
+                        write_ahead_log,
+                        stop,
+                        batches,
                     ) {
                         error!("{log_type} dedicated execution thread {i} exited with error: {e}");
                     }
@@ -814,16 +1499,73 @@ impl Executor {
                 thread_handles.push(handle);
             }
         }
-        (Self { thread_pools }, ExecutorThreads { thread_handles })
+
+        // Non-concurrent processing. This pool only ever has one thread, and only exists
+        // if this instance is responsible for running non-concurrent log types.
+        if let Some(thread_pool) = &thread_pools.non_concurrent_pool {
+            info!("Starting Execution Thread Dedicated to Non-Concurrent Log Types");
+            let receiver = thread_pool.receiver.clone();
+            let modules = modules.clone();
+            let context = context.clone();
+            let immediate_sender = immediate_sender.clone();
+            let non_concurrent_routing = thread_pools.non_concurrent_pool_routing();
+            let recorder = recorder.clone();
+            let write_ahead_log = write_ahead_log.clone();
+            let stop = stop.clone();
+            let batches = Arc::new(PendingBatches::default());
+            let handle = thread::spawn(move || {
+                if let Err(e) = execution_loop(
+                    receiver,
+                    modules,
+                    context,
+                    immediate_sender,
+                    non_concurrent_routing,
+                    recorder,
+                    write_ahead_log,
+                    stop,
+                    batches,
+                ) {
+                    error!("Non-concurrent execution thread exited with error: {e}");
+                }
+            });
+            thread_handles.push(handle);
+        }
+        (
+            Self { thread_pools },
+            ExecutorThreads {
+                thread_handles,
+                stop,
+            },
+        )
     }
 
     /// Execute a message coming from a webhook, by sending it to the appropriate thread pool.
-    /// That will be the thread pool dedicated to the message's type, if one exists, or the
+    /// That will be the non-concurrent thread pool if the message's type is marked as
+    /// non-concurrent, the thread pool dedicated to the message's type, if one exists, or the
     /// default thread pool for general execution.
+    ///
+    /// Messages of a non-concurrent type are dropped if this instance does not run them.
     pub fn execute_webhook_message(
         self: &Self,
         message: Message,
     ) -> Result<(), TrySendError<Message>> {
+        if self
+            .thread_pools
+            .non_concurrent_log_types
+            .contains(&message.type_)
+        {
+            return match &self.thread_pools.non_concurrent_pool {
+                Some(tp) => tp.sender.try_send(message),
+                None => {
+                    warn!(
+                        "Dropping log of non-concurrent type [{}]: this instance does not run non-concurrent log types",
+                        message.type_
+                    );
+                    Ok(())
+                }
+            };
+        }
+
         let sender = match self.thread_pools.dedicated_pools.get(&message.type_) {
             Some(tp) => {
                 // We have a dedicated thread pool for this type: send the log to that channel
@@ -836,4 +1578,14 @@ impl Executor {
         };
         sender.try_send(message)
     }
+
+    /// Whether webhook messages of this log type are dropped instead of executed, because they
+    /// are of a non-concurrent type and this instance does not run those
+    pub fn drops_log_type(&self, log_type: &str) -> bool {
+        self.thread_pools.non_concurrent_pool.is_none()
+            && self
+                .thread_pools
+                .non_concurrent_log_types
+                .contains(log_type)
+    }
 }
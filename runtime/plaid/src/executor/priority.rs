@@ -0,0 +1,292 @@
+use std::collections::{HashMap, VecDeque};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+use plaid_stl::messages::LogSource;
+use serde::Deserialize;
+
+use super::Message;
+
+/// Configuration for priority lanes within the execution thread pools. Each pool (except the
+/// non-concurrent one, which keeps its messages in order) queues its messages in one bounded
+/// lane per priority, and its threads take messages from the lanes in proportion to their
+/// weights. A burst of messages in one lane then does not hold up the messages in the others,
+/// even once that lane is full.
+///
+/// A message goes to the lane of its webhook or generator if it has one, otherwise to the
+/// lane of its log type if it has one, otherwise to the default lane.
+#[derive(Deserialize)]
+pub struct PriorityConfig {
+    /// The lanes. The mapping is `{lane -> settings}`
+    pub lanes: HashMap<String, LaneConfig>,
+    /// The lane of the messages which are not assigned to another one
+    pub default_lane: String,
+    /// The lanes of the messages received by webhooks, keyed by webhook (or by its label if
+    /// it has one). The mapping is `{webhook -> lane}`
+    #[serde(default)]
+    pub webhooks: HashMap<String, String>,
+    /// The lanes of the messages produced by data generators, keyed by generator (like
+    /// `github`, or `interval/<job>`). The mapping is `{generator -> lane}`
+    #[serde(default)]
+    pub generators: HashMap<String, String>,
+    /// The lanes of the messages of log types. The mapping is `{log type -> lane}`
+    #[serde(default)]
+    pub log_types: HashMap<String, String>,
+}
+
+/// Settings for a priority lane
+#[derive(Deserialize)]
+pub struct LaneConfig {
+    /// How many messages are taken from this lane for every message taken from a lane with a
+    /// weight of 1, while both have messages queued
+    pub weight: NonZeroU32,
+    /// The maximum number of messages queued in this lane, in each thread pool. When it is
+    /// full, the messages for this lane wait for room (or are dropped by the senders which
+    /// do not wait), while the other lanes keep taking messages.
+    #[serde(default = "default_lane_queue_size")]
+    pub queue_size: usize,
+}
+
+fn default_lane_queue_size() -> usize {
+    1024
+}
+
+impl PriorityConfig {
+    /// A lane that messages are assigned to but which is not configured, if there is one
+    pub fn unknown_lane(&self) -> Option<&str> {
+        std::iter::once(&self.default_lane)
+            .chain(self.webhooks.values())
+            .chain(self.generators.values())
+            .chain(self.log_types.values())
+            .find(|lane| !self.lanes.contains_key(*lane))
+            .map(String::as_str)
+    }
+}
+
+/// Decides which lane each message goes to. This is shared by all the thread pools.
+pub struct PriorityRouter {
+    /// The lanes' names, weights and sizes, sorted by name
+    lanes: Vec<(String, u32, usize)>,
+    default_lane: usize,
+    webhooks: HashMap<String, usize>,
+    generators: HashMap<String, usize>,
+    log_types: HashMap<String, usize>,
+}
+
+impl PriorityRouter {
+    /// Build the router from a configuration whose lanes were all checked to exist
+    pub fn new(config: &PriorityConfig) -> Self {
+        let mut lanes: Vec<(String, u32, usize)> = config
+            .lanes
+            .iter()
+            .map(|(name, lane)| (name.clone(), lane.weight.get(), lane.queue_size))
+            .collect();
+        lanes.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let index = |lane: &String| {
+            lanes
+                .iter()
+                .position(|(name, _, _)| name == lane)
+                .expect("priority lanes are validated when the configuration is loaded")
+        };
+        let indexes = |assignments: &HashMap<String, String>| {
+            assignments
+                .iter()
+                .map(|(key, lane)| (key.clone(), index(lane)))
+                .collect()
+        };
+
+        Self {
+            default_lane: index(&config.default_lane),
+            webhooks: indexes(&config.webhooks),
+            generators: indexes(&config.generators),
+            log_types: indexes(&config.log_types),
+            lanes,
+        }
+    }
+
+    /// The lane a message goes to
+    fn lane(&self, message: &Message) -> usize {
+        let by_source = match &message.source {
+            LogSource::WebhookPost(webhook) | LogSource::WebhookGet(webhook) => {
+                self.webhooks.get(webhook)
+            }
+            LogSource::Generator(generator) => self.generators.get(&generator.to_string()),
+            LogSource::Logback(_) | LogSource::ModuleInvocation(_) => None,
+        };
+
+        by_source
+            .or_else(|| self.log_types.get(&message.type_))
+            .copied()
+            .unwrap_or(self.default_lane)
+    }
+}
+
+/// A lane of a thread pool
+struct Lane {
+    weight: i64,
+    capacity: usize,
+    /// The lane's standing in the weighted round robin: the lane with the most credit that has
+    /// messages queued goes next
+    credit: i64,
+    queue: VecDeque<Message>,
+}
+
+/// The lanes of a single thread pool. Messages are taken from the lanes with a smooth weighted
+/// round robin: over any stretch where all lanes have messages queued, each lane gets a share
+/// of the turns proportional to its weight, and the turns are spread out rather than bunched.
+pub struct PriorityLanes {
+    router: Arc<PriorityRouter>,
+    lanes: Vec<Lane>,
+}
+
+impl PriorityLanes {
+    pub fn new(router: Arc<PriorityRouter>) -> Self {
+        let lanes = router
+            .lanes
+            .iter()
+            .map(|(_, weight, capacity)| Lane {
+                weight: *weight as i64,
+                // A lane always has room for a message, so its senders are not stuck forever
+                capacity: (*capacity).max(1),
+                credit: 0,
+                queue: VecDeque::new(),
+            })
+            .collect();
+
+        Self { router, lanes }
+    }
+
+    /// Whether the lane of a message has room for it
+    pub fn has_room(&self, message: &Message) -> bool {
+        let lane = &self.lanes[self.router.lane(message)];
+        lane.queue.len() < lane.capacity
+    }
+
+    /// Queue a message in its lane. Must only be called if the lane has room for it.
+    pub fn push(&mut self, message: Message) {
+        let lane = self.router.lane(&message);
+        self.lanes[lane].queue.push_back(message);
+    }
+
+    /// Take the next message, according to the lanes' weights
+    pub fn pop(&mut self) -> Option<Message> {
+        let total: i64 = self
+            .lanes
+            .iter()
+            .filter(|lane| !lane.queue.is_empty())
+            .map(|lane| lane.weight)
+            .sum();
+        for lane in self.lanes.iter_mut().filter(|lane| !lane.queue.is_empty()) {
+            lane.credit += lane.weight;
+        }
+        let lane = self
+            .lanes
+            .iter_mut()
+            .filter(|lane| !lane.queue.is_empty())
+            .reduce(|best, lane| {
+                if lane.credit > best.credit {
+                    lane
+                } else {
+                    best
+                }
+            })?;
+        lane.credit -= total;
+        lane.queue.pop_front()
+    }
+
+    /// How many messages are queued in all the lanes
+    pub fn queued(&self) -> usize {
+        self.lanes.iter().map(|lane| lane.queue.len()).sum()
+    }
+
+    /// How many messages are queued in each lane, by lane name
+    pub fn depths(&self) -> Vec<(&str, usize)> {
+        self.router
+            .lanes
+            .iter()
+            .zip(&self.lanes)
+            .map(|((name, _, _), lane)| (name.as_str(), lane.queue.len()))
+            .collect()
+    }
+
+    /// Take all the queued messages
+    pub fn drain(&mut self) -> Vec<Message> {
+        self.lanes
+            .iter_mut()
+            .flat_map(|lane| lane.queue.drain(..))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::webhook_message;
+
+    fn lanes(capacity: usize) -> PriorityLanes {
+        let lane = |weight| LaneConfig {
+            weight: NonZeroU32::new(weight).unwrap(),
+            queue_size: capacity,
+        };
+        let config = PriorityConfig {
+            lanes: HashMap::from([("high".to_string(), lane(3)), ("low".to_string(), lane(1))]),
+            default_lane: "low".to_string(),
+            webhooks: HashMap::from([("alerts".to_string(), "high".to_string())]),
+            generators: HashMap::new(),
+            log_types: HashMap::from([("pages".to_string(), "high".to_string())]),
+        };
+        assert!(config.unknown_lane().is_none());
+        PriorityLanes::new(Arc::new(PriorityRouter::new(&config)))
+    }
+
+    fn message(log_type: &str, webhook: &str) -> Message {
+        webhook_message(log_type, webhook, b"")
+    }
+
+    #[test]
+    fn lanes_are_served_by_weight() {
+        let mut lanes = lanes(100);
+        for _ in 0..8 {
+            lanes.push(message("bulk", "github"));
+        }
+        for _ in 0..6 {
+            lanes.push(message("bulk", "alerts"));
+            lanes.push(message("pages", "github"));
+        }
+
+        let order: String = std::iter::from_fn(|| lanes.pop())
+            .map(|message| match message.source {
+                LogSource::WebhookPost(webhook) if webhook == "alerts" => 'h',
+                _ if message.type_ == "pages" => 'h',
+                _ => 'l',
+            })
+            .collect();
+        // Three high priority messages for every low priority one, spread out, until the
+        // high priority lane is empty
+        assert_eq!(&order[..16], "hhlhhhlhhhlhhhlh");
+        assert_eq!(&order[16..], "llll");
+    }
+
+    #[test]
+    fn a_full_lane_only_refuses_its_own_messages() {
+        let mut lanes = lanes(1);
+        let bulk = message("bulk", "github");
+        assert!(lanes.has_room(&bulk));
+        lanes.push(bulk);
+        assert!(!lanes.has_room(&message("bulk", "github")));
+
+        let alert = message("bulk", "alerts");
+        assert!(lanes.has_room(&alert));
+        lanes.push(alert);
+        assert_eq!(lanes.depths(), vec![("high", 1), ("low", 1)]);
+        assert_eq!(lanes.queued(), 2);
+
+        // The high priority message goes first, even though the low priority lane filled up
+        // before it arrived
+        assert!(lanes.pop().unwrap().source == LogSource::WebhookPost("alerts".to_string()));
+        assert!(lanes.pop().is_some());
+        assert!(lanes.has_room(&message("bulk", "github")));
+        assert!(lanes.pop().is_none());
+    }
+}
@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread;
+
+use crossbeam_channel::{bounded, unbounded, Sender, TrySendError};
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use super::{prepare_for_execution, run_prepared_instance, Message, PoolSender};
+use crate::apis::Api;
+use crate::cache::Cache;
+use crate::data::{DelayedMessage, ScheduledLogbacks};
+use crate::loader::{PlaidModule, PlaidModules};
+use crate::logging::Logger;
+use crate::storage::{Storage, StorageError};
+
+/// How many runs can wait for a shadow's thread before new ones are dropped
+const SHADOW_QUEUE_SIZE: usize = 1024;
+
+/// Something a module attempted to do that has an effect outside of it. These are recorded
+/// for modules that have a shadow, so the two can be compared.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum SideEffect {
+    /// The module called this API function
+    HostCall(String),
+    /// The module logged back a message of this log type, with this delay in seconds
+    Logback { log_type: String, delay: u32 },
+    /// The module stored a value under this key of its namespace
+    StorageInsert { key: String, value_sha256: String },
+    /// The module deleted this key of its namespace
+    StorageDelete { key: String },
+}
+
+impl std::fmt::Display for SideEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HostCall(function) => write!(f, "called {function}"),
+            Self::Logback { log_type, delay: 0 } => write!(f, "logged back to [{log_type}]"),
+            Self::Logback { log_type, delay } => {
+                write!(f, "logged back to [{log_type}] with a delay of {delay}s")
+            }
+            Self::StorageInsert { key, value_sha256 } => {
+                write!(f, "stored [{key}] with a value of SHA-256 {value_sha256}")
+            }
+            Self::StorageDelete { key } => write!(f, "deleted [{key}]"),
+        }
+    }
+}
+
+/// A shadow module, with the thread it runs on. Shadows run on their own thread so they
+/// never hold up the threads that execute the primary modules.
+#[derive(Clone)]
+pub struct Shadow {
+    pub module: Arc<PlaidModule>,
+    runs: Sender<ShadowRun>,
+}
+
+impl Shadow {
+    /// Start the thread of a shadow module. It stops once the primary module and every
+    /// version of it that was reloaded are dropped.
+    pub fn new(module: Arc<PlaidModule>) -> Self {
+        let (runs, receiver) = bounded::<ShadowRun>(SHADOW_QUEUE_SIZE);
+        let shadow = module.clone();
+        thread::spawn(move || {
+            for run in receiver {
+                run_shadow(&shadow, run);
+            }
+        });
+        Self { module, runs }
+    }
+
+    /// Queue a run of the shadow. If the shadow has fallen too far behind its primary, the
+    /// run is dropped rather than slowing the primary down.
+    pub(super) fn submit(&self, run: ShadowRun) {
+        if let Err(TrySendError::Full(run)) = self.runs.try_send(run) {
+            warn!(
+                "Shadow module [{}] is falling behind and will not run on message [{}]",
+                self.module.name, run.message.id
+            );
+        }
+    }
+}
+
+/// What the shadow of a module needs to process the message the module just processed, and
+/// to compare the two runs
+pub(super) struct ShadowRun {
+    /// The primary module
+    pub module: String,
+    pub message: Message,
+    /// What the primary module's run returned, or why it failed
+    pub primary: Result<Option<String>, String>,
+    /// What the primary module attempted
+    pub primary_side_effects: Vec<SideEffect>,
+    /// The value each key the primary module wrote had before its run
+    pub primary_storage_before_writes: HashMap<String, Option<Vec<u8>>>,
+    pub api: Arc<Api>,
+    /// The storage of the primary module. The shadow only reads it.
+    pub storage: Option<Arc<Storage>>,
+    pub cache: Option<Arc<Cache>>,
+    pub els: Logger,
+    pub scheduled_logbacks: Arc<ScheduledLogbacks>,
+    pub modules: Arc<PlaidModules>,
+}
+
+/// How a shadow module processed a message, compared to its primary module
+#[derive(Serialize)]
+pub struct ShadowComparison {
+    /// The primary module
+    pub module: String,
+    /// The shadow module
+    pub shadow: String,
+    pub message_id: String,
+    /// Why the primary module failed, if it did
+    pub primary_error: Option<String>,
+    /// Why the shadow module failed, if it did
+    pub shadow_error: Option<String>,
+    pub primary_side_effects: Vec<SideEffect>,
+    pub shadow_side_effects: Vec<SideEffect>,
+    /// How the shadow module's run differed from the primary's. Empty if they behaved the same.
+    pub differences: Vec<String>,
+}
+
+/// Describe how the run of a shadow module differed from the run of its primary module
+fn differences(
+    primary: &Result<Option<String>, String>,
+    shadow: &Result<Option<String>, String>,
+    primary_side_effects: &[SideEffect],
+    shadow_side_effects: &[SideEffect],
+) -> Vec<String> {
+    let mut differences = vec![];
+    match (primary, shadow) {
+        (Ok(_), Err(e)) => differences.push(format!("only the shadow failed: {e}")),
+        (Err(e), Ok(_)) => differences.push(format!("only the primary failed: {e}")),
+        (Ok(primary), Ok(shadow)) if primary != shadow => {
+            differences.push("the responses differ".to_string())
+        }
+        _ => (),
+    }
+
+    let outcome_differences = differences.len();
+    // Side effects that one module attempted more times than the other
+    let mut only_shadow = shadow_side_effects.to_vec();
+    for effect in primary_side_effects {
+        match only_shadow.iter().position(|other| other == effect) {
+            Some(index) => {
+                only_shadow.remove(index);
+            }
+            None => differences.push(format!("only the primary {effect}")),
+        }
+    }
+    for effect in only_shadow {
+        differences.push(format!("only the shadow {effect}"));
+    }
+
+    if differences.len() == outcome_differences && primary_side_effects != shadow_side_effects {
+        differences.push("the side effects happened in a different order".to_string());
+    }
+    differences
+}
+
+/// Build the storage a shadow runs against: an in-memory copy of the primary module's
+/// namespace, as it was before the primary processed the message, under the shadow's name.
+/// Nothing the shadow stores ever reaches the primary's storage.
+async fn seed_storage(
+    storage: &Storage,
+    primary: &str,
+    shadow: &str,
+    primary_storage_before_writes: HashMap<String, Option<Vec<u8>>>,
+) -> Result<(Storage, u64), StorageError> {
+    let mut namespace: HashMap<String, Option<Vec<u8>>> = storage
+        .fetch_all(primary, None)
+        .await?
+        .into_iter()
+        .collect();
+    namespace.extend(primary_storage_before_writes);
+
+    let seeded = Storage::new_in_memory();
+    let mut used_storage = 0;
+    for (key, value) in namespace {
+        if let Some(value) = value {
+            used_storage += (key.len() + value.len()) as u64;
+            seeded.insert(shadow.to_string(), key, value).await?;
+        }
+    }
+    Ok((seeded, used_storage))
+}
+
+/// Run the shadow of a module on the message the module just processed, and report how the
+/// two runs compare.
+///
+/// The shadow runs in test mode, against a copy of the primary's storage. Its logbacks are
+/// recorded but never delivered, and neither its response nor its errors go anywhere but
+/// the comparison.
+fn run_shadow(shadow: &Arc<PlaidModule>, run: ShadowRun) {
+    let storage = match &run.storage {
+        Some(storage) => match run.api.runtime.block_on(seed_storage(
+            storage,
+            &run.module,
+            &shadow.name,
+            run.primary_storage_before_writes,
+        )) {
+            Ok((seeded, used_storage)) => {
+                // The shadow's storage starts over on every run, and so does what it uses
+                match shadow.storage_current.write() {
+                    Ok(mut storage_current) => *storage_current = used_storage,
+                    Err(e) => error!("Critical error getting a lock on used storage: {:?}", e),
+                }
+                Some(Arc::new(seeded))
+            }
+            Err(e) => {
+                error!(
+                    "Shadow module [{}] will not run on message [{}] because the storage of [{}] could not be read: {e}",
+                    shadow.name, run.message.id, run.module
+                );
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // The receivers are kept until the shadow is done, so its logbacks succeed like the
+    // primary's do, but nothing ever reads them
+    let (immediate_sender, _immediate_logbacks) = PoolSender::unbounded();
+    let (delayed_log_sender, _delayed_logbacks) = unbounded::<DelayedMessage>();
+
+    let (shadow_result, shadow_side_effects) = match prepare_for_execution(
+        run.message.create_duplicate(),
+        shadow.clone(),
+        run.api,
+        storage,
+        run.cache,
+        run.els.clone(),
+        None,
+        Some(immediate_sender),
+        delayed_log_sender,
+        CancellationToken::new(),
+        None,
+        run.scheduled_logbacks,
+        run.modules,
+    ) {
+        Ok(mut prepared) => {
+            prepared.env.as_mut(&mut prepared.store).side_effects = Some(vec![]);
+            let (response, _) = run_prepared_instance(&mut prepared, shadow.computation_limit);
+            let side_effects = prepared
+                .env
+                .as_mut(&mut prepared.store)
+                .side_effects
+                .take()
+                .unwrap_or_default();
+            (response.map_err(|e| e.to_string()), side_effects)
+        }
+        Err(e) => (Err(format!("Failed to prepare for execution: {e}")), vec![]),
+    };
+
+    let comparison = ShadowComparison {
+        module: run.module,
+        shadow: shadow.name.clone(),
+        message_id: run.message.id.clone(),
+        differences: differences(
+            &run.primary,
+            &shadow_result,
+            &run.primary_side_effects,
+            &shadow_side_effects,
+        ),
+        primary_error: run.primary.err(),
+        shadow_error: shadow_result.err(),
+        primary_side_effects: run.primary_side_effects,
+        shadow_side_effects,
+    };
+    if let Err(e) = run.els.log_shadow_comparison(comparison) {
+        error!("Logging system is not working!!: {:?}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(function: &str) -> SideEffect {
+        SideEffect::HostCall(function.to_string())
+    }
+
+    #[test]
+    fn identical_runs_do_not_differ() {
+        let effects = vec![
+            call("github_add_user_to_repo"),
+            SideEffect::Logback {
+                log_type: "audit".to_string(),
+                delay: 0,
+            },
+        ];
+        assert!(differences(&Ok(None), &Ok(None), &effects, &effects).is_empty());
+        assert!(differences(
+            &Err("a".to_string()),
+            &Err("b".to_string()),
+            &effects,
+            &effects
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn differences_are_described() {
+        let primary = vec![call("slack_post_message"), call("slack_post_message")];
+        let shadow = vec![call("slack_post_message"), call("github_add_user_to_repo")];
+        assert_eq!(
+            differences(
+                &Ok(Some("a".to_string())),
+                &Err("boom".to_string()),
+                &primary,
+                &shadow
+            ),
+            vec![
+                "only the shadow failed: boom",
+                "only the primary called slack_post_message",
+                "only the shadow called github_add_user_to_repo",
+            ]
+        );
+
+        let reordered = vec![call("b"), call("a")];
+        assert_eq!(
+            differences(
+                &Ok(Some("a".to_string())),
+                &Ok(Some("b".to_string())),
+                &[call("a"), call("b")],
+                &reordered
+            ),
+            vec![
+                "the responses differ",
+                "the side effects happened in a different order"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn shadows_start_from_the_primary_namespace_before_its_run() {
+        let storage = Storage::new_in_memory();
+        for (key, value) in [("created", "1"), ("updated", "2"), ("untouched", "3")] {
+            storage
+                .insert("primary".to_string(), key.to_string(), value.into())
+                .await
+                .unwrap();
+        }
+        // The primary created a key, updated one and deleted one
+        let before_writes = HashMap::from([
+            ("created".to_string(), None),
+            ("updated".to_string(), Some(b"1".to_vec())),
+            ("deleted".to_string(), Some(b"4".to_vec())),
+        ]);
+
+        let (seeded, used_storage) = seed_storage(&storage, "primary", "shadow", before_writes)
+            .await
+            .unwrap();
+        let mut namespace = seeded.fetch_all("shadow", None).await.unwrap();
+        namespace.sort();
+        assert_eq!(
+            namespace,
+            vec![
+                ("deleted".to_string(), Some(b"4".to_vec())),
+                ("untouched".to_string(), Some(b"3".to_vec())),
+                ("updated".to_string(), Some(b"1".to_vec())),
+            ]
+        );
+        assert_eq!(used_storage, 8 + 10 + 8);
+        // The primary's storage is only read
+        assert_eq!(storage.fetch_all("primary", None).await.unwrap().len(), 3);
+        assert!(storage.fetch_all("shadow", None).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn storage_writes_are_compared() {
+        let insert = |value: &str| SideEffect::StorageInsert {
+            key: "seen".to_string(),
+            value_sha256: value.to_string(),
+        };
+        let delete = SideEffect::StorageDelete {
+            key: "stale".to_string(),
+        };
+        assert_eq!(
+            differences(
+                &Ok(None),
+                &Ok(None),
+                &[insert("a"), delete.clone()],
+                &[insert("b")]
+            ),
+            vec![
+                "only the primary stored [seen] with a value of SHA-256 a",
+                "only the primary deleted [stale]",
+                "only the shadow stored [seen] with a value of SHA-256 b",
+            ]
+        );
+    }
+}
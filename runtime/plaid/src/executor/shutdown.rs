@@ -0,0 +1,182 @@
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::thread_pools::PoolReceiver;
+use super::{ExecutorThreads, Message};
+use crate::storage::{Storage, StorageError};
+
+/// The storage namespace where the messages still queued at shutdown are kept
+const QUEUED_MESSAGES_NS: &str = "queued_messages_internal";
+
+impl ExecutorThreads {
+    /// Wait for worker threads to exit after all executor ingress senders have been dropped.
+    /// If `deadline` is set and the threads are still working through their queues when it
+    /// passes, they stop taking new messages: the ones left in the queues can then be
+    /// persisted with [`persist_queued_messages`]. Messages being executed are finished.
+    pub async fn join_within(self, deadline: Option<Duration>) {
+        let stop = self.stop.clone();
+        let mut join = tokio::task::spawn_blocking(move || self.join());
+
+        if let Some(deadline) = deadline {
+            if tokio::time::timeout(deadline, &mut join).await.is_ok() {
+                return;
+            }
+            warn!("Executor threads did not drain their queues within {deadline:?}, stopping them");
+            stop.cancel();
+        }
+
+        if let Err(e) = join.await {
+            error!("Failed to wait for execution threads: {e}");
+        }
+    }
+}
+
+/// Take the messages left in the executor's queues and store them, so they are executed when
+/// Plaid next starts. Messages for which `skip` returns true are dropped instead, and so are
+/// messages waiting for a response (from GET requests), since no one is waiting anymore.
+/// Returns how many messages were persisted.
+pub async fn persist_queued_messages(
+    receivers: Vec<PoolReceiver>,
+    storage: &Storage,
+    skip: impl Fn(&Message) -> bool,
+) -> usize {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let mut persisted = 0;
+
+    for message in receivers.iter().flat_map(PoolReceiver::drain) {
+        if message.module.is_some() || skip(&message) {
+            continue;
+        }
+
+        // Keys keep the messages in the order they were queued
+        let key = format!("{now:020}_{persisted:010}_{}", message.id);
+        let value = match serde_json::to_vec(&message) {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Failed to serialize queued [{}] log: {e}", message.type_);
+                continue;
+            }
+        };
+        match storage
+            .insert(QUEUED_MESSAGES_NS.to_string(), key, value)
+            .await
+        {
+            Ok(_) => persisted += 1,
+            Err(e) => error!("Failed to persist queued [{}] log: {e}", message.type_),
+        }
+    }
+
+    persisted
+}
+
+/// Requeue the messages persisted by [`persist_queued_messages`] with `requeue`, oldest first.
+/// A message is only removed from storage once `requeue` returns true, so if one cannot be
+/// requeued, it and the ones after it are kept for the next time Plaid starts. When instances
+/// share their storage and start at the same time, a message can be requeued by more than one
+/// of them. Returns how many messages were requeued.
+pub async fn requeue_persisted_messages<F, R>(
+    storage: &Storage,
+    mut requeue: F,
+) -> Result<usize, StorageError>
+where
+    F: FnMut(Message) -> R,
+    R: Future<Output = bool>,
+{
+    let mut entries = storage.fetch_all(QUEUED_MESSAGES_NS, None).await?;
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut requeued = 0;
+    for (key, value) in entries {
+        // Not every backend returns the values along with the keys
+        let value = match value {
+            Some(value) => Some(value),
+            None => storage.get(QUEUED_MESSAGES_NS, &key).await?,
+        };
+        match value.map(|value| serde_json::from_slice::<Message>(&value)) {
+            Some(Ok(message)) => {
+                if !requeue(message).await {
+                    break;
+                }
+                requeued += 1;
+            }
+            Some(Err(e)) => warn!("Persisted queued log [{key}] could not be deserialized [{e}]"),
+            // Another instance requeued the message since the entries were fetched
+            None => continue,
+        }
+        storage.delete(QUEUED_MESSAGES_NS, &key).await?;
+    }
+
+    Ok(requeued)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::thread_pools::ThreadPool;
+    use super::*;
+    use crate::testing::fixtures::message;
+
+    #[tokio::test]
+    async fn queued_messages_are_persisted_in_order() {
+        let storage = Storage::new_in_memory();
+        let pool = ThreadPool::new(1, 10, None);
+        for data in ["first", "skipped", "second"] {
+            pool.sender.send(message("test", data.as_bytes())).unwrap();
+        }
+
+        let persisted = persist_queued_messages(vec![pool.receiver], &storage, |message| {
+            message.data == b"skipped"
+        })
+        .await;
+        assert_eq!(persisted, 2);
+
+        let mut messages = vec![];
+        let requeued = requeue_persisted_messages(&storage, |message| {
+            messages.push(message);
+            async { true }
+        })
+        .await
+        .unwrap();
+        assert_eq!(requeued, 2);
+        let data: Vec<&[u8]> = messages.iter().map(|message| &message.data[..]).collect();
+        assert_eq!(data, vec![&b"first"[..], &b"second"[..]]);
+        // Requeued messages are removed
+        assert_eq!(
+            requeue_persisted_messages(&storage, |_| async { true })
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn messages_which_cannot_be_requeued_are_kept() {
+        let storage = Storage::new_in_memory();
+        let pool = ThreadPool::new(1, 10, None);
+        for data in ["first", "second", "third"] {
+            pool.sender.send(message("test", data.as_bytes())).unwrap();
+        }
+        persist_queued_messages(vec![pool.receiver], &storage, |_| false).await;
+
+        // The executor stops accepting messages after the first one
+        let mut accepted = 0;
+        let requeued = requeue_persisted_messages(&storage, |_| {
+            accepted += 1;
+            async move { accepted == 1 }
+        })
+        .await
+        .unwrap();
+        assert_eq!(requeued, 1);
+
+        let mut messages = vec![];
+        requeue_persisted_messages(&storage, |message| {
+            messages.push(message.data);
+            async { true }
+        })
+        .await
+        .unwrap();
+        assert_eq!(messages, vec![b"second".to_vec(), b"third".to_vec()]);
+    }
+}
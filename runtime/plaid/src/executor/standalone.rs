@@ -0,0 +1,79 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::Sender;
+use tokio_util::sync::CancellationToken;
+
+use super::{
+    prepare_for_execution, run_prepared_instance, ExecutorError, Message, ModuleExecutionError,
+    PoolSender,
+};
+use crate::apis::Api;
+use crate::cache::Cache;
+use crate::data::{DelayedMessage, ScheduledLogbacks};
+use crate::loader::{PlaidModule, PlaidModules};
+use crate::logging::Logger;
+use crate::storage::Storage;
+
+/// What happened when a module processed a message outside of the execution loop
+pub struct StandaloneExecution {
+    /// The response the module set, or why it failed
+    pub response: Result<Option<String>, ModuleExecutionError>,
+    /// How much computation the module used
+    pub computation_used: u64,
+    /// How long the module ran for
+    pub duration: Duration,
+}
+
+/// What a module run with [`execute_standalone`] has access to
+pub struct StandaloneContext {
+    pub api: Arc<Api>,
+    pub storage: Option<Arc<Storage>>,
+    pub cache: Option<Arc<Cache>>,
+    /// Where the module's logs and errors go
+    pub els: Logger,
+    /// Where logbacks without a delay are sent, if they can be sent anywhere
+    pub immediate_sender: Option<PoolSender>,
+    pub delayed_log_sender: Sender<DelayedMessage>,
+    pub scheduled_logbacks: Arc<ScheduledLogbacks>,
+    /// The modules the module can invoke
+    pub modules: Arc<PlaidModules>,
+}
+
+/// Run `module` on `message` once, outside of the execution loop, and return what happened.
+/// This is for tools that run modules on their own, like replaying recorded messages.
+///
+/// Unlike the execution loop, this does not apply the module's retry policy or execution
+/// limits, does not keep dead letters and does not update the module's persistent response.
+/// Logbacks are sent to the context's senders like they normally are.
+pub fn execute_standalone(
+    message: Message,
+    module: Arc<PlaidModule>,
+    context: StandaloneContext,
+) -> Result<StandaloneExecution, ExecutorError> {
+    let mut prepared = prepare_for_execution(
+        message,
+        module.clone(),
+        context.api,
+        context.storage,
+        context.cache,
+        context.els,
+        module.get_persistent_response_data(),
+        context.immediate_sender,
+        context.delayed_log_sender,
+        CancellationToken::new(),
+        None,
+        context.scheduled_logbacks,
+        context.modules,
+    )?;
+
+    let begin = Instant::now();
+    let (response, computation_used) =
+        run_prepared_instance(&mut prepared, module.computation_limit);
+
+    Ok(StandaloneExecution {
+        response,
+        computation_used,
+        duration: begin.elapsed(),
+    })
+}
@@ -1,24 +1,57 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
 
-use crossbeam_channel::{bounded, Receiver, Sender};
+use crossbeam_channel::{
+    bounded, unbounded, Receiver, RecvTimeoutError, SendError, Sender, TrySendError,
+};
 
 use crate::config::ExecutorConfig;
+use crate::InstanceRoles;
 
+use super::priority::{PriorityLanes, PriorityRouter};
 use super::Message;
 
 /// A pool of threads to process logs
 #[derive(Clone)]
 pub struct ThreadPool {
     pub num_threads: u8,
-    pub sender: Sender<Message>,
-    pub receiver: Receiver<Message>,
+    pub sender: PoolSender,
+    pub receiver: PoolReceiver,
 }
 
 impl ThreadPool {
     /// Create a new thread pool with the given number of threads, operating
-    /// on a channel with the given size limit.
-    pub fn new(num_threads: u8, queue_size: usize) -> Self {
-        let (sender, receiver) = bounded(queue_size);
+    /// on a queue with the given size limit. If `priorities` is set, the
+    /// pool's threads take the messages from the queue by priority.
+    pub fn new(
+        num_threads: u8,
+        queue_size: usize,
+        priorities: Option<Arc<PriorityRouter>>,
+    ) -> Self {
+        let (sender, receiver) = match priorities {
+            Some(router) => {
+                let queue = Arc::new(LaneQueue {
+                    lanes: Mutex::new(PriorityLanes::new(router)),
+                    available: Condvar::new(),
+                    room: Condvar::new(),
+                    bound: queue_size,
+                    senders: AtomicUsize::new(1),
+                });
+                (
+                    PoolSender(SenderKind::Lanes(LaneSender(queue.clone()))),
+                    PoolReceiver(ReceiverKind::Lanes(queue)),
+                )
+            }
+            None => {
+                let (sender, receiver) = bounded(queue_size);
+                (
+                    PoolSender(SenderKind::Channel(sender)),
+                    PoolReceiver(ReceiverKind::Channel(receiver)),
+                )
+            }
+        };
         ThreadPool {
             num_threads,
             sender,
@@ -27,6 +60,212 @@ impl ThreadPool {
     }
 }
 
+/// The queue of a pool with priority lanes. A message is queued once its lane has room for
+/// it and the lanes together hold fewer messages than the pool's queue size.
+struct LaneQueue {
+    lanes: Mutex<PriorityLanes>,
+    /// Notified every time a message is queued
+    available: Condvar,
+    /// Notified every time messages are taken. The senders waiting on it can be waiting for
+    /// different lanes, so they are all woken up.
+    room: Condvar,
+    /// How many messages can be queued in total
+    bound: usize,
+    /// How many senders are left. Once none is, the pool's threads stop when the queue is empty.
+    senders: AtomicUsize,
+}
+
+impl LaneQueue {
+    fn lock(&self) -> MutexGuard<'_, PriorityLanes> {
+        self.lanes.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Whether a message can be queued in `lanes`
+    fn has_room(&self, lanes: &PriorityLanes, message: &Message) -> bool {
+        lanes.queued() < self.bound && lanes.has_room(message)
+    }
+
+    /// Queue a message in `lanes`, which must have room for it
+    fn enqueue(&self, mut lanes: MutexGuard<'_, PriorityLanes>, message: Message) {
+        lanes.push(message);
+        drop(lanes);
+        self.available.notify_one();
+    }
+}
+
+/// Sends messages into a pool with priority lanes, and keeps the pool's threads running
+/// while it exists
+struct LaneSender(Arc<LaneQueue>);
+
+impl Clone for LaneSender {
+    fn clone(&self) -> Self {
+        self.0.senders.fetch_add(1, Ordering::SeqCst);
+        Self(self.0.clone())
+    }
+}
+
+impl Drop for LaneSender {
+    fn drop(&mut self) {
+        if self.0.senders.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // Wake up the threads waiting for messages, so they see there will be no more
+            let _lanes = self.0.lock();
+            self.0.available.notify_all();
+        }
+    }
+}
+
+/// The sending end of a thread pool's queue. If the pool has priority lanes, every message
+/// queued wakes up one of the pool's threads.
+#[derive(Clone)]
+pub struct PoolSender(SenderKind);
+
+#[derive(Clone)]
+enum SenderKind {
+    /// A pool without priority lanes, or a queue which is not a pool's
+    Channel(Sender<Message>),
+    /// A pool with priority lanes
+    Lanes(LaneSender),
+}
+
+impl PoolSender {
+    /// An unbounded queue, which is read directly rather than by a pool's threads
+    pub fn unbounded() -> (Self, Receiver<Message>) {
+        let (sender, receiver) = unbounded();
+        (Self(SenderKind::Channel(sender)), receiver)
+    }
+
+    /// Queue a message, waiting for room if the queue (or the message's priority lane) is full. Errors hand the
+    /// message back the same way crossbeam's do.
+    #[allow(clippy::result_large_err)]
+    pub fn send(&self, message: Message) -> Result<(), SendError<Message>> {
+        match &self.0 {
+            SenderKind::Channel(sender) => sender.send(message),
+            SenderKind::Lanes(LaneSender(queue)) => {
+                let mut lanes = queue.lock();
+                while !queue.has_room(&lanes, &message) {
+                    lanes = queue.room.wait(lanes).unwrap_or_else(|e| e.into_inner());
+                }
+                queue.enqueue(lanes, message);
+                Ok(())
+            }
+        }
+    }
+
+    /// Queue a message if there is room for it in the queue and in its priority lane
+    #[allow(clippy::result_large_err)]
+    pub fn try_send(&self, message: Message) -> Result<(), TrySendError<Message>> {
+        match &self.0 {
+            SenderKind::Channel(sender) => sender.try_send(message),
+            SenderKind::Lanes(LaneSender(queue)) => {
+                let lanes = queue.lock();
+                if !queue.has_room(&lanes, &message) {
+                    return Err(TrySendError::Full(message));
+                }
+                queue.enqueue(lanes, message);
+                Ok(())
+            }
+        }
+    }
+
+    /// How many messages can be queued, if the queue is bounded
+    pub fn capacity(&self) -> Option<usize> {
+        match &self.0 {
+            SenderKind::Channel(sender) => sender.capacity(),
+            SenderKind::Lanes(LaneSender(queue)) => Some(queue.bound),
+        }
+    }
+}
+
+/// The receiving end of a thread pool's queue. If the pool has priority lanes, messages are
+/// taken from the lanes according to their weights. This does not keep the queue open.
+#[derive(Clone)]
+pub struct PoolReceiver(ReceiverKind);
+
+#[derive(Clone)]
+enum ReceiverKind {
+    Channel(Receiver<Message>),
+    Lanes(Arc<LaneQueue>),
+}
+
+impl PoolReceiver {
+    /// Wait for the next message, for at most `timeout` if it is set. Fails once every sender
+    /// is gone and nothing is queued.
+    pub fn recv(&self, timeout: Option<Duration>) -> Result<Message, RecvTimeoutError> {
+        let queue = match &self.0 {
+            ReceiverKind::Channel(receiver) => {
+                return match timeout {
+                    Some(timeout) => receiver.recv_timeout(timeout),
+                    None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                }
+            }
+            ReceiverKind::Lanes(queue) => queue,
+        };
+
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut lanes = queue.lock();
+        loop {
+            if let Some(message) = lanes.pop() {
+                drop(lanes);
+                queue.room.notify_all();
+                return Ok(message);
+            }
+            if queue.senders.load(Ordering::SeqCst) == 0 {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            lanes = match deadline {
+                None => queue
+                    .available
+                    .wait(lanes)
+                    .unwrap_or_else(|e| e.into_inner()),
+                Some(deadline) => {
+                    let Some(left) = deadline.checked_duration_since(Instant::now()) else {
+                        return Err(RecvTimeoutError::Timeout);
+                    };
+                    queue
+                        .available
+                        .wait_timeout(lanes, left)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+            };
+        }
+    }
+
+    /// Take all the queued messages, without waiting
+    pub fn drain(&self) -> Vec<Message> {
+        match &self.0 {
+            ReceiverKind::Channel(receiver) => receiver.try_iter().collect(),
+            ReceiverKind::Lanes(queue) => {
+                let messages = queue.lock().drain();
+                queue.room.notify_all();
+                messages
+            }
+        }
+    }
+
+    /// How many messages are queued, and how many can be
+    pub fn depth_and_capacity(&self) -> (usize, usize) {
+        match &self.0 {
+            ReceiverKind::Channel(receiver) => (receiver.len(), receiver.capacity().unwrap_or(0)),
+            ReceiverKind::Lanes(queue) => (queue.lock().queued(), queue.bound),
+        }
+    }
+
+    /// How many messages are queued in each priority lane, by lane name. This is empty if the
+    /// pool does not have priority lanes.
+    pub fn lane_depths(&self) -> Vec<(String, usize)> {
+        match &self.0 {
+            ReceiverKind::Channel(_) => vec![],
+            ReceiverKind::Lanes(queue) => queue
+                .lock()
+                .depths()
+                .into_iter()
+                .map(|(lane, depth)| (lane.to_string(), depth))
+                .collect(),
+        }
+    }
+}
+
 /// A struct that keeps track of all Plaid's thread pools
 #[derive(Clone)]
 pub struct ExecutionThreadPools {
@@ -36,29 +275,319 @@ pub struct ExecutionThreadPools {
     /// Thread pools dedicated to specific log types.
     /// Mapping { log_type --> thread_pool }
     pub dedicated_pools: HashMap<String, ThreadPool>,
+    /// A single-threaded pool that executes all the log types marked as non-concurrent.
+    /// This is `None` if there are no such log types or if this instance does not have
+    /// the role to run them.
+    pub non_concurrent_pool: Option<ThreadPool>,
+    /// The log types which must never be processed concurrently
+    pub non_concurrent_log_types: Arc<HashSet<String>>,
 }
 
 impl ExecutionThreadPools {
     /// Create a new ExecutionThreadPools object by initializing only the thread
     /// pool for general processing. Other thread pools, if present, must be
     /// added separately by inserting into the `dedicated_pools` map.
-    pub fn new(executor_config: &ExecutorConfig) -> Self {
+    pub fn new(executor_config: &ExecutorConfig, roles: &InstanceRoles) -> Self {
         // If we are dedicating threads to specific log types, create their channels and add them to the map
+        let priorities = executor_config
+            .priorities
+            .as_ref()
+            .map(|config| Arc::new(PriorityRouter::new(config)));
+
         let dedicated_pools: HashMap<String, ThreadPool> = executor_config
             .dedicated_threads
             .iter()
             .map(|(logtype, config)| {
-                let tp = ThreadPool::new(config.num_threads, config.log_queue_size);
+                let tp = ThreadPool::new(
+                    config.num_threads,
+                    config.log_queue_size,
+                    priorities.clone(),
+                );
                 (logtype.clone(), tp)
             })
             .collect();
 
+        let non_concurrent_log_types: HashSet<String> = executor_config
+            .non_concurrent
+            .as_ref()
+            .map(|config| config.log_types.iter().cloned().collect())
+            .unwrap_or_default();
+
+        // Only create the non-concurrent pool if there is something to run on it and
+        // this instance is the one responsible for running it.
+        let non_concurrent_pool = match &executor_config.non_concurrent {
+            Some(config) if roles.non_concurrent_rules && !non_concurrent_log_types.is_empty() => {
+                // Non-concurrent messages are executed in the order they come in
+                Some(ThreadPool::new(1, config.log_queue_size, None))
+            }
+            _ => None,
+        };
+
         ExecutionThreadPools {
             general_pool: ThreadPool::new(
                 executor_config.execution_threads,
                 executor_config.log_queue_size,
+                priorities,
             ),
             dedicated_pools,
+            non_concurrent_pool,
+            non_concurrent_log_types: Arc::new(non_concurrent_log_types),
+        }
+    }
+
+    /// The receiving ends of all the pools' queues
+    pub fn receivers(&self) -> Vec<PoolReceiver> {
+        std::iter::once(&self.general_pool)
+            .chain(self.dedicated_pools.values())
+            .chain(&self.non_concurrent_pool)
+            .map(|tp| tp.receiver.clone())
+            .collect()
+    }
+
+    /// Get the routing that a thread in a regular (general or dedicated) pool must
+    /// apply to the non-concurrent messages it receives.
+    pub fn non_concurrent_routing(&self) -> NonConcurrentRouting {
+        let route = match &self.non_concurrent_pool {
+            Some(tp) => NonConcurrentRoute::Forward(tp.sender.clone()),
+            None => NonConcurrentRoute::Drop,
+        };
+
+        NonConcurrentRouting {
+            log_types: self.non_concurrent_log_types.clone(),
+            route,
+        }
+    }
+
+    /// Get the routing for the thread of the non-concurrent pool, which executes
+    /// every message it receives.
+    pub fn non_concurrent_pool_routing(&self) -> NonConcurrentRouting {
+        NonConcurrentRouting {
+            log_types: self.non_concurrent_log_types.clone(),
+            route: NonConcurrentRoute::Execute,
+        }
+    }
+}
+
+/// What an execution thread does with a message whose log type is non-concurrent
+#[derive(Clone)]
+enum NonConcurrentRoute {
+    /// This thread is the non-concurrent thread: execute the message here
+    Execute,
+    /// Hand the message over to the non-concurrent pool
+    Forward(PoolSender),
+    /// This instance does not run non-concurrent log types: drop the message
+    Drop,
+}
+
+/// Decides whether an execution thread is allowed to process a message, based on
+/// whether the message's log type is marked as non-concurrent.
+///
+/// Messages reach the general pool from many places (data generators, logbacks, etc.)
+/// so it is not enough to route them when they are first queued: each execution thread
+/// checks the messages it receives before running them.
+#[derive(Clone)]
+pub struct NonConcurrentRouting {
+    log_types: Arc<HashSet<String>>,
+    route: NonConcurrentRoute,
+}
+
+impl NonConcurrentRouting {
+    /// Returns the message if the calling thread should execute it. Otherwise the message
+    /// is either forwarded to the non-concurrent pool or dropped, and `None` is returned.
+    pub fn route(&self, message: Message) -> Option<Message> {
+        if !self.log_types.contains(&message.type_) {
+            return Some(message);
+        }
+
+        match &self.route {
+            NonConcurrentRoute::Execute => Some(message),
+            NonConcurrentRoute::Forward(sender) => {
+                match sender.try_send(message) {
+                    Ok(()) => {}
+                    Err(TrySendError::Full(message)) => {
+                        error!(
+                            "Non-concurrent queue full! [{}] log dropped!",
+                            message.type_
+                        );
+                    }
+                    Err(TrySendError::Disconnected(message)) => {
+                        error!(
+                            "Non-concurrent execution thread is gone! [{}] log dropped!",
+                            message.type_
+                        );
+                    }
+                }
+                None
+            }
+            NonConcurrentRoute::Drop => {
+                warn!(
+                    "Dropping log of non-concurrent type [{}]: this instance does not run non-concurrent log types",
+                    message.type_
+                );
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::message;
+
+    fn pools(non_concurrent_rules: bool) -> ExecutionThreadPools {
+        let config: ExecutorConfig = toml::from_str(
+            r#"
+            execution_threads = 2
+            [non_concurrent]
+            log_types = ["serial"]
+            "#,
+        )
+        .unwrap();
+        let roles = InstanceRoles {
+            non_concurrent_rules,
+            ..InstanceRoles::default()
+        };
+        ExecutionThreadPools::new(&config, &roles)
+    }
+
+    #[test]
+    fn non_concurrent_messages_are_forwarded_to_their_pool() {
+        let pools = pools(true);
+        let non_concurrent_pool = pools.non_concurrent_pool.as_ref().unwrap();
+        assert_eq!(non_concurrent_pool.num_threads, 1);
+
+        // Regular threads run the other log types, and hand the non-concurrent ones over
+        let routing = pools.non_concurrent_routing();
+        assert!(routing.route(message("regular", b"")).is_some());
+        assert!(routing.route(message("serial", b"")).is_none());
+        let forwarded = non_concurrent_pool.receiver.recv(None).unwrap();
+        assert_eq!(forwarded.type_, "serial");
+
+        // The non-concurrent thread runs them
+        let routing = pools.non_concurrent_pool_routing();
+        assert!(routing.route(message("serial", b"")).is_some());
+    }
+
+    #[test]
+    fn non_concurrent_messages_are_dropped_without_the_role() {
+        let pools = pools(false);
+        assert!(pools.non_concurrent_pool.is_none());
+
+        let routing = pools.non_concurrent_routing();
+        assert!(routing.route(message("regular", b"")).is_some());
+        assert!(routing.route(message("serial", b"")).is_none());
+        assert_eq!(pools.receivers().len(), 1);
+    }
+
+    fn prioritized_pools() -> ExecutionThreadPools {
+        let config: ExecutorConfig = toml::from_str(
+            r#"
+            execution_threads = 4
+            log_queue_size = 64
+            [priorities]
+            default_lane = "low"
+            [priorities.lanes.high]
+            weight = 3
+            queue_size = 48
+            [priorities.lanes.low]
+            weight = 1
+            queue_size = 48
+            [priorities.log_types]
+            urgent = "high"
+            "#,
+        )
+        .unwrap();
+        ExecutionThreadPools::new(&config, &InstanceRoles::default())
+    }
+
+    #[test]
+    fn messages_in_the_lanes_count_against_the_queue_size() {
+        let pools = prioritized_pools();
+        let pool = &pools.general_pool;
+        for i in 0..64 {
+            let log_type = if i % 2 == 0 { "urgent" } else { "regular" };
+            pool.sender.try_send(message(log_type, b"")).unwrap();
+        }
+        assert!(matches!(
+            pool.sender.try_send(message("urgent", b"")),
+            Err(TrySendError::Full(_))
+        ));
+        assert_eq!(pool.receiver.depth_and_capacity(), (64, 64));
+        assert_eq!(
+            pool.receiver.lane_depths(),
+            vec![("high".to_string(), 32), ("low".to_string(), 32)]
+        );
+
+        // Taking a message makes room for another one
+        pool.receiver.recv(None).unwrap();
+        pool.sender.try_send(message("urgent", b"")).unwrap();
+    }
+
+    #[test]
+    fn a_full_lane_does_not_hold_up_the_others() {
+        let pools = prioritized_pools();
+        let pool = &pools.general_pool;
+        for _ in 0..48 {
+            pool.sender.try_send(message("regular", b"")).unwrap();
+        }
+        assert!(matches!(
+            pool.sender.try_send(message("regular", b"")),
+            Err(TrySendError::Full(_))
+        ));
+
+        // High priority messages are still queued, and taken first
+        pool.sender.try_send(message("urgent", b"")).unwrap();
+        assert_eq!(pool.receiver.recv(None).unwrap().type_, "urgent");
+
+        // A sender waiting for room in the low priority lane gets it once a message is taken
+        let sender = pool.sender.clone();
+        let waiting = std::thread::spawn(move || sender.send(message("regular", b"")).is_ok());
+        pool.receiver.recv(None).unwrap();
+        assert!(waiting.join().unwrap());
+        assert_eq!(
+            pool.receiver.lane_depths(),
+            vec![("high".to_string(), 0), ("low".to_string(), 48)]
+        );
+    }
+
+    #[test]
+    fn receivers_drain_a_backlog_of_every_priority_together() {
+        let pools = prioritized_pools();
+        let sender = pools.general_pool.sender.clone();
+
+        // The receivers are all waiting before anything is queued
+        let (received, results) = unbounded();
+        let receivers: Vec<_> = (0..4)
+            .map(|_| {
+                let receiver = pools.general_pool.receiver.clone();
+                let received = received.clone();
+                std::thread::spawn(move || {
+                    while let Ok(message) = receiver.recv(None) {
+                        received.send(message.id).unwrap();
+                    }
+                })
+            })
+            .collect();
+        drop(received);
+
+        let mut ids = HashSet::new();
+        for i in 0..64 {
+            let message = message(if i % 2 == 0 { "urgent" } else { "regular" }, b"");
+            ids.insert(message.id.clone());
+            sender.send(message).unwrap();
+        }
+
+        // Once the senders are gone, the receivers stop when everything is processed
+        drop(sender);
+        drop(pools);
+        let mut processed = HashSet::new();
+        while let Ok(id) = results.recv_timeout(Duration::from_secs(10)) {
+            processed.insert(id);
+        }
+        for receiver in receivers {
+            receiver.join().unwrap();
         }
+        assert_eq!(processed, ids);
     }
 }
@@ -0,0 +1,352 @@
+use std::num::NonZeroU32;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+/// Limits on how much a module can run, regardless of how many messages it receives
+#[derive(Deserialize, Clone)]
+pub struct ExecutionLimits {
+    /// The most executions of the module that can happen at the same time
+    pub max_concurrent: Option<NonZeroU32>,
+    /// How many executions of the module can be started in a period of time
+    pub rate_limit: Option<RateLimit>,
+    /// What happens to a message when the module is at one of its limits. Defaults to `Delay`.
+    #[serde(default)]
+    pub on_limit: LimitBehavior,
+}
+
+/// A token bucket rate limit. Up to `executions` executions can happen in a burst, and the
+/// bucket is refilled at a rate of `executions` every `period_secs` seconds.
+#[derive(Deserialize, Clone)]
+pub struct RateLimit {
+    pub executions: NonZeroU32,
+    pub period_secs: u64,
+}
+
+/// What happens to a message which a module cannot run yet because of its limits
+#[derive(Deserialize, Clone, Copy, Default, PartialEq)]
+pub enum LimitBehavior {
+    /// Wait until the module can run. This holds up the execution thread.
+    Queue,
+    /// Do not run the module on the message
+    Drop,
+    /// Run the module on the message later, through the delayed logback system
+    #[default]
+    Delay,
+}
+
+/// Which of its limits a module hit
+#[derive(Clone, Copy)]
+pub enum ThrottleReason {
+    Concurrency,
+    Rate,
+}
+
+impl ThrottleReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Concurrency => "concurrency",
+            Self::Rate => "rate",
+        }
+    }
+}
+
+/// A module could not run on a message because of its limits and the message must not wait
+pub struct Throttled {
+    pub reason: ThrottleReason,
+    /// How long until the module can probably run again
+    pub retry_after: Duration,
+}
+
+struct TokenBucket {
+    tokens: f64,
+    capacity: f64,
+    /// Tokens added per second
+    refill_rate: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate_limit: &RateLimit) -> Self {
+        let capacity = rate_limit.executions.get() as f64;
+        Self {
+            tokens: capacity,
+            capacity,
+            refill_rate: capacity / rate_limit.period_secs.max(1) as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Take a token if there is one. Otherwise, returns how long until there is.
+    fn try_take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.refill_rate,
+            ))
+        }
+    }
+}
+
+/// A token bucket that can be shared between threads, which keeps count of what it turns away
+pub struct RateLimiter {
+    /// The bucket and how many requests were rejected since the last one that went through
+    state: Mutex<(TokenBucket, u64)>,
+}
+
+impl RateLimiter {
+    pub fn new(rate_limit: &RateLimit) -> Self {
+        Self {
+            state: Mutex::new((TokenBucket::new(rate_limit), 0)),
+        }
+    }
+
+    /// Take a token if there is one. On success, returns how many requests were
+    /// rejected since the previous one that got a token.
+    pub fn try_acquire(&self) -> Option<u64> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let (bucket, rejected) = &mut *state;
+        match bucket.try_take(Instant::now()) {
+            Ok(()) => Some(std::mem::take(rejected)),
+            Err(_) => {
+                *rejected += 1;
+                None
+            }
+        }
+    }
+
+    /// Take a token if there is one. Otherwise, returns how long until there is.
+    pub fn try_take(&self) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.0.try_take(Instant::now())
+    }
+}
+
+/// Enforces a module's execution limits across all the execution threads
+pub struct ModuleThrottle {
+    limits: ExecutionLimits,
+    running: Mutex<u32>,
+    slot_freed: Condvar,
+    bucket: Option<Mutex<TokenBucket>>,
+}
+
+/// Allows an execution of a module to go ahead. The module's concurrency slot
+/// is given back when this is dropped.
+pub struct ExecutionPermit {
+    throttle: Option<Arc<ModuleThrottle>>,
+    /// If the execution had to wait for a limit before it could go ahead
+    pub waited_for: Option<ThrottleReason>,
+}
+
+impl ExecutionPermit {
+    /// A permit for a module which has no limits
+    pub fn unlimited() -> Self {
+        Self {
+            throttle: None,
+            waited_for: None,
+        }
+    }
+}
+
+impl Drop for ExecutionPermit {
+    fn drop(&mut self) {
+        if let Some(throttle) = &self.throttle {
+            if throttle.limits.max_concurrent.is_some() {
+                let mut running = throttle.running.lock().unwrap_or_else(|e| e.into_inner());
+                *running = running.saturating_sub(1);
+                throttle.slot_freed.notify_one();
+            }
+        }
+    }
+}
+
+impl ModuleThrottle {
+    pub fn new(limits: ExecutionLimits) -> Self {
+        let bucket = limits
+            .rate_limit
+            .as_ref()
+            .map(|rate_limit| Mutex::new(TokenBucket::new(rate_limit)));
+        Self {
+            limits,
+            running: Mutex::new(0),
+            slot_freed: Condvar::new(),
+            bucket,
+        }
+    }
+
+    /// The configured behavior for messages which cannot run yet
+    pub fn on_limit(&self) -> LimitBehavior {
+        self.limits.on_limit
+    }
+
+    /// Get permission to run the module once. If `wait` is true, this blocks until the module
+    /// is within its limits. Otherwise it fails straight away if the module is at one of them.
+    pub fn acquire(self: &Arc<Self>, wait: bool) -> Result<ExecutionPermit, Throttled> {
+        let mut waited_for = None;
+        loop {
+            let mut permit = ExecutionPermit {
+                throttle: None,
+                waited_for,
+            };
+
+            if let Some(max_concurrent) = self.limits.max_concurrent {
+                let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+                while *running >= max_concurrent.get() {
+                    if !wait {
+                        return Err(Throttled {
+                            reason: ThrottleReason::Concurrency,
+                            retry_after: Duration::from_secs(1),
+                        });
+                    }
+                    permit.waited_for = Some(ThrottleReason::Concurrency);
+                    running = self
+                        .slot_freed
+                        .wait(running)
+                        .unwrap_or_else(|e| e.into_inner());
+                }
+                *running += 1;
+            }
+            // From here on, dropping the permit gives the slot back
+            permit.throttle = Some(self.clone());
+
+            let Some(bucket) = &self.bucket else {
+                return Ok(permit);
+            };
+            let next_token = bucket
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .try_take(Instant::now());
+            match next_token {
+                Ok(()) => return Ok(permit),
+                Err(retry_after) if !wait => {
+                    return Err(Throttled {
+                        reason: ThrottleReason::Rate,
+                        retry_after,
+                    })
+                }
+                Err(retry_after) => {
+                    // The slot is given back while waiting for a token, so executions which
+                    // already have one are not held up
+                    waited_for = Some(ThrottleReason::Rate);
+                    drop(permit);
+                    std::thread::sleep(retry_after.min(Duration::from_secs(1)));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(max_concurrent: Option<u32>, rate_limit: Option<RateLimit>) -> ExecutionLimits {
+        ExecutionLimits {
+            max_concurrent: max_concurrent.and_then(NonZeroU32::new),
+            rate_limit,
+            on_limit: LimitBehavior::Drop,
+        }
+    }
+
+    #[test]
+    fn concurrency_slots_are_given_back() {
+        let throttle = Arc::new(ModuleThrottle::new(limits(Some(2), None)));
+
+        let first = throttle.acquire(false).ok().unwrap();
+        let _second = throttle.acquire(false).ok().unwrap();
+        assert!(throttle.acquire(false).is_err());
+
+        drop(first);
+        assert!(throttle.acquire(false).is_ok());
+    }
+
+    #[test]
+    fn token_bucket_allows_bursts_then_refills() {
+        let mut bucket = TokenBucket::new(&RateLimit {
+            executions: NonZeroU32::new(2).unwrap(),
+            period_secs: 10,
+        });
+        let start = bucket.last_refill;
+
+        assert!(bucket.try_take(start).is_ok());
+        assert!(bucket.try_take(start).is_ok());
+        let wait = bucket.try_take(start).unwrap_err();
+        assert_eq!(wait.as_secs(), 5);
+
+        assert!(bucket.try_take(start + Duration::from_secs(5)).is_ok());
+        assert!(bucket.try_take(start + Duration::from_secs(5)).is_err());
+    }
+
+    #[test]
+    fn rate_limiter_counts_rejections() {
+        let limiter = RateLimiter::new(&RateLimit {
+            executions: NonZeroU32::new(1).unwrap(),
+            period_secs: 3600,
+        });
+
+        assert_eq!(limiter.try_acquire(), Some(0));
+        assert_eq!(limiter.try_acquire(), None);
+        assert_eq!(limiter.try_acquire(), None);
+
+        limiter.state.lock().unwrap().0.tokens = 1.0;
+        assert_eq!(limiter.try_acquire(), Some(2));
+    }
+
+    #[test]
+    fn rate_limited_permit_gives_back_its_slot() {
+        let throttle = Arc::new(ModuleThrottle::new(limits(
+            Some(1),
+            Some(RateLimit {
+                executions: NonZeroU32::new(1).unwrap(),
+                period_secs: 60,
+            }),
+        )));
+
+        drop(throttle.acquire(false).ok().unwrap());
+        // Out of tokens, but the failed attempt must not keep the concurrency slot
+        assert!(matches!(
+            throttle.acquire(false),
+            Err(Throttled {
+                reason: ThrottleReason::Rate,
+                ..
+            })
+        ));
+        assert_eq!(*throttle.running.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn waiting_for_a_token_does_not_hold_a_slot() {
+        let throttle = Arc::new(ModuleThrottle::new(limits(
+            Some(1),
+            Some(RateLimit {
+                executions: NonZeroU32::new(1).unwrap(),
+                period_secs: 2,
+            }),
+        )));
+        drop(throttle.acquire(false).ok().unwrap());
+
+        let waiter = std::thread::spawn({
+            let throttle = throttle.clone();
+            move || throttle.acquire(true).ok().unwrap().waited_for
+        });
+        std::thread::sleep(Duration::from_millis(200));
+        assert_eq!(*throttle.running.lock().unwrap(), 0);
+
+        assert!(matches!(waiter.join().unwrap(), Some(ThrottleReason::Rate)));
+    }
+
+    #[test]
+    fn messages_are_delayed_by_default() {
+        let limits: ExecutionLimits = toml::from_str("max_concurrent = 1").unwrap();
+        assert!(limits.on_limit == LimitBehavior::Delay);
+    }
+}
@@ -0,0 +1,237 @@
+use std::cell::OnceCell;
+
+use regex::Regex;
+use serde::{de, Deserialize};
+use serde_json::Value;
+
+use crate::executor::Message;
+
+/// A declarative filter that decides whether a message is of interest, without having to
+/// run a module. A message passes the filter if it matches all of its rules.
+///
+/// In the configuration, a filter is a list of rules, each of which reads a value from the
+/// message and checks it against a condition. For example:
+/// ```toml
+/// filter = [
+///     { json_pointer = "/action", in = ["repo.create", "repo.destroy"] },
+///     { header = "x-github-event", equals = "audit_log" },
+/// ]
+/// ```
+#[derive(Deserialize, Clone, Debug)]
+#[serde(transparent)]
+pub struct MessageFilter {
+    rules: Vec<FilterRule>,
+}
+
+/// A single check performed by a `MessageFilter`
+#[derive(Deserialize, Clone, Debug)]
+pub struct FilterRule {
+    /// Where the value to check is read from
+    #[serde(flatten)]
+    target: FilterTarget,
+    /// What the value must satisfy
+    #[serde(flatten)]
+    condition: FilterCondition,
+}
+
+/// Where a filter rule reads its value from
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterTarget {
+    /// A JSON pointer (RFC 6901) into the message's body, e.g. `/actor/login`.
+    /// Messages whose body is not JSON never match.
+    JsonPointer(String),
+    /// A header of the message. Only headers which are forwarded to modules can be checked.
+    Header(String),
+}
+
+/// The condition that the value read by a filter rule must satisfy.
+/// If there is no value (e.g. the header is absent), the condition is never satisfied.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterCondition {
+    /// The value is equal to this one
+    Equals(Value),
+    /// The value is equal to one of these
+    In(Vec<Value>),
+    /// The value matches this regex. Values which are not strings are matched
+    /// against their JSON representation.
+    Regex(#[serde(deserialize_with = "deserialize_regex")] Regex),
+}
+
+fn deserialize_regex<'de, D>(deserializer: D) -> Result<Regex, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let regex = String::deserialize(deserializer)?;
+    Regex::new(&regex).map_err(|e| de::Error::custom(format!("Invalid filter regex: {e}")))
+}
+
+/// A message that is being checked against filters. The message's body is only parsed
+/// the first time a rule needs it, and then shared by all the filters the message goes through.
+pub struct FilterInput<'a> {
+    message: &'a Message,
+    body: OnceCell<Option<Value>>,
+}
+
+impl<'a> FilterInput<'a> {
+    pub fn new(message: &'a Message) -> Self {
+        Self {
+            message,
+            body: OnceCell::new(),
+        }
+    }
+
+    fn body(&self) -> Option<&Value> {
+        self.body
+            .get_or_init(|| serde_json::from_slice(&self.message.data).ok())
+            .as_ref()
+    }
+
+    fn header(&self, name: &str) -> Option<Value> {
+        self.message
+            .headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| String::from_utf8(value.clone()).ok())
+            .map(Value::String)
+    }
+}
+
+impl MessageFilter {
+    /// Check whether a message passes this filter
+    pub fn matches(&self, input: &FilterInput) -> bool {
+        self.rules.iter().all(|rule| rule.matches(input))
+    }
+
+    /// Check a message against a filter which is only evaluated once,
+    /// e.g. the filter of the webhook or generator that produced it
+    pub fn matches_message(&self, message: &Message) -> bool {
+        self.matches(&FilterInput::new(message))
+    }
+}
+
+impl FilterRule {
+    fn matches(&self, input: &FilterInput) -> bool {
+        match &self.target {
+            FilterTarget::JsonPointer(pointer) => input
+                .body()
+                .and_then(|body| body.pointer(pointer))
+                .is_some_and(|value| self.condition.matches(value)),
+            FilterTarget::Header(name) => input
+                .header(name)
+                .is_some_and(|value| self.condition.matches(&value)),
+        }
+    }
+}
+
+impl FilterCondition {
+    fn matches(&self, value: &Value) -> bool {
+        match self {
+            Self::Equals(expected) => value == expected,
+            Self::In(expected) => expected.contains(value),
+            Self::Regex(regex) => match value {
+                Value::String(s) => regex.is_match(s),
+                other => regex.is_match(&other.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::testing::fixtures::webhook_message;
+
+    #[derive(Deserialize)]
+    struct TestConfig {
+        filter: MessageFilter,
+    }
+
+    fn filter(toml: &str) -> MessageFilter {
+        toml::from_str::<TestConfig>(toml).unwrap().filter
+    }
+
+    fn message(body: &str, headers: &[(&str, &str)]) -> Message {
+        let mut message = webhook_message("test", "test", body.as_bytes());
+        message.headers = headers
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.as_bytes().to_vec()))
+            .collect::<HashMap<_, _>>();
+        message
+    }
+
+    #[test]
+    fn json_pointer_conditions() {
+        let message = message(r#"{"action": "repo.create", "actor": {"id": 42}}"#, &[]);
+
+        assert!(
+            filter(r#"filter = [{ json_pointer = "/action", equals = "repo.create" }]"#)
+                .matches_message(&message)
+        );
+        assert!(
+            filter(r#"filter = [{ json_pointer = "/actor/id", in = [1, 42] }]"#)
+                .matches_message(&message)
+        );
+        assert!(
+            filter(r#"filter = [{ json_pointer = "/action", regex = "^repo\\." }]"#)
+                .matches_message(&message)
+        );
+        assert!(
+            !filter(r#"filter = [{ json_pointer = "/action", equals = "repo.destroy" }]"#)
+                .matches_message(&message)
+        );
+        assert!(
+            !filter(r#"filter = [{ json_pointer = "/missing", regex = ".*" }]"#)
+                .matches_message(&message)
+        );
+    }
+
+    #[test]
+    fn header_conditions() {
+        let message = message("not json", &[("X-GitHub-Event", "push")]);
+
+        assert!(
+            filter(r#"filter = [{ header = "x-github-event", equals = "push" }]"#)
+                .matches_message(&message)
+        );
+        assert!(
+            !filter(r#"filter = [{ header = "x-github-event", in = ["issues"] }]"#)
+                .matches_message(&message)
+        );
+        assert!(
+            !filter(r#"filter = [{ json_pointer = "/action", regex = ".*" }]"#)
+                .matches_message(&message)
+        );
+    }
+
+    #[test]
+    fn all_rules_must_match() {
+        let message = message(r#"{"action": "repo.create"}"#, &[("x-env", "prod")]);
+
+        assert!(filter(
+            r#"filter = [
+                { json_pointer = "/action", equals = "repo.create" },
+                { header = "x-env", equals = "prod" },
+            ]"#
+        )
+        .matches_message(&message));
+        assert!(!filter(
+            r#"filter = [
+                { json_pointer = "/action", equals = "repo.create" },
+                { header = "x-env", equals = "staging" },
+            ]"#
+        )
+        .matches_message(&message));
+    }
+
+    #[test]
+    fn invalid_regex_is_rejected() {
+        assert!(toml::from_str::<TestConfig>(
+            r#"filter = [{ json_pointer = "/action", regex = "(" }]"#
+        )
+        .is_err());
+    }
+}
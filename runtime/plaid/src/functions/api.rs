@@ -1,12 +1,47 @@
 use super::{safely_write_data_back, FunctionErrors};
+use crate::apis::canned::CannedResponse;
 use crate::apis::ApiError;
-use crate::executor::Env;
-use crate::functions::{get_memory, safely_get_string};
-use wasmer::{AsStoreRef, Function, FunctionEnv, FunctionEnvMut, Store, WasmPtr};
+use crate::executor::{Env, SideEffect};
+use crate::functions::{
+    enforce_execution_deadline, get_memory, safely_get_string,
+    test_mode::{blocked_call_code, blocked_call_data},
+    within_execution_deadline, HostCallSpan,
+};
+use std::time::Instant;
+use wasmer::{AsStoreRef, Function, FunctionEnv, FunctionEnvMut, RuntimeError, Store, WasmPtr};
 
 const ALLOW_IN_TEST_MODE: bool = true;
 const DISALLOW_IN_TEST_MODE: bool = false;
 
+/// Keep the parameters of an API call if the message being processed is recorded, so that
+/// the API's response can be recorded along with them
+fn params_to_record(env: &Env, params: &str) -> Option<String> {
+    let recorder = env.api.recorder.as_ref()?;
+    recorder
+        .records(&env.message.type_)
+        .then(|| params.to_string())
+}
+
+/// Record the response an API gave, so it can be served again when the message being
+/// processed is replayed. Calls the API refused because the module is in test mode are not.
+fn record_api_response<T: ToString>(
+    env: &Env,
+    function: &str,
+    params: Option<String>,
+    result: &Result<T, ApiError>,
+) {
+    let (Some(recorder), Some(params)) = (&env.api.recorder, params) else {
+        return;
+    };
+    let response = match result {
+        Ok(data) => CannedResponse::Success(data.to_string()),
+        Err(ApiError::TestMode) => return,
+        Err(ApiError::RateLimited(_)) => CannedResponse::Error(FunctionErrors::RateLimited),
+        Err(_) => CannedResponse::Error(FunctionErrors::InternalApiError),
+    };
+    recorder.record_api_call(&env.message, &env.module.name, function, params, response);
+}
+
 /// Macro to implement a new host function in a given API. The function does not fill a data buffer with returned values.
 ///
 /// This macro generates two functions:
@@ -25,6 +60,10 @@ const DISALLOW_IN_TEST_MODE: bool = false;
 /// converted to int error codes by the wrapper function. These errors include:
 /// - `FunctionErrors::InternalApiError`: For internal API-related errors.
 /// - `FunctionErrors::ApiNotConfigured`: If the API is not configured.
+/// - `FunctionErrors::CircuitOpen`: If the API has been failing and its circuit breaker is open.
+/// - `FunctionErrors::RateLimited`: If the call would have had to wait too long for its rate limit.
+/// - `FunctionErrors::TimeoutElapsed`: If the module's execution timeout is reached during the call.
+///   The wrapper function then traps, which stops the module.
 macro_rules! impl_new_function {
     ($api:ident, $function_name:ident, $allow_in_test_mode:expr) => {
         paste::item! {
@@ -37,12 +76,6 @@ macro_rules! impl_new_function {
                     return Err(FunctionErrors::InternalApiError);
                 }
 
-
-                // Disallow this function call from continuing if the module is in test mode
-                if !$allow_in_test_mode && env_data.module.test_mode {
-                    return Err(FunctionErrors::TestMode);
-                }
-
                 let memory_view = match get_memory(&env, &store) {
                     Ok(memory_view) => memory_view,
                     Err(e) => {
@@ -51,25 +84,53 @@ macro_rules! impl_new_function {
                     },
                 };
 
+                // Disallow this function call from continuing if the module is in test mode, whatever its
+                // parameters. The call is journaled, and returns a synthetic response instead if one is configured.
+                if !$allow_in_test_mode && env_data.module.test_mode {
+                    let params = safely_get_string(&memory_view, params_buffer, params_buffer_len).unwrap_or_default();
+                    return blocked_call_code(env_data, stringify!([< $api _ $function_name >]), &params);
+                }
+
                 let params = safely_get_string(&memory_view, params_buffer, params_buffer_len)?;
 
+                // When running under the test runtime, answer with the canned response instead of calling the API
+                if let Some(canned) = env_data.api.canned_response(stringify!([< $api _ $function_name >]), &params) {
+                    return canned.code();
+                }
+
                 // Check that the request API system is even configured.
                 // This is something like Okta, Slack, or GitHub
                 let api = env_data.api.$api.as_ref().ok_or(FunctionErrors::ApiNotConfigured)?;
 
+                // Fail fast while this API keeps failing, rather than waiting on it
+                let breaker_call = env_data.api.circuit_breaker_call(stringify!($api), stringify!([< $api _ $function_name >]), &params)?;
+
                 // Clone the APIs Arc to use in Tokio closure
                 let env_api = env_data.api.clone();
                 let module = env_data.module.clone();
-                // Run the function on the Tokio runtime and wait for the result
-                let result = env_api.runtime.block_on(async move {
+                // Kept to journal the call if the API refuses it because the module is in test mode
+                let journal_params = env_data.module.test_mode.then(|| params.clone());
+                // Kept to record the response if the message being processed is recorded
+                let recorded_params = params_to_record(env_data, &params);
+                // Run the function on the Tokio runtime and wait for the result, for as long as the
+                // module is still within its execution timeout
+                let deadline = env_data.execution_deadline;
+                let result = env_api.runtime.block_on(within_execution_deadline(deadline, async move {
                     api.$function_name(&params, module).await
-                });
+                })).ok_or(FunctionErrors::TimeoutElapsed)?;
+                if let Some(breaker_call) = breaker_call {
+                    breaker_call.finish(result.as_ref().err().is_none_or(|e| !e.is_integration_failure()));
+                }
+                record_api_response(env_data, stringify!([< $api _ $function_name >]), recorded_params, &result);
 
                 let return_data = match result {
                     Ok(return_data) => return_data,
                     Err(ApiError::TestMode) => {
-                        return Err(FunctionErrors::TestMode);
+                        let params = journal_params.unwrap_or_default();
+                        return blocked_call_code(env_data, stringify!([< $api _ $function_name >]), &params);
                     }
+                    // The limiter already logged which limit was hit
+                    Err(ApiError::RateLimited(_)) => return Err(FunctionErrors::RateLimited),
                     Err(e) => {
                         error!("{} experienced an issue calling {}: {:?}", env_data.module.name, stringify!([< $api _ $function_name >]), e);
                         return Err(FunctionErrors::InternalApiError);
@@ -80,15 +141,32 @@ macro_rules! impl_new_function {
                 return Ok(return_data as i32);
             }
 
-            fn [< $api _ $function_name >] (env: FunctionEnvMut<Env>, params_buffer: WasmPtr<u8>, params_buffer_len: u32) -> i32 {
+            fn [< $api _ $function_name >] (mut env: FunctionEnvMut<Env>, params_buffer: WasmPtr<u8>, params_buffer_len: u32) -> Result<i32, RuntimeError> {
                 let name = env.data().module.name.clone();
-                match [< $api _ $function_name _impl>](env, params_buffer, params_buffer_len) {
+                let deadline = env.data().execution_deadline;
+                // Record the call if the module's side effects are compared with its shadow's
+                if let Some(side_effects) = &mut env.data_mut().side_effects {
+                    side_effects.push(SideEffect::HostCall(stringify!([< $api _ $function_name >]).to_string()));
+                }
+                let metrics = env.data().metrics.clone();
+                let span = HostCallSpan::start(env.data(), stringify!([< $api _ $function_name >]));
+                let begin = Instant::now();
+                let result = [< $api _ $function_name _impl>](env, params_buffer, params_buffer_len);
+                if let Some(metrics) = metrics {
+                    metrics.record_host_call(&name, stringify!([< $api _ $function_name >]), begin.elapsed(), result.as_ref().err());
+                }
+                if let Some(span) = span {
+                    span.finish(&result);
+                }
+                let result = match result {
                     Ok(res) => res,
                     Err(e) => {
                         error!("{} experienced an issue calling {}: {:?}", name, stringify!([< $api _ $function_name >]), e);
                         e as i32
                     }
-                }
+                };
+                enforce_execution_deadline(deadline)?;
+                Ok(result)
             }
         }
     }
@@ -112,6 +190,10 @@ macro_rules! impl_new_function {
 /// converted to int error codes by the wrapper function. These errors include:
 /// - `FunctionErrors::InternalApiError`: For internal API-related errors.
 /// - `FunctionErrors::ApiNotConfigured`: If the API is not configured.
+/// - `FunctionErrors::CircuitOpen`: If the API has been failing and its circuit breaker is open.
+/// - `FunctionErrors::RateLimited`: If the call would have had to wait too long for its rate limit.
+/// - `FunctionErrors::TimeoutElapsed`: If the module's execution timeout is reached during the call.
+///   The wrapper function then traps, which stops the module.
 /// - `FunctionErrors::ReturnBufferTooSmall`: If the provided return buffer is too small to hold the result.
 macro_rules! impl_new_function_with_error_buffer {
     ($api:ident, $function_name:ident, $allow_in_test_mode:expr) => {
@@ -125,11 +207,6 @@ macro_rules! impl_new_function_with_error_buffer {
                     return Err(FunctionErrors::InternalApiError);
                 }
 
-                // Disallow this function call from continuing if the module is in test mode
-                if !$allow_in_test_mode && env_data.module.test_mode {
-                    return Err(FunctionErrors::TestMode);
-                }
-
                 let memory_view = match get_memory(&env, &store) {
                     Ok(memory_view) => memory_view,
                     Err(e) => {
@@ -138,24 +215,54 @@ macro_rules! impl_new_function_with_error_buffer {
                     },
                 };
 
+                // Disallow this function call from continuing if the module is in test mode, whatever its
+                // parameters. The call is journaled, and returns a synthetic response instead if one is configured.
+                if !$allow_in_test_mode && env_data.module.test_mode {
+                    let params = safely_get_string(&memory_view, params_buffer, params_buffer_len).unwrap_or_default();
+                    return blocked_call_data(env_data, stringify!([< $api _ $function_name >]), &params)
+                        .and_then(|response| safely_write_data_back(&memory_view, response.as_bytes(), ret_buffer, ret_buffer_len));
+                }
+
                 let params = safely_get_string(&memory_view, params_buffer, params_buffer_len)?;
 
+                // When running under the test runtime, answer with the canned response instead of calling the API
+                if let Some(canned) = env_data.api.canned_response(stringify!([< $api _ $function_name >]), &params) {
+                    return safely_write_data_back(&memory_view, canned.data()?.as_bytes(), ret_buffer, ret_buffer_len);
+                }
+
                 // Check the requested API system is configured.
                 let api = env_data.api.$api.as_ref().ok_or(FunctionErrors::ApiNotConfigured)?;
 
+                // Fail fast while this API keeps failing, rather than waiting on it
+                let breaker_call = env_data.api.circuit_breaker_call(stringify!($api), stringify!([< $api _ $function_name >]), &params)?;
+
                 // Clone the APIs Arc to use in Tokio closure
                 let env_api = env_data.api.clone();
                 let module = env_data.module.clone();
-                // Run the function on the Tokio runtime and wait for the result
-                let result = env_api.runtime.block_on(async move {
+                // Kept to journal the call if the API refuses it because the module is in test mode
+                let journal_params = env_data.module.test_mode.then(|| params.clone());
+                // Kept to record the response if the message being processed is recorded
+                let recorded_params = params_to_record(env_data, &params);
+                // Run the function on the Tokio runtime and wait for the result, for as long as the
+                // module is still within its execution timeout
+                let deadline = env_data.execution_deadline;
+                let result = env_api.runtime.block_on(within_execution_deadline(deadline, async move {
                     api.$function_name(&params, module).await
-                });
+                })).ok_or(FunctionErrors::TimeoutElapsed)?;
+                if let Some(breaker_call) = breaker_call {
+                    breaker_call.finish(result.as_ref().err().is_none_or(|e| !e.is_integration_failure()));
+                }
+                record_api_response(env_data, stringify!([< $api _ $function_name >]), recorded_params, &result);
 
                 let return_data = match result {
                     Ok(return_data) => return_data,
                     Err(ApiError::TestMode) => {
-                        return Err(FunctionErrors::TestMode);
+                        let params = journal_params.unwrap_or_default();
+                        return blocked_call_data(env_data, stringify!([< $api _ $function_name >]), &params)
+                            .and_then(|response| safely_write_data_back(&memory_view, response.as_bytes(), ret_buffer, ret_buffer_len));
                     }
+                    // The limiter already logged which limit was hit
+                    Err(ApiError::RateLimited(_)) => return Err(FunctionErrors::RateLimited),
                     Err(e) => {
                         error!("{} experienced an issue calling {}: {:?}", env_data.module.name, stringify!([< $api _ $function_name >]), e);
                         return Err(FunctionErrors::InternalApiError);
@@ -174,15 +281,32 @@ macro_rules! impl_new_function_with_error_buffer {
                 return Ok(return_data.len() as i32);
             }
 
-            fn [< $api _ $function_name >] (env: FunctionEnvMut<Env>, params_buffer: WasmPtr<u8>, params_buffer_len: u32, ret_buffer: WasmPtr<u8>, ret_buffer_len: u32) -> i32 {
+            fn [< $api _ $function_name >] (mut env: FunctionEnvMut<Env>, params_buffer: WasmPtr<u8>, params_buffer_len: u32, ret_buffer: WasmPtr<u8>, ret_buffer_len: u32) -> Result<i32, RuntimeError> {
                 let name = env.data().module.name.clone();
-                match [< $api _ $function_name _impl>](env, params_buffer, params_buffer_len, ret_buffer, ret_buffer_len) {
+                let deadline = env.data().execution_deadline;
+                // Record the call if the module's side effects are compared with its shadow's
+                if let Some(side_effects) = &mut env.data_mut().side_effects {
+                    side_effects.push(SideEffect::HostCall(stringify!([< $api _ $function_name >]).to_string()));
+                }
+                let metrics = env.data().metrics.clone();
+                let span = HostCallSpan::start(env.data(), stringify!([< $api _ $function_name >]));
+                let begin = Instant::now();
+                let result = [< $api _ $function_name _impl>](env, params_buffer, params_buffer_len, ret_buffer, ret_buffer_len);
+                if let Some(metrics) = metrics {
+                    metrics.record_host_call(&name, stringify!([< $api _ $function_name >]), begin.elapsed(), result.as_ref().err());
+                }
+                if let Some(span) = span {
+                    span.finish(&result);
+                }
+                let result = match result {
                     Ok(res) => res,
                     Err(e) => {
                         error!("{} experienced an issue calling {}: {:?}", name, stringify!([< $api _ $function_name >]), e);
                         e as i32
                     }
-                }
+                };
+                enforce_execution_deadline(deadline)?;
+                Ok(result)
             }
         }
     }
@@ -207,6 +331,10 @@ macro_rules! impl_new_function_with_error_buffer {
 /// converted to int error codes by the wrapper function. These errors include:
 /// - `FunctionErrors::InternalApiError`: For internal API-related errors.
 /// - `FunctionErrors::ApiNotConfigured`: If the API is not configured.
+/// - `FunctionErrors::CircuitOpen`: If the API has been failing and its circuit breaker is open.
+/// - `FunctionErrors::RateLimited`: If the call would have had to wait too long for its rate limit.
+/// - `FunctionErrors::TimeoutElapsed`: If the module's execution timeout is reached during the call.
+///   The wrapper function then traps, which stops the module.
 /// - `FunctionErrors::ReturnBufferTooSmall`: If the provided return buffer is too small to hold the result.
 #[allow(unused_macros)] // not to have a warning when compiling without the `aws` feature
 macro_rules! impl_new_sub_module_function_with_error_buffer {
@@ -222,12 +350,6 @@ macro_rules! impl_new_sub_module_function_with_error_buffer {
                     return Err(FunctionErrors::InternalApiError);
                 }
 
-
-                // Disallow this function call from continuing if the module is in test mode
-                if !$allow_in_test_mode && env_data.module.test_mode {
-                    return Err(FunctionErrors::TestMode);
-                }
-
                 let memory_view = match get_memory(&env, &store) {
                     Ok(memory_view) => memory_view,
                     Err(e) => {
@@ -236,25 +358,55 @@ macro_rules! impl_new_sub_module_function_with_error_buffer {
                     },
                 };
 
+                // Disallow this function call from continuing if the module is in test mode, whatever its
+                // parameters. The call is journaled, and returns a synthetic response instead if one is configured.
+                if !$allow_in_test_mode && env_data.module.test_mode {
+                    let params = safely_get_string(&memory_view, params_buffer, params_buffer_len).unwrap_or_default();
+                    return blocked_call_data(env_data, stringify!([< $api _ $sub_module _ $function_name >]), &params)
+                        .and_then(|response| safely_write_data_back(&memory_view, response.as_bytes(), ret_buffer, ret_buffer_len));
+                }
+
                 let params = safely_get_string(&memory_view, params_buffer, params_buffer_len)?;
 
+                // When running under the test runtime, answer with the canned response instead of calling the API
+                if let Some(canned) = env_data.api.canned_response(stringify!([< $api _ $sub_module _ $function_name >]), &params) {
+                    return safely_write_data_back(&memory_view, canned.data()?.as_bytes(), ret_buffer, ret_buffer_len);
+                }
+
                 // Check that AWS API is configured
                 let aws = env_data.api.$api.as_ref().ok_or(FunctionErrors::ApiNotConfigured)?;
                 let sub_module = aws.$sub_module.as_ref().ok_or(FunctionErrors::ApiNotConfigured)?;
 
+                // Fail fast while this API keeps failing, rather than waiting on it
+                let breaker_call = env_data.api.circuit_breaker_call(concat!(stringify!($api), "_", stringify!($sub_module)), stringify!([< $api _ $sub_module _ $function_name >]), &params)?;
+
                 // Clone the APIs Arc to use in Tokio closure
                 let env_api = env_data.api.clone();
                 let module = env_data.module.clone();
-                // Run the function on the Tokio runtime and wait for the result
-                let result = env_api.runtime.block_on(async move {
+                // Kept to journal the call if the API refuses it because the module is in test mode
+                let journal_params = env_data.module.test_mode.then(|| params.clone());
+                // Kept to record the response if the message being processed is recorded
+                let recorded_params = params_to_record(env_data, &params);
+                // Run the function on the Tokio runtime and wait for the result, for as long as the
+                // module is still within its execution timeout
+                let deadline = env_data.execution_deadline;
+                let result = env_api.runtime.block_on(within_execution_deadline(deadline, async move {
                     sub_module.$function_name(&params, module).await
-                });
+                })).ok_or(FunctionErrors::TimeoutElapsed)?;
+                if let Some(breaker_call) = breaker_call {
+                    breaker_call.finish(result.as_ref().err().is_none_or(|e| !e.is_integration_failure()));
+                }
+                record_api_response(env_data, stringify!([< $api _ $sub_module _ $function_name >]), recorded_params, &result);
 
                 let return_data = match result {
                     Ok(return_data) => return_data,
                     Err(ApiError::TestMode) => {
-                        return Err(FunctionErrors::TestMode);
+                        let params = journal_params.unwrap_or_default();
+                        return blocked_call_data(env_data, stringify!([< $api _ $sub_module _ $function_name >]), &params)
+                            .and_then(|response| safely_write_data_back(&memory_view, response.as_bytes(), ret_buffer, ret_buffer_len));
                     }
+                    // The limiter already logged which limit was hit
+                    Err(ApiError::RateLimited(_)) => return Err(FunctionErrors::RateLimited),
                     Err(e) => {
                         error!("{} experienced an issue calling {}: {:?}", env_data.module.name, stringify!([< $api _ $sub_module _ $function_name >]), e);
                         return Err(FunctionErrors::InternalApiError);
@@ -273,15 +425,32 @@ macro_rules! impl_new_sub_module_function_with_error_buffer {
                 return Ok(return_data.len() as i32);
             }
 
-            fn [< $api _ $sub_module _ $function_name >] (env: FunctionEnvMut<Env>, params_buffer: WasmPtr<u8>, params_buffer_len: u32, ret_buffer: WasmPtr<u8>, ret_buffer_len: u32) -> i32 {
+            fn [< $api _ $sub_module _ $function_name >] (mut env: FunctionEnvMut<Env>, params_buffer: WasmPtr<u8>, params_buffer_len: u32, ret_buffer: WasmPtr<u8>, ret_buffer_len: u32) -> Result<i32, RuntimeError> {
                 let name = env.data().module.name.clone();
-                match [< $api _ $sub_module _ $function_name _impl>](env, params_buffer, params_buffer_len, ret_buffer, ret_buffer_len) {
+                let deadline = env.data().execution_deadline;
+                // Record the call if the module's side effects are compared with its shadow's
+                if let Some(side_effects) = &mut env.data_mut().side_effects {
+                    side_effects.push(SideEffect::HostCall(stringify!([< $api _ $sub_module _ $function_name >]).to_string()));
+                }
+                let metrics = env.data().metrics.clone();
+                let span = HostCallSpan::start(env.data(), stringify!([< $api _ $sub_module _ $function_name >]));
+                let begin = Instant::now();
+                let result = [< $api _ $sub_module _ $function_name _impl>](env, params_buffer, params_buffer_len, ret_buffer, ret_buffer_len);
+                if let Some(metrics) = metrics {
+                    metrics.record_host_call(&name, stringify!([< $api _ $sub_module _ $function_name >]), begin.elapsed(), result.as_ref().err());
+                }
+                if let Some(span) = span {
+                    span.finish(&result);
+                }
+                let result = match result {
                     Ok(res) => res,
                     Err(e) => {
                         error!("{} experienced an issue calling {}: {:?}", name,  stringify!([< $api _ $sub_module _ $function_name >]), e);
                         e as i32
                     }
-                }
+                };
+                enforce_execution_deadline(deadline)?;
+                Ok(result)
             }
         }
     }
@@ -306,6 +475,10 @@ macro_rules! impl_new_sub_module_function_with_error_buffer {
 /// converted to int error codes by the wrapper function. These errors include:
 /// - `FunctionErrors::InternalApiError`: For internal API-related errors.
 /// - `FunctionErrors::ApiNotConfigured`: If the API is not configured.
+/// - `FunctionErrors::CircuitOpen`: If the API has been failing and its circuit breaker is open.
+/// - `FunctionErrors::RateLimited`: If the call would have had to wait too long for its rate limit.
+/// - `FunctionErrors::TimeoutElapsed`: If the module's execution timeout is reached during the call.
+///   The wrapper function then traps, which stops the module.
 macro_rules! impl_new_sub_module_function {
     ($api:ident, $sub_module:ident, $function_name:ident, $allow_in_test_mode:expr) => {
         paste::item! {
@@ -318,12 +491,6 @@ macro_rules! impl_new_sub_module_function {
                     return Err(FunctionErrors::InternalApiError);
                 }
 
-
-                // Disallow this function call from continuing if the module is in test mode
-                if !$allow_in_test_mode && env_data.module.test_mode {
-                    return Err(FunctionErrors::TestMode);
-                }
-
                 let memory_view = match get_memory(&env, &store) {
                     Ok(memory_view) => memory_view,
                     Err(e) => {
@@ -332,25 +499,53 @@ macro_rules! impl_new_sub_module_function {
                     },
                 };
 
+                // Disallow this function call from continuing if the module is in test mode, whatever its
+                // parameters. The call is journaled, and returns a synthetic response instead if one is configured.
+                if !$allow_in_test_mode && env_data.module.test_mode {
+                    let params = safely_get_string(&memory_view, params_buffer, params_buffer_len).unwrap_or_default();
+                    return blocked_call_code(env_data, stringify!([< $api _ $sub_module _ $function_name >]), &params);
+                }
+
                 let params = safely_get_string(&memory_view, params_buffer, params_buffer_len)?;
 
+                // When running under the test runtime, answer with the canned response instead of calling the API
+                if let Some(canned) = env_data.api.canned_response(stringify!([< $api _ $sub_module _ $function_name >]), &params) {
+                    return canned.code();
+                }
+
                 // Check that API is configured
                 let api = env_data.api.$api.as_ref().ok_or(FunctionErrors::ApiNotConfigured)?;
                 let sub_module = api.$sub_module.as_ref().ok_or(FunctionErrors::ApiNotConfigured)?;
 
+                // Fail fast while this API keeps failing, rather than waiting on it
+                let breaker_call = env_data.api.circuit_breaker_call(concat!(stringify!($api), "_", stringify!($sub_module)), stringify!([< $api _ $sub_module _ $function_name >]), &params)?;
+
                 // Clone the APIs Arc to use in Tokio closure
                 let env_api = env_data.api.clone();
                 let module = env_data.module.clone();
-                // Run the function on the Tokio runtime and wait for the result
-                let result = env_api.runtime.block_on(async move {
+                // Kept to journal the call if the API refuses it because the module is in test mode
+                let journal_params = env_data.module.test_mode.then(|| params.clone());
+                // Kept to record the response if the message being processed is recorded
+                let recorded_params = params_to_record(env_data, &params);
+                // Run the function on the Tokio runtime and wait for the result, for as long as the
+                // module is still within its execution timeout
+                let deadline = env_data.execution_deadline;
+                let result = env_api.runtime.block_on(within_execution_deadline(deadline, async move {
                     sub_module.$function_name(&params, module).await
-                });
+                })).ok_or(FunctionErrors::TimeoutElapsed)?;
+                if let Some(breaker_call) = breaker_call {
+                    breaker_call.finish(result.as_ref().err().is_none_or(|e| !e.is_integration_failure()));
+                }
+                record_api_response(env_data, stringify!([< $api _ $sub_module _ $function_name >]), recorded_params, &result);
 
                 let return_data = match result {
                     Ok(return_data) => return_data,
                     Err(ApiError::TestMode) => {
-                        return Err(FunctionErrors::TestMode);
+                        let params = journal_params.unwrap_or_default();
+                        return blocked_call_code(env_data, stringify!([< $api _ $sub_module _ $function_name >]), &params);
                     }
+                    // The limiter already logged which limit was hit
+                    Err(ApiError::RateLimited(_)) => return Err(FunctionErrors::RateLimited),
                     Err(e) => {
                         error!("{} experienced an issue calling {}: {:?}", env_data.module.name, stringify!([< $api _ $function_name >]), e);
                         return Err(FunctionErrors::InternalApiError);
@@ -361,15 +556,32 @@ macro_rules! impl_new_sub_module_function {
                 return Ok(return_data as i32);
             }
 
-            fn [< $api _ $sub_module _ $function_name >] (env: FunctionEnvMut<Env>, params_buffer: WasmPtr<u8>, params_buffer_len: u32) -> i32 {
+            fn [< $api _ $sub_module _ $function_name >] (mut env: FunctionEnvMut<Env>, params_buffer: WasmPtr<u8>, params_buffer_len: u32) -> Result<i32, RuntimeError> {
                 let name = env.data().module.name.clone();
-                match [< $api _ $sub_module _ $function_name _impl>](env, params_buffer, params_buffer_len) {
+                let deadline = env.data().execution_deadline;
+                // Record the call if the module's side effects are compared with its shadow's
+                if let Some(side_effects) = &mut env.data_mut().side_effects {
+                    side_effects.push(SideEffect::HostCall(stringify!([< $api _ $sub_module _ $function_name >]).to_string()));
+                }
+                let metrics = env.data().metrics.clone();
+                let span = HostCallSpan::start(env.data(), stringify!([< $api _ $sub_module _ $function_name >]));
+                let begin = Instant::now();
+                let result = [< $api _ $sub_module _ $function_name _impl>](env, params_buffer, params_buffer_len);
+                if let Some(metrics) = metrics {
+                    metrics.record_host_call(&name, stringify!([< $api _ $sub_module _ $function_name >]), begin.elapsed(), result.as_ref().err());
+                }
+                if let Some(span) = span {
+                    span.finish(&result);
+                }
+                let result = match result {
                     Ok(res) => res,
                     Err(e) => {
                         error!("{} experienced an issue calling {}: {:?}", name,  stringify!([< $api _ $sub_module _ $function_name >]), e);
                         e as i32
                     }
-                }
+                };
+                enforce_execution_deadline(deadline)?;
+                Ok(result)
             }
         }
     }
@@ -814,6 +1026,7 @@ define_api_functions! {
         "get_secrets"           => super::runtime_data::get_secrets,
         "get_headers"           => super::message::get_headers,
         "get_query_params"      => super::message::get_query_params,
+        "get_attempt"           => super::message::get_attempt,
         "fetch_random_bytes"    => super::internal::fetch_random_bytes,
 
         // The below are types that deal with Plaid specific internals like
@@ -821,8 +1034,10 @@ define_api_functions! {
         // so are broken out into their own module.
         "get_response"             => super::response::get_response,
         "set_response"             => super::response::set_response,
+        "set_batch_results"        => super::response::set_batch_results,
         "set_error_context"        => super::internal::set_error_context,
         "print_debug_string"       => super::internal::print_debug_string,
+        "module_log"               => super::internal::module_log,
         "storage_insert"           => super::storage::insert,
         "storage_insert_shared"    => super::storage::insert_shared,
         "storage_get"              => super::storage::get,
@@ -835,6 +1050,12 @@ define_api_functions! {
         "cache_get"                => super::cache::get,
         "log_back"                 => super::internal::log_back,
         "log_back_unlimited"       => super::internal::log_back_unlimited,
+        "log_back_tracked"         => super::internal::log_back_tracked,
+        "get_logback"              => super::internal::get_logback,
+        "cancel_logback"           => super::internal::cancel_logback,
+        "invoke_module"            => super::invocation::invoke_module,
+        "fetch_invocation_response" => super::invocation::fetch_invocation_response,
+        "record_metric"            => super::metrics::record_metric,
 
         // Npm Calls
         "npm_publish_empty_stub"                  => npm_publish_empty_stub,
@@ -1,11 +1,14 @@
 use std::time::Duration;
 
 use tokio::time::timeout;
-use wasmer::{AsStoreRef, FunctionEnvMut, WasmPtr};
+use wasmer::{AsStoreRef, FunctionEnvMut, RuntimeError, WasmPtr};
 
 use crate::{executor::Env, functions::FunctionErrors};
 
-use super::{get_memory, safely_get_string, safely_write_data_back};
+use super::{
+    block_on_within_deadline, enforce_execution_deadline, get_memory, safely_get_string,
+    safely_write_data_back,
+};
 
 /// Store data in the cache system if one is configured
 pub fn insert(
@@ -16,14 +19,14 @@ pub fn insert(
     value_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let cache = if let Some(c) = &env_data.cache {
         c
     } else {
-        return FunctionErrors::CacheDisabled as i32;
+        return Ok(FunctionErrors::CacheDisabled as i32);
     };
 
     let memory_view = match get_memory(&env, &store) {
@@ -33,7 +36,7 @@ pub fn insert(
                 "{}: Memory error in cache_insert: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
@@ -46,7 +49,7 @@ pub fn insert(
                 "{}: Key error in cache_insert: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::ParametersNotUtf8 as i32;
+            return Ok(FunctionErrors::ParametersNotUtf8 as i32);
         }
     };
 
@@ -58,7 +61,7 @@ pub fn insert(
                 "{}: Value error in cache_insert: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
@@ -94,15 +97,14 @@ pub fn insert(
             }
         }
     };
-    match env_data
-        .api
-        .clone()
-        .runtime
-        .block_on(async move { timeout(Duration::from_secs(5), fut).await })
-    {
-        Ok(v) => v,
-        Err(_) => FunctionErrors::TimeoutElapsed as i32,
-    }
+    let code = match block_on_within_deadline(env_data, async move {
+        timeout(Duration::from_secs(5), fut).await
+    }) {
+        Some(Ok(v)) => v,
+        _ => FunctionErrors::TimeoutElapsed as i32,
+    };
+    enforce_execution_deadline(env_data.execution_deadline)?;
+    Ok(code)
 }
 
 /// Get data from the cache system if one is configured
@@ -112,14 +114,14 @@ pub fn get(
     key_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let cache = if let Some(c) = &env_data.cache {
         c
     } else {
-        return FunctionErrors::CacheDisabled as i32;
+        return Ok(FunctionErrors::CacheDisabled as i32);
     };
 
     let memory_view = match get_memory(&env, &store) {
@@ -129,7 +131,7 @@ pub fn get(
                 "{}: Memory error in cache_get: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
@@ -139,7 +141,7 @@ pub fn get(
         Ok(s) => s,
         Err(e) => {
             error!("{}: Key error in cache_get: {:?}", env_data.module.name, e);
-            return FunctionErrors::ParametersNotUtf8 as i32;
+            return Ok(FunctionErrors::ParametersNotUtf8 as i32);
         }
     };
 
@@ -175,13 +177,12 @@ pub fn get(
             }
         }
     };
-    match env_data
-        .api
-        .clone()
-        .runtime
-        .block_on(async move { timeout(Duration::from_secs(5), fut).await })
-    {
-        Ok(v) => v,
-        Err(_) => FunctionErrors::TimeoutElapsed as i32,
-    }
+    let code = match block_on_within_deadline(env_data, async move {
+        timeout(Duration::from_secs(5), fut).await
+    }) {
+        Some(Ok(v)) => v,
+        _ => FunctionErrors::TimeoutElapsed as i32,
+    };
+    enforce_execution_deadline(env_data.execution_deadline)?;
+    Ok(code)
 }
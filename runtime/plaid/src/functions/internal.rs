@@ -1,11 +1,15 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::time::timeout;
 
 use plaid_stl::messages::{LogSource, LogbacksAllowed};
+use plaid_stl::plaid::logging::ModuleLogEntry;
+use plaid_stl::plaid::LogbackRequest;
 use wasmer::{AsStoreRef, FunctionEnvMut, WasmPtr};
 
 use crate::{
     data::DelayedMessage,
-    executor::{Env, Message},
+    executor::{Env, Message, SideEffect},
     functions::{get_memory, safely_get_string},
 };
 
@@ -41,6 +45,64 @@ pub fn print_debug_string(env: FunctionEnvMut<Env>, log_buffer: WasmPtr<u8>, log
     debug!("Message from [{}]: {message}", env.data().module.name);
 }
 
+/// Implement a way for a module to send a structured log to all the configured logging
+/// backends. Logs over the module's rate limit are dropped.
+pub fn module_log(
+    env: FunctionEnvMut<Env>,
+    entry_buffer: WasmPtr<u8>,
+    entry_buffer_size: u32,
+) -> i32 {
+    let store = env.as_store_ref();
+    let env_data = env.data();
+    let memory_view = match get_memory(&env, &store) {
+        Ok(memory_view) => memory_view,
+        Err(e) => {
+            error!(
+                "{}: Memory error in module_log: {:?}",
+                env_data.module.name, e
+            );
+            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+        }
+    };
+
+    let entry = match safely_get_string(&memory_view, entry_buffer, entry_buffer_size) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("{}: Error in module_log: {:?}", env_data.module.name, e);
+            return FunctionErrors::ParametersNotUtf8 as i32;
+        }
+    };
+
+    let entry = match serde_json::from_str::<ModuleLogEntry>(&entry) {
+        Ok(entry) => entry,
+        Err(e) => {
+            error!(
+                "{}: Could not deserialize log entry: {e}",
+                env_data.module.name
+            );
+            return FunctionErrors::ErrorCouldNotSerialize as i32;
+        }
+    };
+
+    let Some(suppressed) = env_data.module.log_rate_limiter.try_acquire() else {
+        return FunctionErrors::RateLimited as i32;
+    };
+
+    match env_data.external_logging_system.log_module_message(
+        env_data.module.name.clone(),
+        env_data.message.id.clone(),
+        env_data.message.trace.trace_id.clone(),
+        entry,
+        suppressed,
+    ) {
+        Ok(()) => 0,
+        Err(e) => {
+            error!("Logging system is not working!!: {:?}", e);
+            FunctionErrors::InternalApiError as i32
+        }
+    }
+}
+
 /// Implement a way for a module to set a descriptive context for
 /// an error encountered during execution.
 pub fn set_error_context(
@@ -85,7 +147,7 @@ pub fn get_time() -> u32 {
         .expect("Time went backwards")
         .as_secs();
 
-    return current_timestamp as u32;
+    current_timestamp as u32
 }
 
 /// Send a log back with a requested budget
@@ -130,6 +192,100 @@ pub fn log_back_unlimited(
     )
 }
 
+/// Send a log back with a requested budget and write its ID to the guest's buffer, so that
+/// the module can look it up or cancel it later if it is delayed. The log type, delay and
+/// budget are passed as a JSON [`LogbackRequest`].
+pub fn log_back_tracked(
+    mut env: FunctionEnvMut<Env>,
+    request_buf: WasmPtr<u8>,
+    request_buf_len: u32,
+    log_buf: WasmPtr<u8>,
+    log_buf_len: u32,
+    id_buffer: WasmPtr<u8>,
+    id_buffer_size: u32,
+) -> i32 {
+    // Make sure the ID can be returned before scheduling anything, otherwise the module
+    // would have a logback it does not know about. Logback IDs are UUIDs.
+    if id_buffer_size < 36 {
+        return FunctionErrors::ReturnBufferTooSmall as i32;
+    }
+
+    let request = {
+        let store = env.as_store_ref();
+        let memory_view = match get_memory(&env, &store) {
+            Ok(memory_view) => memory_view,
+            Err(e) => {
+                error!(
+                    "{}: Memory error in log_back_tracked: {:?}",
+                    env.data().module.name,
+                    e
+                );
+                return e as i32;
+            }
+        };
+
+        let request = match safely_get_string(&memory_view, request_buf, request_buf_len) {
+            Ok(s) => s,
+            Err(e) => {
+                error!(
+                    "{}: Error in log_back_tracked: {:?}",
+                    env.data().module.name,
+                    e
+                );
+                return FunctionErrors::ParametersNotUtf8 as i32;
+            }
+        };
+
+        match serde_json::from_str::<LogbackRequest>(&request) {
+            Ok(request) => request,
+            Err(e) => {
+                error!(
+                    "{}: Could not deserialize logback request: {e}",
+                    env.data().module.name
+                );
+                return FunctionErrors::ErrorCouldNotSerialize as i32;
+            }
+        }
+    };
+
+    let id = match send_log_back(
+        &mut env,
+        request.log_type,
+        log_buf,
+        log_buf_len,
+        request.delay,
+        LogbacksAllowed::Limited(request.logbacks_allowed),
+    ) {
+        Ok(id) => id,
+        Err(e) => return e as i32,
+    };
+
+    let store = env.as_store_ref();
+    let memory_view = match get_memory(&env, &store) {
+        Ok(memory_view) => memory_view,
+        Err(e) => {
+            error!(
+                "{}: Memory error in log_back_tracked: {:?}",
+                env.data().module.name,
+                e
+            );
+            return e as i32;
+        }
+    };
+
+    match safely_write_data_back(&memory_view, id.as_bytes(), id_buffer, id_buffer_size) {
+        Ok(x) => x,
+        Err(e) => {
+            error!(
+                "{}: Error in log_back_tracked: {:?}",
+                env.data().module.name,
+                e
+            );
+            e as i32
+        }
+    }
+}
+
 /// Send a log from one module into the logging system to be picked up by another module
 pub fn log_back_detailed(
     mut env: FunctionEnvMut<Env>,
@@ -141,6 +297,52 @@ pub fn log_back_detailed(
     // How many logbacks the rule would like this new invocation to be able to trigger
     logbacks_requested: LogbacksAllowed,
 ) -> i32 {
+    let type_ = {
+        let store = env.as_store_ref();
+        let memory_view = match get_memory(&env, &store) {
+            Ok(memory_view) => memory_view,
+            Err(e) => {
+                error!(
+                    "{}: Memory error in log_back: {:?}",
+                    env.data().module.name,
+                    e
+                );
+                return FunctionErrors::InternalApiError as i32;
+            }
+        };
+
+        match safely_get_string(&memory_view, type_buf, type_buf_len) {
+            Ok(s) => s,
+            Err(e) => {
+                error!("{}: Error in log_back: {:?}", env.data().module.name, e);
+                return FunctionErrors::InternalApiError as i32;
+            }
+        }
+    };
+
+    match send_log_back(
+        &mut env,
+        type_,
+        log_buf,
+        log_buf_len,
+        delay,
+        logbacks_requested,
+    ) {
+        Ok(_) => 0,
+        Err(e) => e as i32,
+    }
+}
+
+/// Check the logback budget, read the log from the guest and send it to the logback system
+/// with the given type. Returns the ID of the logback.
+fn send_log_back(
+    env: &mut FunctionEnvMut<Env>,
+    type_: String,
+    log_buf: WasmPtr<u8>,
+    log_buf_len: u32,
+    delay: u32,
+    logbacks_requested: LogbacksAllowed,
+) -> Result<String, FunctionErrors> {
     let name = env.data().module.name.clone();
     // We need to check that the that the module has the logbacks_allowed "budget"
     // for the logback they are requesting
@@ -151,7 +353,7 @@ pub fn log_back_detailed(
         // There is no logback budget left so this call is not allowed to continue
         LogbacksAllowed::Limited(0) => {
             error!("{name}: Logback attempted with zero budget.");
-            return FunctionErrors::LogbackBudgetExhausted as i32;
+            return Err(FunctionErrors::LogbackBudgetExhausted);
         }
         LogbacksAllowed::Limited(x) => {
             // See what the caller was asking for
@@ -165,7 +367,7 @@ pub fn log_back_detailed(
                     // the budget is not 0 so it will not underflow.
                     if asked > (*x - 1) {
                         error!("{name}: Logback budget exceeded. Requested {asked}, but only {x} was available.");
-                        return FunctionErrors::LogbackBudgetExhausted as i32;
+                        return Err(FunctionErrors::LogbackBudgetExhausted);
                     }
                     // The assigned logbacks are subtracted from the budget after
                     // we've checked that it is smaller.
@@ -175,7 +377,7 @@ pub fn log_back_detailed(
                 }
                 LogbacksAllowed::Unlimited => {
                     error!("{name} attempted unlimited log back with limited budget. The budget was {x}");
-                    return FunctionErrors::LogbackBudgetExhausted as i32;
+                    return Err(FunctionErrors::LogbackBudgetExhausted);
                 }
             }
         }
@@ -186,22 +388,14 @@ pub fn log_back_detailed(
     let store = env.as_store_ref();
     let env_data = env.data();
 
-    let memory_view = match get_memory(&env, &store) {
+    let memory_view = match get_memory(env, &store) {
         Ok(memory_view) => memory_view,
         Err(e) => {
             error!(
                 "{}: Memory error in log_back: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::InternalApiError as i32;
-        }
-    };
-
-    let type_ = match safely_get_string(&memory_view, type_buf, type_buf_len) {
-        Ok(s) => s,
-        Err(e) => {
-            error!("{}: Error in log_back: {:?}", env_data.module.name, e);
-            return FunctionErrors::InternalApiError as i32;
+            return Err(FunctionErrors::InternalApiError);
         }
     };
 
@@ -209,18 +403,27 @@ pub fn log_back_detailed(
         Ok(d) => d,
         Err(e) => {
             error!("{}: Error in log_back: {:?}", env_data.module.name, e);
-            return FunctionErrors::InternalApiError as i32;
+            return Err(FunctionErrors::InternalApiError);
         }
     };
 
-    let msg = Message::new(type_, log, LogSource::Logback(name), assigned_budget);
-    match dispatch_logback(env.data(), delay, msg) {
-        Ok(()) => 0,
-        Err(e) => e as i32,
+    let mut msg = Message::new(type_, log, LogSource::Logback(name), assigned_budget);
+    // The logback is part of the same trace as the message that caused it
+    msg.trace = env_data.message.trace.child(&env_data.span_id);
+    // Record the logback if the module's side effects are compared with its shadow's
+    if let Some(side_effects) = &mut env.data_mut().side_effects {
+        side_effects.push(SideEffect::Logback {
+            log_type: msg.type_.clone(),
+            delay,
+        });
     }
+    dispatch_logback(env.data(), delay, msg)
 }
 
-fn dispatch_logback(env: &Env, delay: u32, msg: Message) -> Result<(), FunctionErrors> {
+/// Send a logback to the executor or, if it is delayed, to be persisted until its delay has
+/// elapsed. Returns the ID of the logback, which is the ID of its message.
+fn dispatch_logback(env: &Env, delay: u32, msg: Message) -> Result<String, FunctionErrors> {
+    let id = msg.id.clone();
     let cancelled = env.cancellation_token.is_cancelled();
 
     // Happy path: not shutting down, zero delay, immediate sender available.
@@ -232,7 +435,7 @@ fn dispatch_logback(env: &Env, delay: u32, msg: Message) -> Result<(), FunctionE
             return Err(FunctionErrors::FailedToLogBack);
         }
 
-        return Ok(());
+        return Ok(id);
     }
 
     // Everything else falls through to the delayed queue.
@@ -243,7 +446,11 @@ fn dispatch_logback(env: &Env, delay: u32, msg: Message) -> Result<(), FunctionE
     let actual_delay = std::cmp::max(delay as u64, 1);
     if let Err(e) = env
         .delayed_log_sender
-        .try_send(DelayedMessage::new(actual_delay, msg))
+        .try_send(DelayedMessage::scheduled_by(
+            actual_delay,
+            msg,
+            env.module.name.clone(),
+        ))
     {
         let err = e.to_string();
         let source = e.into_inner().message.source;
@@ -251,7 +458,125 @@ fn dispatch_logback(env: &Env, delay: u32, msg: Message) -> Result<(), FunctionE
         return Err(FunctionErrors::FailedToLogBack);
     }
 
-    Ok(())
+    Ok(id)
+}
+
+/// Implement a way for a module to look up a delayed logback it scheduled. Nothing is
+/// written back if the module has no such logback waiting to be delivered.
+pub fn get_logback(
+    env: FunctionEnvMut<Env>,
+    id_buffer: WasmPtr<u8>,
+    id_buffer_size: u32,
+    data_buffer: WasmPtr<u8>,
+    data_buffer_size: u32,
+) -> i32 {
+    let store = env.as_store_ref();
+    let env_data = env.data();
+    let memory_view = match get_memory(&env, &store) {
+        Ok(memory_view) => memory_view,
+        Err(e) => {
+            error!(
+                "{}: Memory error in get_logback: {:?}",
+                env_data.module.name, e
+            );
+            return e as i32;
+        }
+    };
+
+    let id = match safely_get_string(&memory_view, id_buffer, id_buffer_size) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("{}: Error in get_logback: {:?}", env_data.module.name, e);
+            return e as i32;
+        }
+    };
+
+    let logback = env_data.api.clone().runtime.block_on(async {
+        timeout(
+            Duration::from_secs(5),
+            env_data.scheduled_logbacks.get(&env_data.module.name, &id),
+        )
+        .await
+    });
+    let logback = match logback {
+        Ok(Ok(Some(logback))) => logback,
+        Ok(Ok(None)) => return 0,
+        Ok(Err(e)) => {
+            error!(
+                "{}: Storage error in get_logback: {e}",
+                env_data.module.name
+            );
+            return FunctionErrors::InternalApiError as i32;
+        }
+        Err(_) => return FunctionErrors::TimeoutElapsed as i32,
+    };
+
+    let logback = match serde_json::to_vec(&logback) {
+        Ok(logback) => logback,
+        Err(_) => return FunctionErrors::ErrorCouldNotSerialize as i32,
+    };
+
+    match safely_write_data_back(&memory_view, &logback, data_buffer, data_buffer_size) {
+        Ok(x) => x,
+        Err(e) => {
+            error!("{}: Error in get_logback: {:?}", env_data.module.name, e);
+            e as i32
+        }
+    }
+}
+
+/// Implement a way for a module to cancel a delayed logback it scheduled. Returns 1 if the
+/// logback was cancelled and 0 if the module had no such logback waiting to be delivered.
+pub fn cancel_logback(
+    env: FunctionEnvMut<Env>,
+    id_buffer: WasmPtr<u8>,
+    id_buffer_size: u32,
+) -> i32 {
+    let store = env.as_store_ref();
+    let env_data = env.data();
+    let memory_view = match get_memory(&env, &store) {
+        Ok(memory_view) => memory_view,
+        Err(e) => {
+            error!(
+                "{}: Memory error in cancel_logback: {:?}",
+                env_data.module.name, e
+            );
+            return e as i32;
+        }
+    };
+
+    let id = match safely_get_string(&memory_view, id_buffer, id_buffer_size) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("{}: Error in cancel_logback: {:?}", env_data.module.name, e);
+            return e as i32;
+        }
+    };
+
+    let cancelled = env_data.api.clone().runtime.block_on(async {
+        timeout(
+            Duration::from_secs(5),
+            env_data
+                .scheduled_logbacks
+                .cancel(&env_data.module.name, &id),
+        )
+        .await
+    });
+    match cancelled {
+        Ok(Ok(true)) => {
+            info!("{}: Cancelled logback [{id}]", env_data.module.name);
+            1
+        }
+        Ok(Ok(false)) => 0,
+        Ok(Err(e)) => {
+            error!(
+                "{}: Storage error in cancel_logback: {e}",
+                env_data.module.name
+            );
+            FunctionErrors::InternalApiError as i32
+        }
+        Err(_) => FunctionErrors::TimeoutElapsed as i32,
+    }
 }
 
 /// Implement a way for randomness to get into the module
@@ -301,3 +626,99 @@ pub fn fetch_random_bytes(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crossbeam_channel::{unbounded, Receiver};
+    use plaid_stl::messages::LogbacksAllowed;
+
+    use crate::data::DelayedMessage;
+    use crate::executor::{execute_standalone, ModuleExecutionError};
+    use crate::storage::Storage;
+    use crate::testing::fixtures::{api, message, standalone_context, wat_module};
+
+    const REQUEST: &str = r#"{"log_type":"later","delay":60,"logbacks_allowed":0}"#;
+
+    /// Run a module which schedules a logback with `log_back_tracked`, giving it a buffer of
+    /// `id_buffer_size` bytes for the ID. The module responds with the ID it got back.
+    fn schedule(
+        logbacks_allowed: LogbacksAllowed,
+        id_buffer_size: u32,
+    ) -> (
+        Result<Option<String>, ModuleExecutionError>,
+        Receiver<DelayedMessage>,
+    ) {
+        let wat = format!(
+            r#"(module
+                (import "env" "log_back_tracked" (func $log_back_tracked (param i32 i32 i32 i32 i32 i32) (result i32)))
+                (import "env" "set_response" (func $set_response (param i32 i32)))
+                (memory (export "memory") 1)
+                (data (i32.const 0) "{request}")
+                (data (i32.const 256) "hello")
+                (func (export "entrypoint") (result i32)
+                    (local $copied i32)
+                    (local.set $copied (call $log_back_tracked
+                        (i32.const 0) (i32.const {len})
+                        (i32.const 256) (i32.const 5)
+                        (i32.const 512) (i32.const {id_buffer_size})))
+                    (if (i32.lt_s (local.get $copied) (i32.const 0))
+                        (then (return (local.get $copied))))
+                    (call $set_response (i32.const 512) (local.get $copied))
+                    i32.const 0))"#,
+            request = REQUEST.replace('"', "\\\""),
+            len = REQUEST.len(),
+        );
+        let mut message = message("scheduler", b"");
+        message.logbacks_allowed = logbacks_allowed;
+        let (mut context, _logs) = standalone_context(api(), Arc::new(Storage::new_in_memory()));
+        let (delayed_log_sender, delayed_logs) = unbounded();
+        context.delayed_log_sender = delayed_log_sender;
+
+        let response =
+            execute_standalone(message, Arc::new(wat_module("scheduler", &wat)), context)
+                .unwrap_or_else(|e| panic!("{e}"))
+                .response;
+        (response, delayed_logs)
+    }
+
+    #[test]
+    fn tracked_logbacks_return_the_id_of_the_scheduled_message() {
+        let (response, delayed_logs) = schedule(LogbacksAllowed::Limited(1), 36);
+
+        let scheduled = delayed_logs.try_recv().unwrap();
+        let response = response.unwrap_or_else(|e| panic!("{e}"));
+        assert_eq!(response, Some(scheduled.message.id.clone()));
+        assert_eq!(scheduled.delay, 60);
+        assert_eq!(scheduled.scheduled_by.as_deref(), Some("scheduler.wasm"));
+        assert_eq!(scheduled.message.type_, "later");
+        assert_eq!(scheduled.message.data, b"hello");
+        assert!(matches!(
+            scheduled.message.logbacks_allowed,
+            LogbacksAllowed::Limited(0)
+        ));
+    }
+
+    #[test]
+    fn tracked_logbacks_need_a_budget() {
+        let (response, delayed_logs) = schedule(LogbacksAllowed::Limited(0), 36);
+
+        assert!(matches!(
+            response,
+            Err(ModuleExecutionError::ModuleError(_))
+        ));
+        assert!(delayed_logs.try_recv().is_err());
+    }
+
+    #[test]
+    fn nothing_is_scheduled_if_the_id_cannot_be_returned() {
+        let (response, delayed_logs) = schedule(LogbacksAllowed::Limited(1), 10);
+
+        assert!(matches!(
+            response,
+            Err(ModuleExecutionError::ModuleError(_))
+        ));
+        assert!(delayed_logs.try_recv().is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use wasmer::{AsStoreRef, FunctionEnvMut, RuntimeError, WasmPtr};
+use wasmer_middlewares::metering::{get_remaining_points, set_remaining_points, MeteringPoints};
+
+use super::{
+    calculate_max_buffer_size, enforce_execution_deadline, get_memory, safely_get_memory,
+    safely_get_string, safely_write_data_back, FunctionErrors,
+};
+use crate::executor::{invoke_module as run_invoked_module, Env};
+
+/// Implement a way for a module to run another module and wait for its response. The
+/// response is kept in the environment until the module fetches it with
+/// `fetch_invocation_response`, and this returns how long it is.
+///
+/// The computation the invoked module uses is charged to the calling module, so running
+/// out of it in the invoked module stops the calling module too.
+pub fn invoke_module(
+    mut env: FunctionEnvMut<Env>,
+    module_buf: WasmPtr<u8>,
+    module_buf_len: u32,
+    payload_buf: WasmPtr<u8>,
+    payload_buf_len: u32,
+) -> Result<i32, RuntimeError> {
+    let max_buffer_size = calculate_max_buffer_size(env.data().module.page_limit);
+    let store = env.as_store_ref();
+    let env_data = env.data();
+    let memory_view = match get_memory(&env, &store) {
+        Ok(memory_view) => memory_view,
+        Err(e) => {
+            error!(
+                "{}: Memory error in invoke_module: {:?}",
+                env_data.module.name, e
+            );
+            return Ok(e as i32);
+        }
+    };
+
+    let callee = match safely_get_string(&memory_view, module_buf, module_buf_len) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("{}: Error in invoke_module: {:?}", env_data.module.name, e);
+            return Ok(e as i32);
+        }
+    };
+
+    let payload =
+        match safely_get_memory(&memory_view, payload_buf, payload_buf_len, max_buffer_size) {
+            Ok(d) => d,
+            Err(e) => {
+                error!("{}: Error in invoke_module: {:?}", env_data.module.name, e);
+                return Ok(e as i32);
+            }
+        };
+
+    let Some(instance) = env_data.instance.clone() else {
+        error!(
+            "{}: No instance to charge computation to in invoke_module",
+            env_data.module.name
+        );
+        return Ok(FunctionErrors::InternalApiError as i32);
+    };
+    let remaining = match get_remaining_points(&mut env, &instance) {
+        MeteringPoints::Remaining(remaining) => remaining,
+        MeteringPoints::Exhausted => 0,
+    };
+
+    let env_data = env.data();
+    let caller = env_data.module.name.clone();
+    let outcome = match run_invoked_module(env_data, &callee, payload.clone(), remaining) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            warn!("{caller}: Could not invoke [{callee}]: {e}");
+            return Ok(FunctionErrors::InvocationFailed as i32);
+        }
+    };
+    let els = env_data.external_logging_system.clone();
+    let deadline = env_data.execution_deadline;
+
+    // Whatever happened, the caller pays for the computation the invoked module used
+    set_remaining_points(
+        &mut env,
+        &instance,
+        remaining.saturating_sub(outcome.computation_used),
+    );
+
+    let code = match outcome.response {
+        Ok(response) => {
+            let length = response.as_ref().map(|r| r.len()).unwrap_or_default();
+            env.data_mut().invocation_response = response;
+            length as i32
+        }
+        Err(e) => {
+            if let Err(e) = els.log_module_error(
+                callee.clone(),
+                format!("Failed while invoked by [{caller}]: {e}"),
+                payload,
+            ) {
+                error!("Logging system is not working!!: {:?}", e);
+            }
+            env.data_mut().invocation_response = None;
+            FunctionErrors::InvocationFailed as i32
+        }
+    };
+
+    // The invoked module may have used up the rest of the caller's time
+    enforce_execution_deadline(deadline)?;
+    Ok(code)
+}
+
+/// Implement a way for a module to get the response of the module it last invoked
+pub fn fetch_invocation_response(
+    env: FunctionEnvMut<Env>,
+    data_buffer: WasmPtr<u8>,
+    buffer_size: u32,
+) -> i32 {
+    let store = env.as_store_ref();
+    let env_data = env.data();
+    let memory_view = match get_memory(&env, &store) {
+        Ok(memory_view) => memory_view,
+        Err(e) => {
+            error!(
+                "{}: Memory error in fetch_invocation_response: {:?}",
+                env_data.module.name, e
+            );
+            return e as i32;
+        }
+    };
+
+    let Some(response) = &env_data.invocation_response else {
+        return 0;
+    };
+
+    match safely_write_data_back(&memory_view, response.as_bytes(), data_buffer, buffer_size) {
+        Ok(x) => x,
+        Err(e) => {
+            error!(
+                "{}: Error in fetch_invocation_response: {:?}",
+                env_data.module.name, e
+            );
+            e as i32
+        }
+    }
+}
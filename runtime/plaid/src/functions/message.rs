@@ -201,3 +201,9 @@ macro_rules! generate_string_getter {
 // Documentation for these methods is generated by the macro itself
 generate_string_getter!(headers);
 generate_string_getter!(query_params);
+
+/// Get which attempt at processing the current message this is. This is 1 the first time
+/// a message is processed and goes up every time the module's retry policy retries it.
+pub fn get_attempt(env: FunctionEnvMut<Env>) -> u32 {
+    env.data().message.attempt()
+}
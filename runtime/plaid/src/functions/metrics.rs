@@ -0,0 +1,64 @@
+use plaid_stl::plaid::metrics::MetricUpdate;
+use wasmer::{AsStoreRef, FunctionEnvMut, WasmPtr};
+
+use super::{get_memory, safely_get_string, FunctionErrors};
+use crate::executor::Env;
+
+/// Implement a way for a module to update one of its own Prometheus metrics
+pub fn record_metric(
+    env: FunctionEnvMut<Env>,
+    update_buf: WasmPtr<u8>,
+    update_buf_len: u32,
+) -> i32 {
+    let store = env.as_store_ref();
+    let env_data = env.data();
+
+    let Some(metrics) = &env_data.metrics else {
+        return FunctionErrors::MetricsDisabled as i32;
+    };
+
+    let memory_view = match get_memory(&env, &store) {
+        Ok(memory_view) => memory_view,
+        Err(e) => {
+            error!(
+                "{}: Memory error in record_metric: {:?}",
+                env_data.module.name, e
+            );
+            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+        }
+    };
+
+    let update = match safely_get_string(&memory_view, update_buf, update_buf_len) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("{}: Error in record_metric: {:?}", env_data.module.name, e);
+            return FunctionErrors::ParametersNotUtf8 as i32;
+        }
+    };
+
+    let update = match serde_json::from_str::<MetricUpdate>(&update) {
+        Ok(update) => update,
+        Err(e) => {
+            error!(
+                "{}: Could not deserialize metric update: {e}",
+                env_data.module.name
+            );
+            return FunctionErrors::ErrorCouldNotSerialize as i32;
+        }
+    };
+
+    match metrics.record_custom(
+        &env_data.module.name,
+        env_data.module.custom_metric_series_limit,
+        &update,
+    ) {
+        Ok(()) => 0,
+        Err(e) => {
+            warn!(
+                "{}: Rejected update to metric [{}]: {e}",
+                env_data.module.name, update.name
+            );
+            FunctionErrors::MetricRejected as i32
+        }
+    }
+}
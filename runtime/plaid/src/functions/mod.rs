@@ -1,22 +1,32 @@
 mod api;
 mod cache;
 mod internal;
+mod invocation;
 mod memory;
 mod message;
+mod metrics;
 mod response;
 mod runtime_data;
 mod storage;
+mod test_mode;
 
 use memory::*;
 
+use serde::{Deserialize, Serialize};
+
 pub use api::is_known_api_function;
 use api::to_api_function;
-use wasmer::{Exports, Function, FunctionEnv, Module, Store};
+use std::future::Future;
+use std::time::Instant;
+pub use test_mode::{BlockedCall, TestModeJournalConfig};
+use wasmer::{Exports, Function, FunctionEnv, Module, RuntimeError, Store};
 
 use crate::executor::Env;
+use crate::logging::Logger;
+use crate::trace::{new_span_id, Span};
 
 /// Errors that can be encountered during execution
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum FunctionErrors {
     ApiNotConfigured = -1,
     ReturnBufferTooSmall = -2,
@@ -34,6 +44,11 @@ pub enum FunctionErrors {
     TimeoutElapsed = -14,
     FailedToLogBack = -15,
     LogbackBudgetExhausted = -16,
+    MetricsDisabled = -17,
+    MetricRejected = -18,
+    RateLimited = -19,
+    InvocationFailed = -20,
+    CircuitOpen = -21,
 }
 
 #[derive(Debug)]
@@ -49,6 +64,75 @@ impl std::fmt::Display for LinkError {
     }
 }
 
+/// Await a future, giving up once the module's execution deadline (if it has one) has passed.
+/// Returns `None` if the deadline was reached before the future completed.
+pub async fn within_execution_deadline<F: Future>(
+    deadline: Option<Instant>,
+    future: F,
+) -> Option<F::Output> {
+    match deadline {
+        Some(deadline) => tokio::time::timeout_at(deadline.into(), future).await.ok(),
+        None => Some(future.await),
+    }
+}
+
+/// Run a future on the APIs' runtime and wait for its result, for as long as the module is
+/// still within its execution deadline. Returns `None` if the deadline was reached first.
+pub fn block_on_within_deadline<F: Future>(env_data: &Env, future: F) -> Option<F::Output> {
+    env_data.api.runtime.block_on(within_execution_deadline(
+        env_data.execution_deadline,
+        future,
+    ))
+}
+
+/// Stop the module if it has run past its execution deadline. Returning an error from a
+/// host function traps the instance, so the module is interrupted as soon as the host call
+/// returns and the executor reports the timeout.
+pub fn enforce_execution_deadline(deadline: Option<Instant>) -> Result<(), RuntimeError> {
+    match deadline {
+        Some(deadline) if Instant::now() >= deadline => {
+            Err(RuntimeError::new("Module execution timeout exceeded"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The span of a module's call to a host function, if spans are being exported
+pub struct HostCallSpan {
+    els: Logger,
+    span: Span,
+}
+
+impl HostCallSpan {
+    /// Start the span of a call to `function` by the module running in `env`. The span is
+    /// part of the span of the module's execution.
+    pub fn start(env: &Env, function: &str) -> Option<Self> {
+        if !env.external_logging_system.tracing_enabled() {
+            return None;
+        }
+        let span = Span::start(
+            function,
+            &env.message.trace.trace_id,
+            new_span_id(),
+            Some(env.span_id.clone()),
+        )
+        .with_attribute("plaid.module", &env.module.name);
+
+        Some(Self {
+            els: env.external_logging_system.clone(),
+            span,
+        })
+    }
+
+    /// Finish the span with the result of the call
+    pub fn finish<T>(self, result: &Result<T, FunctionErrors>) {
+        let error = result.as_ref().err().map(|e| format!("{e:?}"));
+        if let Err(e) = self.els.record_span(self.span.finish(error)) {
+            error!("Logging system is not working!!: {:?}", e);
+        }
+    }
+}
+
 pub fn fake_wbindgen_describe(placeholder: i32) {
     warn!("Fake __wbindgen_describe called with placeholder: {placeholder}");
 }
@@ -139,3 +223,83 @@ pub fn create_bindgen_externref_xform(mut store: &mut Store) -> Exports {
 
     exports
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::apis::ApiConfigs;
+    use crate::executor::{execute_standalone, ModuleExecutionError};
+    use crate::storage::Storage;
+    use crate::testing::fixtures::{
+        api_with, message, standalone_context, stuck_storage, wat_module,
+    };
+
+    /// Run a module which calls `function` with `params` once, with a one second execution timeout
+    fn run_calling(
+        function: &str,
+        params: &str,
+        api: ApiConfigs,
+        storage: Storage,
+    ) -> Result<Option<String>, ModuleExecutionError> {
+        let wat = format!(
+            r#"(module
+                (import "env" "{function}" (func $call (param i32 i32 i32 i32) (result i32)))
+                (memory (export "memory") 1)
+                (data (i32.const 0) "{data}")
+                (func (export "entrypoint") (result i32)
+                    (drop (call $call (i32.const 0) (i32.const {len}) (i32.const 1024) (i32.const 1024)))
+                    i32.const 0))"#,
+            data = params.replace('"', "\\\""),
+            len = params.len(),
+        );
+        let mut module = wat_module("slow", &wat);
+        module.execution_timeout = Some(Duration::from_secs(1));
+        let (context, _logs) = standalone_context(api_with(api), Arc::new(storage));
+
+        execute_standalone(message("slow", b""), Arc::new(module), context)
+            .unwrap_or_else(|e| panic!("{e}"))
+            .response
+    }
+
+    #[test]
+    fn slow_api_calls_stop_at_the_deadline() {
+        // Connections to this listener are accepted by the OS but never answered
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let api: ApiConfigs = toml::from_str(&format!(
+            r#"
+            [general.network.web_requests.slow]
+            verb = "post"
+            uri = "http://{}/"
+            return_body = true
+            return_code = true
+            allowed_rules = ["slow.wasm"]
+            headers = {{}}
+            "#,
+            listener.local_addr().unwrap()
+        ))
+        .unwrap();
+
+        let response = run_calling(
+            "general_make_named_request",
+            r#"{"body":"","request_name":"slow","variables":{},"headers":null,"response_encoding":"Utf8"}"#,
+            api,
+            Storage::new_in_memory(),
+        );
+        assert!(matches!(
+            response,
+            Err(ModuleExecutionError::TimeoutExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn slow_storage_calls_stop_at_the_deadline() {
+        let response = run_calling("storage_get", "key", ApiConfigs::default(), stuck_storage());
+        assert!(matches!(
+            response,
+            Err(ModuleExecutionError::TimeoutExceeded(_))
+        ));
+    }
+}
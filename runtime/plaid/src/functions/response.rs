@@ -1,3 +1,4 @@
+use plaid_stl::messages::BatchResult;
 use wasmer::{AsStoreRef, FunctionEnvMut, WasmPtr};
 
 use crate::{executor::Env, functions::FunctionErrors};
@@ -82,3 +83,33 @@ pub fn set_response(
     let data = env.data_mut();
     data.response = Some(message);
 }
+
+/// Implement a way for a module processing a batch of messages to report the result of each
+/// of them. The results are a JSON list of [`BatchResult`]s, which say for each message ID
+/// the error the module encountered or null if it processed the message. Results which
+/// cannot be read are kept as an error, so every message of the batch counts as failed.
+pub fn set_batch_results(
+    mut env: FunctionEnvMut<Env>,
+    results_buffer: WasmPtr<u8>,
+    results_buffer_size: u32,
+) {
+    let store = env.as_store_ref();
+    let results = match get_memory(&env, &store) {
+        Ok(memory_view) => {
+            match safely_get_string(&memory_view, results_buffer, results_buffer_size) {
+                Ok(results) => serde_json::from_str::<Vec<BatchResult>>(&results)
+                    .map_err(|e| format!("Could not parse batch results: {e}")),
+                Err(e) => Err(format!("Error in set_batch_results: {e:?}")),
+            }
+        }
+        Err(e) => Err(format!("Memory error in set_batch_results: {e:?}")),
+    };
+
+    if let Err(e) = &results {
+        error!("{}: {e}", env.data().module.name);
+    }
+
+    let mut env = env.as_mut();
+    let data = env.data_mut();
+    data.batch_results = Some(results);
+}
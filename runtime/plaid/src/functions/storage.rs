@@ -1,12 +1,18 @@
 use std::sync::{Arc, RwLock};
 
-use wasmer::{AsStoreRef, FunctionEnvMut, MemoryView, WasmPtr};
-
-use crate::{executor::Env, functions::FunctionErrors, loader::LimitValue, storage::Storage};
+use wasmer::{AsStoreRef, FunctionEnvMut, MemoryView, RuntimeError, WasmPtr};
+
+use crate::{
+    cryptography::hash::sha256_hex,
+    executor::{Env, SideEffect},
+    functions::FunctionErrors,
+    loader::LimitValue,
+    storage::Storage,
+};
 
 use super::{
-    calculate_max_buffer_size, get_memory, safely_get_memory, safely_get_string,
-    safely_write_data_back,
+    block_on_within_deadline, calculate_max_buffer_size, enforce_execution_deadline, get_memory,
+    safely_get_memory, safely_get_string, safely_write_data_back,
 };
 
 macro_rules! safely_get_guest_string {
@@ -18,7 +24,7 @@ macro_rules! safely_get_guest_string {
                     "{}: error while getting a string from guest memory: {:?}",
                     $env_data.module.name, e
                 );
-                return FunctionErrors::ParametersNotUtf8 as i32;
+                return Ok(FunctionErrors::ParametersNotUtf8 as i32);
             }
         };
     };
@@ -34,12 +40,16 @@ macro_rules! safely_get_guest_memory {
                     "{}: error while getting bytes from guest memory: {:?}",
                     $env_data.module.name, e
                 );
-                return FunctionErrors::ParametersNotUtf8 as i32;
+                return Ok(FunctionErrors::ParametersNotUtf8 as i32);
             }
         };
     };
 }
 
+/// What a write to storage replaced: `None` if nothing was written, otherwise the value the
+/// key had before, if it had one
+type Replaced = Option<Option<Vec<u8>>>;
+
 /// Code which is common to `insert` and `insert_shared`
 fn insert_common(
     env_data: &Env,
@@ -52,7 +62,7 @@ fn insert_common(
     data_buffer_len: u32,
     storage_limit: LimitValue,
     storage_counter: &Arc<RwLock<u64>>,
-) -> i32 {
+) -> (i32, Replaced) {
     // ugly, but we are dealing with a couple of "async move"s
     let storage_key = key.clone();
     let namespace_clone = namespace.clone();
@@ -61,11 +71,9 @@ fn insert_common(
     let insertion_result = match storage_limit {
         LimitValue::Unlimited => {
             // The storage is unlimited, so we don't check / update any counters and just proceed with the operation
-            env_data
-                .api
-                .clone()
-                .runtime
-                .block_on(async move { storage.insert(namespace, storage_key, value).await })
+            block_on_within_deadline(env_data, async move {
+                storage.insert(namespace, storage_key, value).await
+            })
         }
         LimitValue::Limited(storage_limit) => {
             // The storage is limited, so we need to check / update counters (with locks) because the operation might have to be rejected.
@@ -77,7 +85,7 @@ fn insert_common(
                 Ok(g) => g,
                 Err(e) => {
                     error!("Critical error getting a lock on used storage: {:?}", e);
-                    return FunctionErrors::InternalApiError as i32;
+                    return (FunctionErrors::InternalApiError as i32, None);
                 }
             };
 
@@ -87,18 +95,18 @@ fn insert_common(
             // of a possible insertion, we would have only one key.
             let get_key = key.clone();
             let key_len = key.as_bytes().len();
-            let existing_data_size = match env_data
-                .api
-                .clone()
-                .runtime
-                .block_on(async move { storage.get(&namespace, &get_key).await })
-            {
-                Ok(data) => match data {
+            let existing_data_size = match block_on_within_deadline(env_data, async move {
+                storage.get(&namespace, &get_key).await
+            }) {
+                Some(Ok(data)) => match data {
                     None => 0u64,
                     Some(d) => d.len() as u64 + key_len as u64,
                 },
-                Err(_) => {
-                    return FunctionErrors::InternalApiError as i32;
+                Some(Err(_)) => {
+                    return (FunctionErrors::InternalApiError as i32, None);
+                }
+                None => {
+                    return (FunctionErrors::TimeoutElapsed as i32, None);
                 }
             };
 
@@ -116,17 +124,19 @@ fn insert_common(
                     "Could not insert key/value as that would bring us above the configured storage limit.".to_string(),
                     vec![]
                 );
-                return FunctionErrors::StorageLimitReached as i32;
+                return (FunctionErrors::StorageLimitReached as i32, None);
             }
 
-            let result =
-                env_data.api.clone().runtime.block_on(async move {
-                    storage.insert(namespace_clone, storage_key, value).await
-                });
-            // If the insertion went well, update counter for used storage.
-            // If the insertion failed for some reason, we don't update the counter and release the lock: no harm done.
-            if result.is_ok() {
-                *storage_current = would_be_used_storage;
+            // The insertion is counted before it is made. If the deadline cuts it short, it may
+            // still have been written, and counting it keeps the module within its limit. Only
+            // an insertion which is known to have failed is taken back.
+            let storage_before = *storage_current;
+            *storage_current = would_be_used_storage;
+            let result = block_on_within_deadline(env_data, async move {
+                storage.insert(namespace_clone, storage_key, value).await
+            });
+            if let Some(Err(_)) = result {
+                *storage_current = storage_before;
             }
             result
         }
@@ -134,15 +144,14 @@ fn insert_common(
 
     // Process the insertion result and return info to the caller
     match insertion_result {
-        Ok(data) => {
-            match data {
+        Some(Ok(data)) => {
+            let code = match &data {
                 Some(data) => {
                     // If the data is too large to fit in the buffer that was passed to us. Unfortunately this is a somewhat
                     // unrecoverable state because we've overwritten the value already. We could fail insertion if the data
                     // buffer passed is too small in future? That would mean doing a get call first, which the client can do
                     // too.
-                    match safely_write_data_back(&memory_view, &data, data_buffer, data_buffer_len)
-                    {
+                    match safely_write_data_back(&memory_view, data, data_buffer, data_buffer_len) {
                         Ok(x) => x,
                         Err(e) => {
                             error!(
@@ -155,37 +164,60 @@ fn insert_common(
                 }
                 // This occurs when there is no such key so the number of bytes that have been copied back are 0
                 None => 0,
-            }
+            };
+            (code, Some(data))
         }
         // If the storage system errors (for example a network problem if using a networked storage provider)
         // the error is made opaque to the client here and we log what happened
-        Err(e) => {
+        Some(Err(e)) => {
             error!(
                 "There was a storage system error when key [{key}] was accessed by [{}]: {e}",
                 env_data.module.name
             );
-            return FunctionErrors::InternalApiError as i32;
+            (FunctionErrors::InternalApiError as i32, None)
         }
+        None => (FunctionErrors::TimeoutElapsed as i32, None),
+    }
+}
+
+/// Record a write to the module's own namespace if its side effects are compared with its
+/// shadow's, along with the value the key had before the module first wrote it
+fn record_storage_write(
+    env: &mut FunctionEnvMut<Env>,
+    key: String,
+    written: Option<SideEffect>,
+    replaced: Replaced,
+) {
+    let (Some(written), Some(replaced)) = (written, replaced) else {
+        return;
+    };
+    let env_data = env.data_mut();
+    env_data
+        .storage_before_writes
+        .entry(key)
+        .or_insert(replaced);
+    if let Some(side_effects) = &mut env_data.side_effects {
+        side_effects.push(written);
     }
 }
 
 /// Store data in the storage system if one is configured
 pub fn insert(
-    env: FunctionEnvMut<Env>,
+    mut env: FunctionEnvMut<Env>,
     key_buf: WasmPtr<u8>,
     key_buf_len: u32,
     value_buf: WasmPtr<u8>,
     value_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let storage = if let Some(storage) = &env_data.storage {
         storage
     } else {
-        return FunctionErrors::ApiNotConfigured as i32;
+        return Ok(FunctionErrors::ApiNotConfigured as i32);
     };
 
     let memory_view = match get_memory(&env, &store) {
@@ -195,25 +227,36 @@ pub fn insert(
                 "{}: Memory error in storage_insert: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
     safely_get_guest_string!(key, memory_view, key_buf, key_buf_len, env_data);
     safely_get_guest_memory!(value, memory_view, value_buf, value_buf_len, env_data);
 
-    insert_common(
+    let written = env_data
+        .side_effects
+        .is_some()
+        .then(|| SideEffect::StorageInsert {
+            key: key.clone(),
+            value_sha256: sha256_hex(&value),
+        });
+    let (code, replaced) = insert_common(
         env_data,
         storage,
         env_data.module.name.clone(),
-        key,
+        key.clone(),
         value,
         memory_view,
         data_buffer,
         data_buffer_len,
         env_data.module.storage_limit.clone(),
         &env_data.module.storage_current,
-    )
+    );
+    let deadline = env_data.execution_deadline;
+    record_storage_write(&mut env, key, written, replaced);
+    enforce_execution_deadline(deadline)?;
+    Ok(code)
 }
 
 /// Store data in a shared namespace in the storage system, if one is configured
@@ -227,20 +270,20 @@ pub fn insert_shared(
     value_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let storage = if let Some(storage) = &env_data.storage {
         storage
     } else {
-        return FunctionErrors::ApiNotConfigured as i32;
+        return Ok(FunctionErrors::ApiNotConfigured as i32);
     };
 
     // Check if we have shared DBs at all, otherwise we just stop
     let shared_dbs = match &storage.shared_dbs {
         None => {
-            return FunctionErrors::OperationNotAllowed as i32;
+            return Ok(FunctionErrors::OperationNotAllowed as i32);
         }
         Some(x) => x,
     };
@@ -252,7 +295,7 @@ pub fn insert_shared(
                 "{}: Memory error in storage_insert_shared: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
@@ -270,7 +313,7 @@ pub fn insert_shared(
         Some(db) => db.config.rw.contains(&env_data.module.name),
     };
     if !allowed {
-        return FunctionErrors::OperationNotAllowed as i32;
+        return Ok(FunctionErrors::OperationNotAllowed as i32);
     }
 
     safely_get_guest_string!(key, memory_view, key_buf, key_buf_len, env_data);
@@ -279,17 +322,17 @@ pub fn insert_shared(
     // Get storage limit and counter for the shared DB
     let (storage_limit, storage_current) = match &storage.shared_dbs {
         None => {
-            return FunctionErrors::SharedDbError as i32;
+            return Ok(FunctionErrors::SharedDbError as i32);
         }
         Some(shared_dbs) => match shared_dbs.get(&namespace) {
             None => {
-                return FunctionErrors::SharedDbError as i32;
+                return Ok(FunctionErrors::SharedDbError as i32);
             }
             Some(db) => (db.config.size_limit.clone(), db.used_storage.clone()),
         },
     };
 
-    insert_common(
+    let (code, _) = insert_common(
         env_data,
         storage,
         namespace,
@@ -300,7 +343,9 @@ pub fn insert_shared(
         data_buffer_len,
         storage_limit,
         &storage_current,
-    )
+    );
+    enforce_execution_deadline(env_data.execution_deadline)?;
+    Ok(code)
 }
 
 /// Code which is common to `get` and `get_shared`
@@ -313,14 +358,11 @@ fn get_common(
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
 ) -> i32 {
-    let result = env_data
-        .api
-        .clone()
-        .runtime
-        .block_on(async move { storage.get(namespace, key).await });
+    let result =
+        block_on_within_deadline(env_data, async move { storage.get(namespace, key).await });
 
     match result {
-        Ok(Some(data)) => {
+        Some(Ok(Some(data))) => {
             match safely_write_data_back(&memory_view, &data, data_buffer, data_buffer_len) {
                 Ok(x) => x,
                 Err(e) => {
@@ -332,8 +374,9 @@ fn get_common(
                 }
             }
         }
-        Ok(None) => 0,
-        Err(_) => FunctionErrors::InternalApiError as i32,
+        Some(Ok(None)) => 0,
+        Some(Err(_)) => FunctionErrors::InternalApiError as i32,
+        None => FunctionErrors::TimeoutElapsed as i32,
     }
 }
 
@@ -344,14 +387,14 @@ pub fn get(
     key_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let storage = if let Some(storage) = &env_data.storage {
         storage
     } else {
-        return FunctionErrors::ApiNotConfigured as i32;
+        return Ok(FunctionErrors::ApiNotConfigured as i32);
     };
 
     let memory_view = match get_memory(&env, &store) {
@@ -361,13 +404,13 @@ pub fn get(
                 "{}: Memory error in storage_get: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
     safely_get_guest_string!(key, memory_view, key_buf, key_buf_len, env_data);
 
-    get_common(
+    let code = get_common(
         env_data,
         storage,
         &env_data.module.name,
@@ -375,7 +418,9 @@ pub fn get(
         memory_view,
         data_buffer,
         data_buffer_len,
-    )
+    );
+    enforce_execution_deadline(env_data.execution_deadline)?;
+    Ok(code)
 }
 
 /// Get data from a shared namespace in the storage system, if one is configured
@@ -387,20 +432,20 @@ pub fn get_shared(
     key_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let storage = if let Some(storage) = &env_data.storage {
         storage
     } else {
-        return FunctionErrors::ApiNotConfigured as i32;
+        return Ok(FunctionErrors::ApiNotConfigured as i32);
     };
 
     // Check if we have shared DBs at all, otherwise we just stop
     let shared_dbs = match &storage.shared_dbs {
         None => {
-            return FunctionErrors::OperationNotAllowed as i32;
+            return Ok(FunctionErrors::OperationNotAllowed as i32);
         }
         Some(x) => x,
     };
@@ -412,7 +457,7 @@ pub fn get_shared(
                 "{}: Memory error in storage_get_shared: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
@@ -433,12 +478,12 @@ pub fn get_shared(
         }
     };
     if !allowed {
-        return FunctionErrors::OperationNotAllowed as i32;
+        return Ok(FunctionErrors::OperationNotAllowed as i32);
     }
 
     safely_get_guest_string!(key, memory_view, key_buf, key_buf_len, env_data);
 
-    get_common(
+    let code = get_common(
         env_data,
         storage,
         &namespace,
@@ -446,7 +491,9 @@ pub fn get_shared(
         memory_view,
         data_buffer,
         data_buffer_len,
-    )
+    );
+    enforce_execution_deadline(env_data.execution_deadline)?;
+    Ok(code)
 }
 
 /// Fetch all the keys from the storage system and filter for a prefix
@@ -457,14 +504,14 @@ pub fn list_keys(
     prefix_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let storage = if let Some(storage) = &env_data.storage {
         storage
     } else {
-        return FunctionErrors::ApiNotConfigured as i32;
+        return Ok(FunctionErrors::ApiNotConfigured as i32);
     };
 
     let memory_view = match get_memory(&env, &store) {
@@ -474,13 +521,13 @@ pub fn list_keys(
                 "{}: Memory error in storage_list_keys: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
     safely_get_guest_string!(prefix, memory_view, prefix_buf, prefix_buf_len, env_data);
 
-    list_keys_common(
+    let code = list_keys_common(
         env_data,
         storage,
         env_data.module.name.clone(),
@@ -488,7 +535,9 @@ pub fn list_keys(
         memory_view,
         data_buffer,
         data_buffer_len,
-    )
+    );
+    enforce_execution_deadline(env_data.execution_deadline)?;
+    Ok(code)
 }
 
 /// Fetch all the keys from a shared namespace in the storage system and filter for a prefix
@@ -501,20 +550,20 @@ pub fn list_keys_shared(
     prefix_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let storage = if let Some(storage) = &env_data.storage {
         storage
     } else {
-        return FunctionErrors::ApiNotConfigured as i32;
+        return Ok(FunctionErrors::ApiNotConfigured as i32);
     };
 
     // Check if we have shared DBs at all, otherwise we just stop
     let shared_dbs = match &storage.shared_dbs {
         None => {
-            return FunctionErrors::OperationNotAllowed as i32;
+            return Ok(FunctionErrors::OperationNotAllowed as i32);
         }
         Some(x) => x,
     };
@@ -526,7 +575,7 @@ pub fn list_keys_shared(
                 "{}: Memory error in storage_list_keys: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
@@ -547,12 +596,12 @@ pub fn list_keys_shared(
         }
     };
     if !allowed {
-        return FunctionErrors::OperationNotAllowed as i32;
+        return Ok(FunctionErrors::OperationNotAllowed as i32);
     }
 
     safely_get_guest_string!(prefix, memory_view, prefix_buf, prefix_buf_len, env_data);
 
-    list_keys_common(
+    let code = list_keys_common(
         env_data,
         storage,
         namespace,
@@ -560,7 +609,9 @@ pub fn list_keys_shared(
         memory_view,
         data_buffer,
         data_buffer_len,
-    )
+    );
+    enforce_execution_deadline(env_data.execution_deadline)?;
+    Ok(code)
 }
 
 /// Code which is common to `list_keys` and `list_keys_shared`
@@ -573,14 +624,12 @@ pub fn list_keys_common(
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
 ) -> i32 {
-    let result = env_data
-        .api
-        .clone()
-        .runtime
-        .block_on(async move { storage.list_keys(&namespace, Some(prefix.as_str())).await });
+    let result = block_on_within_deadline(env_data, async move {
+        storage.list_keys(&namespace, Some(prefix.as_str())).await
+    });
 
     match result {
-        Ok(keys) => {
+        Some(Ok(keys)) => {
             let serialized_keys = match serde_json::to_string(&keys) {
                 Ok(sk) => sk,
                 Err(e) => {
@@ -608,13 +657,14 @@ pub fn list_keys_common(
                 }
             }
         }
-        Err(e) => {
+        Some(Err(e)) => {
             error!(
                 "Could not list keys for namespace {}: {e}",
                 &env_data.module.name
             );
             return FunctionErrors::InternalApiError as i32;
         }
+        None => FunctionErrors::TimeoutElapsed as i32,
     }
 }
 
@@ -629,23 +679,18 @@ pub fn delete_common(
     data_buffer_len: u32,
     storage_limit: LimitValue,
     storage_counter: &Arc<RwLock<u64>>,
-) -> i32 {
+) -> (i32, Replaced) {
     let deletion_result = match data_buffer_len {
         // This is a call just to get the size of the buffer, so we do storage.get and don't mess with storage counters
-        0 => env_data
-            .api
-            .clone()
-            .runtime
-            .block_on(async move { storage.get(&namespace, &key).await }),
+        0 => block_on_within_deadline(env_data, async move { storage.get(&namespace, &key).await }),
         // This is a call to delete the value, so we will do storage.delete, but first we need to check the storage limit
         _ => match storage_limit {
             LimitValue::Unlimited => {
                 // The storage is unlimited, so we don't update any counters and just proceed with the operation
-                env_data
-                    .api
-                    .clone()
-                    .runtime
-                    .block_on(async move { storage.delete(&namespace, &key).await })
+                block_on_within_deadline(
+                    env_data,
+                    async move { storage.delete(&namespace, &key).await },
+                )
             }
             LimitValue::Limited(_) => {
                 // for the "async move"
@@ -660,18 +705,16 @@ pub fn delete_common(
                     Ok(g) => g,
                     Err(e) => {
                         error!("Critical error getting a lock on used storage: {:?}", e);
-                        return FunctionErrors::InternalApiError as i32;
+                        return (FunctionErrors::InternalApiError as i32, None);
                     }
                 };
 
-                let result = env_data
-                    .api
-                    .clone()
-                    .runtime
-                    .block_on(async move { storage.delete(&namespace, &storage_key).await });
+                let result = block_on_within_deadline(env_data, async move {
+                    storage.delete(&namespace, &storage_key).await
+                });
                 // If the deletion went well, update counter for used storage.
                 // If the deletion failed for some reason, we don't update the counter and release the lock: no harm done.
-                if let Ok(Some(ref data)) = result {
+                if let Some(Ok(Some(ref data))) = result {
                     let key_len = key.as_bytes().len() as u64;
                     *storage_current = *storage_current - key_len - data.len() as u64;
                 }
@@ -682,40 +725,45 @@ pub fn delete_common(
 
     // Process the deletion result and return info to the caller
     match deletion_result {
-        Ok(data) => match data {
-            Some(data) => {
-                match safely_write_data_back(&memory_view, &data, data_buffer, data_buffer_len) {
-                    Ok(x) => x,
-                    Err(e) => {
-                        error!(
-                            "{}: Data write error in storage_delete: {:?}",
-                            env_data.module.name, e
-                        );
-                        e as i32
+        Some(Ok(data)) => {
+            let code = match &data {
+                Some(data) => {
+                    match safely_write_data_back(&memory_view, data, data_buffer, data_buffer_len) {
+                        Ok(x) => x,
+                        Err(e) => {
+                            error!(
+                                "{}: Data write error in storage_delete: {:?}",
+                                env_data.module.name, e
+                            );
+                            e as i32
+                        }
                     }
                 }
-            }
-            None => 0,
-        },
-        Err(_) => FunctionErrors::InternalApiError as i32,
+                None => 0,
+            };
+            // A call with an empty buffer only asks for the size of the value
+            (code, (data_buffer_len != 0).then_some(data))
+        }
+        Some(Err(_)) => (FunctionErrors::InternalApiError as i32, None),
+        None => (FunctionErrors::TimeoutElapsed as i32, None),
     }
 }
 
 /// Delete data from the storage system if one is configured
 pub fn delete(
-    env: FunctionEnvMut<Env>,
+    mut env: FunctionEnvMut<Env>,
     key_buf: WasmPtr<u8>,
     key_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let storage = if let Some(storage) = &env_data.storage {
         storage
     } else {
-        return FunctionErrors::ApiNotConfigured as i32;
+        return Ok(FunctionErrors::ApiNotConfigured as i32);
     };
 
     let memory_view = match get_memory(&env, &store) {
@@ -725,23 +773,31 @@ pub fn delete(
                 "{}: Memory error in storage_delete: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
     safely_get_guest_string!(key, memory_view, key_buf, key_buf_len, env_data);
 
-    delete_common(
+    let written = env_data
+        .side_effects
+        .is_some()
+        .then(|| SideEffect::StorageDelete { key: key.clone() });
+    let (code, replaced) = delete_common(
         env_data,
         storage,
         env_data.module.name.clone(),
-        key,
+        key.clone(),
         memory_view,
         data_buffer,
         data_buffer_len,
         env_data.module.storage_limit.clone(),
         &env_data.module.storage_current,
-    )
+    );
+    let deadline = env_data.execution_deadline;
+    record_storage_write(&mut env, key, written, replaced);
+    enforce_execution_deadline(deadline)?;
+    Ok(code)
 }
 
 /// Delete data from a shared namespace in the storage system, if one is configured
@@ -753,20 +809,20 @@ pub fn delete_shared(
     key_buf_len: u32,
     data_buffer: WasmPtr<u8>,
     data_buffer_len: u32,
-) -> i32 {
+) -> Result<i32, RuntimeError> {
     let store = env.as_store_ref();
     let env_data = env.data();
 
     let storage = if let Some(storage) = &env_data.storage {
         storage
     } else {
-        return FunctionErrors::ApiNotConfigured as i32;
+        return Ok(FunctionErrors::ApiNotConfigured as i32);
     };
 
     // Check if we have shared DBs at all, otherwise we just stop
     let shared_dbs = match &storage.shared_dbs {
         None => {
-            return FunctionErrors::OperationNotAllowed as i32;
+            return Ok(FunctionErrors::OperationNotAllowed as i32);
         }
         Some(x) => x,
     };
@@ -778,7 +834,7 @@ pub fn delete_shared(
                 "{}: Memory error in storage_delete: {:?}",
                 env_data.module.name, e
             );
-            return FunctionErrors::CouldNotGetAdequateMemory as i32;
+            return Ok(FunctionErrors::CouldNotGetAdequateMemory as i32);
         }
     };
 
@@ -797,23 +853,23 @@ pub fn delete_shared(
         Some(db) => db.config.rw.contains(&env_data.module.name),
     };
     if !allowed {
-        return FunctionErrors::OperationNotAllowed as i32;
+        return Ok(FunctionErrors::OperationNotAllowed as i32);
     }
 
     // Get storage limit and counter for the shared DB
     let (storage_limit, storage_current) = match &storage.shared_dbs {
         None => {
-            return FunctionErrors::ApiNotConfigured as i32;
+            return Ok(FunctionErrors::ApiNotConfigured as i32);
         }
         Some(shared_dbs) => match shared_dbs.get(&namespace) {
             None => {
-                return FunctionErrors::ApiNotConfigured as i32;
+                return Ok(FunctionErrors::ApiNotConfigured as i32);
             }
             Some(db) => (db.config.size_limit.clone(), db.used_storage.clone()),
         },
     };
 
-    delete_common(
+    let (code, _) = delete_common(
         env_data,
         storage,
         namespace,
@@ -823,5 +879,7 @@ pub fn delete_shared(
         data_buffer_len,
         storage_limit,
         &storage_current,
-    )
+    );
+    enforce_execution_deadline(env_data.execution_deadline)?;
+    Ok(code)
 }
@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::executor::Env;
+use crate::functions::FunctionErrors;
+
+/// What is put in place of a redacted value
+const REDACTED: &str = "[REDACTED]";
+
+/// Configuration for the calls that modules in test mode are not allowed to make.
+/// Whether or not this is configured, these calls are logged.
+#[derive(Deserialize, Clone)]
+pub struct TestModeJournalConfig {
+    /// If set, blocked calls are also stored in this storage namespace
+    pub storage_namespace: Option<String>,
+    /// If set, blocked calls succeed with an empty response instead of failing, so the module
+    /// continues as if they had been made
+    #[serde(default)]
+    pub synthetic_success: bool,
+    /// The responses that blocked calls to these functions succeed with, instead of failing.
+    /// Functions that return a code get the code, so their response must be a number.
+    /// The mapping is `{function -> response}`
+    #[serde(default)]
+    pub synthetic_responses: HashMap<String, String>,
+    /// Parameters whose name contains one of these is redacted before the call is journaled.
+    /// Parameters containing one of the module's secrets are always redacted.
+    #[serde(default = "default_redacted_fields")]
+    pub redacted_fields: Vec<String>,
+}
+
+fn default_redacted_fields() -> Vec<String> {
+    ["authorization", "password", "secret", "token", "api_key"]
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+/// A call that a module in test mode was not allowed to make
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BlockedCall {
+    pub module: String,
+    /// The message the module was processing
+    pub message_id: String,
+    pub function: String,
+    /// The parameters of the call, with secrets redacted. Parameters which are not JSON are
+    /// kept as a string.
+    pub params: Value,
+    /// When the call was made, in seconds since the epoch
+    pub timestamp: u64,
+    /// What the call returned instead of failing, if anything
+    pub synthetic_response: Option<String>,
+}
+
+/// Journal a call that a module in test mode is not allowed to make, and get the synthetic
+/// response to return instead of failing, if one is configured.
+fn journal_blocked_call(env: &Env, function: &str, params: &str) -> Option<String> {
+    let module = &env.module;
+    let config = module.test_mode_journal.as_ref();
+    let synthetic_response = config.and_then(|config| {
+        config
+            .synthetic_responses
+            .get(function)
+            .cloned()
+            .or_else(|| config.synthetic_success.then(String::new))
+    });
+
+    let redacted_fields = config.map_or_else(default_redacted_fields, |config| {
+        config.redacted_fields.clone()
+    });
+    let secrets: Vec<String> = module
+        .secrets
+        .iter()
+        .flatten()
+        .filter_map(|(_, secret)| String::from_utf8(secret.clone()).ok())
+        .filter(|secret| !secret.is_empty())
+        .collect();
+    let params = match serde_json::from_str(params) {
+        Ok(params) => params,
+        Err(_) => Value::String(params.to_string()),
+    };
+
+    let call = BlockedCall {
+        module: module.name.clone(),
+        message_id: env.message.id.clone(),
+        function: function.to_string(),
+        params: redact(params, &redacted_fields, &secrets),
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        synthetic_response: synthetic_response.clone(),
+    };
+
+    if let (Some(namespace), Some(storage)) = (
+        config.and_then(|config| config.storage_namespace.as_ref()),
+        &env.storage,
+    ) {
+        let key = format!("{}_{}", call.timestamp, uuid::Uuid::new_v4());
+        match serde_json::to_vec(&call) {
+            Ok(value) => {
+                let insert = storage.insert(namespace.clone(), key, value);
+                if let Err(e) = env.api.runtime.block_on(insert) {
+                    error!(
+                        "Failed to journal blocked call to {function} by {}: {e}",
+                        module.name
+                    );
+                }
+            }
+            Err(e) => error!("Failed to serialize blocked call to {function}: {e}"),
+        }
+    }
+
+    if let Err(e) = env.external_logging_system.log_blocked_call(call) {
+        error!("Logging system is not working!!: {:?}", e);
+    }
+
+    synthetic_response
+}
+
+/// Journal a blocked call to a function that returns a code, and get the code it returns
+/// instead: its synthetic response, `0` if the response is empty, or
+/// `FunctionErrors::TestMode` if none is configured
+pub(super) fn blocked_call_code(
+    env: &Env,
+    function: &str,
+    params: &str,
+) -> Result<i32, FunctionErrors> {
+    let response = journal_blocked_call(env, function, params).ok_or(FunctionErrors::TestMode)?;
+    if response.trim().is_empty() {
+        return Ok(0);
+    }
+    response.trim().parse().map_err(|_| {
+        error!("Synthetic response [{response}] to {function} is not a valid return code");
+        FunctionErrors::InternalApiError
+    })
+}
+
+/// Journal a blocked call to a function that returns data, and get the data it returns
+/// instead, or `FunctionErrors::TestMode` if no synthetic response is configured
+pub(super) fn blocked_call_data(
+    env: &Env,
+    function: &str,
+    params: &str,
+) -> Result<String, FunctionErrors> {
+    journal_blocked_call(env, function, params).ok_or(FunctionErrors::TestMode)
+}
+
+/// Replace the values of fields whose name contains one of `redacted_fields`, and strings
+/// which contain one of `secrets`
+fn redact(value: Value, redacted_fields: &[String], secrets: &[String]) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| {
+                    let lowercase = name.to_lowercase();
+                    let value = if redacted_fields
+                        .iter()
+                        .any(|field| lowercase.contains(&field.to_lowercase()))
+                    {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact(value, redacted_fields, secrets)
+                    };
+                    (name, value)
+                })
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| redact(value, redacted_fields, secrets))
+                .collect(),
+        ),
+        Value::String(s) if secrets.iter().any(|secret| s.contains(secret.as_str())) => {
+            Value::String(REDACTED.to_string())
+        }
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::executor::execute_standalone;
+    use crate::storage::Storage;
+    use crate::testing::fixtures::{api, message, standalone_context, wat_module};
+
+    /// A module in test mode which adds a user to a repository, and fails unless the call
+    /// returns 7
+    const ADD_USER: &str = r#"(module
+        (import "env" "github_add_user_to_repo" (func $add_user (param i32 i32) (result i32)))
+        (memory (export "memory") 1)
+        (data (i32.const 0) "{\"user\":\"alice\",\"token\":\"abc\"}")
+        (func (export "entrypoint") (result i32)
+            (i32.ne (call $add_user (i32.const 0) (i32.const 30)) (i32.const 7))))"#;
+
+    /// A module in test mode which creates a deploy key, and responds with what the call
+    /// returned
+    const CREATE_DEPLOY_KEY: &str = r#"(module
+        (import "env" "github_create_deploy_key" (func $create_deploy_key (param i32 i32 i32 i32) (result i32)))
+        (import "env" "set_response" (func $set_response (param i32 i32)))
+        (memory (export "memory") 1)
+        (data (i32.const 0) "{}")
+        (func (export "entrypoint") (result i32)
+            (local $len i32)
+            (local.set $len (call $create_deploy_key (i32.const 0) (i32.const 2) (i32.const 1024) (i32.const 1024)))
+            (if (i32.lt_s (local.get $len) (i32.const 0)) (then (return (i32.const 1))))
+            (call $set_response (i32.const 1024) (local.get $len))
+            i32.const 0))"#;
+
+    /// Run `wat` in test mode with `journal` configured, and get its response and the storage
+    /// it ran against
+    fn run_blocked(
+        wat: &str,
+        journal: TestModeJournalConfig,
+    ) -> (Result<Option<String>, String>, String, Arc<Storage>) {
+        let mut module = wat_module("blocked", wat);
+        module.test_mode = true;
+        module.test_mode_journal = Some(journal);
+        let message = message(&module.logtype, b"");
+        let message_id = message.id.clone();
+        let storage = Arc::new(Storage::new_in_memory());
+        let (context, _logs) = standalone_context(api(), storage.clone());
+
+        let execution = execute_standalone(message, Arc::new(module), context)
+            .unwrap_or_else(|e| panic!("{e}"));
+        (
+            execution.response.map_err(|e| e.to_string()),
+            message_id,
+            storage,
+        )
+    }
+
+    fn journal(synthetic_responses: &[(&str, &str)]) -> TestModeJournalConfig {
+        TestModeJournalConfig {
+            storage_namespace: Some("journal".to_string()),
+            synthetic_success: false,
+            synthetic_responses: synthetic_responses
+                .iter()
+                .map(|(function, response)| (function.to_string(), response.to_string()))
+                .collect(),
+            redacted_fields: default_redacted_fields(),
+        }
+    }
+
+    /// The calls journaled in `storage`
+    fn journaled(storage: &Storage) -> Vec<BlockedCall> {
+        let entries = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(storage.fetch_all("journal", None))
+            .unwrap();
+        entries
+            .into_iter()
+            .map(|(_, call)| serde_json::from_slice(&call.unwrap()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn blocked_calls_are_journaled_in_storage() {
+        let (response, message_id, storage) =
+            run_blocked(ADD_USER, journal(&[("github_add_user_to_repo", "7")]));
+        assert_eq!(response, Ok(None));
+
+        let journaled = journaled(&storage);
+        assert_eq!(journaled.len(), 1);
+        let call = &journaled[0];
+        assert_eq!(call.module, "blocked.wasm");
+        assert_eq!(call.message_id, message_id);
+        assert_eq!(call.function, "github_add_user_to_repo");
+        assert_eq!(call.params, json!({"user": "alice", "token": REDACTED}));
+        assert_eq!(call.synthetic_response, Some("7".to_string()));
+    }
+
+    #[test]
+    fn blocked_calls_return_their_synthetic_response() {
+        let (response, _, _) = run_blocked(
+            CREATE_DEPLOY_KEY,
+            journal(&[("github_create_deploy_key", r#"{"id":1}"#)]),
+        );
+        assert_eq!(response, Ok(Some(r#"{"id":1}"#.to_string())));
+
+        // Without a synthetic response, the call fails
+        let (response, _, storage) = run_blocked(CREATE_DEPLOY_KEY, journal(&[]));
+        assert!(response.is_err());
+        assert_eq!(journaled(&storage).len(), 1);
+    }
+
+    #[test]
+    fn secrets_and_sensitive_fields_are_redacted() {
+        let params = json!({
+            "user": "alice",
+            "Authorization": "Bearer abc",
+            "body": {
+                "note": "uses hunter2 to log in",
+                "items": ["hunter2", "fine", 3],
+                "github_token": {"nested": "value"},
+            },
+        });
+        let redacted = redact(params, &default_redacted_fields(), &["hunter2".to_string()]);
+        assert_eq!(
+            redacted,
+            json!({
+                "user": "alice",
+                "Authorization": REDACTED,
+                "body": {
+                    "note": REDACTED,
+                    "items": [REDACTED, "fine", 3],
+                    "github_token": REDACTED,
+                },
+            })
+        );
+    }
+}
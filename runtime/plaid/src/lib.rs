@@ -13,13 +13,20 @@ pub mod cache;
 pub mod config;
 pub mod cryptography;
 pub mod data;
+pub mod dead_letter;
+pub mod deduplication;
 pub mod executor;
+pub mod filters;
 pub mod functions;
 pub mod loader;
 pub mod logging;
 pub mod metrics;
 pub mod performance;
+pub mod recording;
 pub mod storage;
+pub mod testing;
+pub mod trace;
+pub mod write_ahead_log;
 
 /// Defines methods to authenticate to AWS with
 #[cfg(feature = "aws")]
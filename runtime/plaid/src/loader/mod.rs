@@ -1,5 +1,7 @@
 mod errors;
 mod limits;
+mod reload;
+mod retry;
 mod signing;
 mod utils;
 
@@ -13,14 +15,18 @@ use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::fs::{self};
 use std::net::SocketAddr;
+use std::num::NonZeroU32;
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 use futures_util::stream::{self, StreamExt};
 
+pub use reload::{watch_for_module_changes, LiveModules};
+pub use retry::{RetryInfo, RetryPolicy, RetryableError};
 pub use utils::cost_function;
 use utils::{
-    get_module_computation_limit, get_module_page_count, get_module_persistent_storage_limit,
-    read_and_configure_secrets, read_and_parse_modules,
+    get_module_computation_limit, get_module_execution_timeout, get_module_page_count,
+    get_module_persistent_storage_limit, read_and_configure_secrets, read_and_parse_modules,
 };
 use wasmer::sys::{NativeEngineExt, Target};
 
@@ -35,7 +41,13 @@ use wasmer::sys::Cranelift;
 use wasmer::{sys::BaseTunables, Engine, Module, Pages};
 use wasmer_middlewares::Metering;
 
-use crate::functions::is_known_api_function;
+use crate::cryptography::hash::sha256_hex;
+use crate::executor::{
+    BatchConfig, ExecutionLimits, ExportMutableGlobals, InstancePool, ModuleThrottle, RateLimit,
+    RateLimiter, Shadow, DEFAULT_CUSTOM_METRIC_SERIES_LIMIT,
+};
+use crate::filters::MessageFilter;
+use crate::functions::{is_known_api_function, TestModeJournalConfig};
 use crate::storage::Storage;
 
 /// Limit imposed on some resource
@@ -125,6 +137,13 @@ pub struct Configuration {
     pub memory_page_count: LimitedAmount,
     /// How many bytes a module is allowed to store in persistent storage
     pub storage_size: LimitableAmount,
+    /// How many seconds a module is allowed to run for. Calls to external APIs are cut short
+    /// once the time is up, and the module is stopped as soon as the call returns.
+    /// The time is only checked when the module calls a host function, so a module which
+    /// computes without calling any is only stopped by its computation limit.
+    /// If this is not set, there is no limit on how long a module can run.
+    #[serde(default, deserialize_with = "deserialize_optional_limited_amount")]
+    pub execution_timeout: Option<LimitedAmount>,
     /// The secrets that are available to modules. No actual secrets should be included in this map.
     /// Instead, the values here should be names of secrets whose values are present in
     /// the secrets file. This makes it possible for to check in your Plaid config without exposing secrets.
@@ -173,6 +192,113 @@ pub struct Configuration {
     /// Defaults to `true` if not provided.
     #[serde(default = "default_panic_on_load_failure")]
     pub panic_on_module_load_failure: bool,
+    /// If this value is set, Plaid checks `module_dir` every this many seconds and hot reloads
+    /// any module that was added, changed or removed, or whose configuration changed. A reload
+    /// can also be triggered at any time by sending SIGHUP to Plaid.
+    pub module_reload_interval: Option<u64>,
+    /// Per-module policies for retrying messages that the module failed to process.
+    /// Modules without a policy are not retried.
+    /// The mapping is `{rule_file_name -> retry_policy}`
+    #[serde(default)]
+    pub retry_policies: HashMap<String, RetryPolicy>,
+    /// Per-module filters on the messages of the module's log type. A module is only run on
+    /// the messages which match its filter, so the others do not cost a WASM instantiation.
+    /// The mapping is `{rule_file_name -> filter}`
+    #[serde(default)]
+    pub module_filters: HashMap<String, MessageFilter>,
+    /// How many ready-to-run instances to keep for each module. Instances of these modules are
+    /// reset and reused between messages instead of being instantiated for every message,
+    /// which saves time for small modules that run often.
+    /// The mapping is `{rule_file_name -> pool_size}`
+    #[serde(default)]
+    pub instance_pools: HashMap<String, usize>,
+    /// Per-module limits on concurrent executions and on the rate of executions.
+    /// The mapping is `{rule_file_name -> limits}`
+    #[serde(default)]
+    pub execution_limits: HashMap<String, ExecutionLimits>,
+    /// How many distinct label sets each of a module's custom metrics can have. Modules
+    /// which are not listed here are limited to 100.
+    /// The mapping is `{rule_file_name -> limit}`
+    #[serde(default)]
+    pub custom_metric_series_limits: HashMap<String, usize>,
+    /// How many structured logs a module can send to the logging backends. Modules which
+    /// are not listed here can send 100 logs per minute.
+    /// The mapping is `{rule_file_name -> rate_limit}`
+    #[serde(default)]
+    pub module_log_rate_limits: HashMap<String, RateLimit>,
+    /// The modules each module is allowed to invoke synchronously. Modules which are not
+    /// listed here cannot invoke any modules.
+    /// The mapping is `{rule_file_name -> [rule_file_names it can invoke]}`
+    #[serde(default)]
+    pub module_invocations: HashMap<String, Vec<String>>,
+    /// Modules to run as shadows of other modules. A shadow runs in test mode on every
+    /// message its primary module processes, after the primary, and how the two runs
+    /// compare is logged. Shadows do not process messages on their own, and are only loaded
+    /// at boot: hot reloading does not pick up changes to them.
+    /// The mapping is `{shadow_rule_file_name -> primary_rule_file_name}`
+    #[serde(default)]
+    pub shadow_modules: HashMap<String, String>,
+    /// Where the calls that modules in test mode are not allowed to make are journaled, and
+    /// whether they return a synthetic response. These calls are logged even if this is not set.
+    pub test_mode_journal: Option<TestModeJournalConfig>,
+    /// Modules which receive the messages of their log type in batches, through the batch
+    /// entrypoint, instead of one at a time. This saves a WASM instantiation per message for
+    /// log types with a lot of traffic.
+    /// The mapping is `{rule_file_name -> batching}`
+    #[serde(default)]
+    pub batch_delivery: HashMap<String, BatchConfig>,
+    /// The loading configuration as it was written, if it was read from the configuration
+    /// files. Used to tell which modules' configuration changed when reloading them.
+    #[serde(skip)]
+    pub raw: Option<toml::Value>,
+}
+
+impl Configuration {
+    /// A hash of the parts of the loading configuration which can apply to a module: all of
+    /// it, except the settings of other modules. Settings by log type are kept, so changing
+    /// one of them changes the hash of every module.
+    pub fn module_config_hash(&self, filename: &str) -> String {
+        /// Remove the settings of other modules, which are keyed by their file name, and the
+        /// tables left empty, which are the same as missing ones
+        fn strip_other_modules(value: &mut toml::Value, filename: &str) {
+            match value {
+                toml::Value::Table(table) => {
+                    for (_, value) in table.iter_mut() {
+                        strip_other_modules(value, filename);
+                    }
+                    let stripped: Vec<String> = table
+                        .iter()
+                        .filter(|(key, value)| {
+                            key.ends_with(".wasm") && *key != filename
+                                || value.as_table().is_some_and(|table| table.is_empty())
+                        })
+                        .map(|(key, _)| key.clone())
+                        .collect();
+                    for key in stripped {
+                        table.remove(&key);
+                    }
+                }
+                toml::Value::Array(values) => {
+                    for value in values {
+                        strip_other_modules(value, filename);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut raw = self.raw.clone().unwrap_or(toml::Value::Boolean(false));
+        strip_other_modules(&mut raw, filename);
+        sha256_hex(&serde_json::to_vec(&raw).unwrap_or_default())
+    }
+}
+
+/// How many structured logs a module can send if it has no configured rate limit
+fn default_module_log_rate_limit() -> RateLimit {
+    RateLimit {
+        executions: NonZeroU32::new(100).unwrap(),
+        period_secs: 60,
+    }
 }
 
 fn default_panic_on_load_failure() -> bool {
@@ -208,6 +334,16 @@ where
     })
 }
 
+/// Deserializer for an optional LimitedAmount where none of the provided values can be 0.
+fn deserialize_optional_limited_amount<'de, D>(
+    deserializer: D,
+) -> Result<Option<LimitedAmount>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    deserialize_limited_amount(deserializer).map(Some)
+}
+
 /// This structure defines the parameters required to validate signatures for modules.
 #[derive(Deserialize)]
 pub struct ModuleSigningConfiguration {
@@ -320,6 +456,8 @@ pub struct PlaidModule {
     pub computation_limit: u64,
     /// The maximum number of memory pages allowed to be mapped for the module
     pub page_limit: u32,
+    /// The maximum wall-clock time the module is allowed to run for, if any
+    pub execution_timeout: Option<Duration>,
     /// The number of bytes the module is currently saving in persistent storage
     pub storage_current: Arc<RwLock<u64>>,
     /// The maximum number of bytes the module can save in persistent storage
@@ -332,6 +470,35 @@ pub struct PlaidModule {
     pub persistent_response: Option<PersistentResponse>,
     /// If the module is in test mode, meaning it should not be allowed to cause side effects
     pub test_mode: bool,
+    /// Hex encoded SHA256 hash of the module's bytes. Used to detect changes when reloading.
+    pub bytes_hash: String,
+    /// Hex encoded SHA256 hash of the module's loading configuration. Used to detect changes
+    /// when reloading.
+    pub config_hash: String,
+    /// How messages that the module failed to process are retried, if at all
+    pub retry_policy: Option<RetryPolicy>,
+    /// The messages of the module's log type that the module is subscribed to. If this is
+    /// `None`, the module is run on all of them.
+    pub filter: Option<MessageFilter>,
+    /// Idle instances of the module which can be reused, if pooling is enabled for it
+    pub instance_pool: Option<InstancePool>,
+    /// Enforces the module's execution limits, if it has any
+    pub throttle: Option<Arc<ModuleThrottle>>,
+    /// How many distinct label sets each of the module's custom metrics can have
+    pub custom_metric_series_limit: usize,
+    /// Limits how many structured logs the module sends to the logging backends
+    pub log_rate_limiter: RateLimiter,
+    /// The modules this module is allowed to invoke synchronously
+    pub invocable_modules: Vec<String>,
+    /// A new version of this module which runs in test mode on the same messages, so the two
+    /// can be compared
+    pub shadow: Option<Shadow>,
+    /// What happens to the calls the module is not allowed to make in test mode, if that is
+    /// configured. Only set for modules in test mode.
+    pub test_mode_journal: Option<TestModeJournalConfig>,
+    /// How the messages of the module's log type are batched, if the module receives them in
+    /// batches
+    pub batching: Option<BatchConfig>,
 }
 
 impl std::fmt::Display for PlaidModule {
@@ -363,6 +530,7 @@ impl PlaidModule {
         computation_amount: &LimitedAmount,
         memory_page_count: &LimitedAmount,
         storage_amount: &LimitableAmount,
+        execution_timeout: &Option<LimitedAmount>,
         module_bytes: Vec<u8>,
         log_type: &str,
         test_mode: bool,
@@ -379,6 +547,9 @@ impl PlaidModule {
         let storage_limit =
             get_module_persistent_storage_limit(storage_amount, &filename, log_type);
 
+        // Get the execution timeout, if one is configured
+        let execution_timeout = get_module_execution_timeout(execution_timeout, filename, log_type);
+
         let metering = Arc::new(Metering::new(computation_limit, cost_function));
 
         // Configure module tunables - this includes our computation limit and page count
@@ -391,12 +562,14 @@ impl PlaidModule {
             CompilerBackend::Cranelift => {
                 let mut compiler = Cranelift::default();
                 compiler.push_middleware(metering);
+                compiler.push_middleware(Arc::new(ExportMutableGlobals));
                 compiler.into()
             }
             #[cfg(feature = "llvm")]
             CompilerBackend::LLVM => {
                 let mut compiler = LLVM::default();
                 compiler.push_middleware(metering);
+                compiler.push_middleware(Arc::new(ExportMutableGlobals));
                 compiler.into()
             }
         };
@@ -404,7 +577,7 @@ impl PlaidModule {
         engine.set_tunables(tunables);
 
         // Compile the module using the middleware and tunables we just set up
-        let mut module = Module::new(&engine, module_bytes).map_err(Errors::CompileError)?;
+        let mut module = Module::new(&engine, &module_bytes).map_err(Errors::CompileError)?;
         module.set_name(&filename);
 
         // Validate that every import the module requires can be satisfied
@@ -427,6 +600,7 @@ impl PlaidModule {
         }
 
         let storage_current = Arc::new(RwLock::new(0));
+        let bytes_hash = sha256_hex(&module_bytes);
 
         Ok(Self {
             name: filename.to_string(),
@@ -437,20 +611,75 @@ impl PlaidModule {
             storage_current,
             storage_limit,
             page_limit,
+            execution_timeout,
             accessory_data: None,
             secrets: None,
             persistent_response: None,
             test_mode,
+            bytes_hash,
+            config_hash: String::new(),
+            retry_policy: None,
+            filter: None,
+            instance_pool: None,
+            throttle: None,
+            custom_metric_series_limit: DEFAULT_CUSTOM_METRIC_SERIES_LIMIT,
+            log_rate_limiter: RateLimiter::new(&default_module_log_rate_limit()),
+            invocable_modules: vec![],
+            shadow: None,
+            test_mode_journal: None,
+            batching: None,
         })
     }
 
+    /// Compile a module on its own, outside of loading, with fixed limits and unlimited storage.
+    /// No loading configuration is applied: the module has no secrets, accessory data, retry
+    /// policy or filter. This is used by the [`TestRuntime`](crate::testing::TestRuntime), and
+    /// for the empty module that idle pooled instances point to.
+    pub(crate) fn compile_standalone(
+        filename: &str,
+        log_type: &str,
+        module_bytes: Vec<u8>,
+        computation_limit: u64,
+        page_limit: u32,
+    ) -> Result<Self, Errors> {
+        let limited = |default| LimitedAmount {
+            default,
+            log_type: HashMap::new(),
+            module_overrides: HashMap::new(),
+        };
+        let storage_amount = LimitableAmount {
+            default: LimitValue::Unlimited,
+            log_type: HashMap::new(),
+            module_overrides: HashMap::new(),
+        };
+
+        #[cfg(feature = "cranelift")]
+        let compiler_backend = CompilerBackend::Cranelift;
+        #[cfg(all(feature = "llvm", not(feature = "cranelift")))]
+        let compiler_backend = CompilerBackend::LLVM;
+
+        Self::compile(
+            filename,
+            &limited(computation_limit),
+            &limited(page_limit as u64),
+            &storage_amount,
+            &None,
+            module_bytes,
+            log_type,
+            false,
+            &compiler_backend,
+        )
+    }
+
     fn log_load_info(&self) {
         let storage_current_bytes = *self.storage_current.read().unwrap();
         info!(
-            "Name: [{}] Computation Limit: [{}] Memory Limit: [{} pages] Storage: [{}/{} bytes used] Log Type: [{}]. Test Mode: [{}]",
+            "Name: [{}] Computation Limit: [{}] Memory Limit: [{} pages] Execution Timeout: [{}] Storage: [{}/{} bytes used] Log Type: [{}]. Test Mode: [{}]",
             self.name,
             self.computation_limit,
             self.page_limit,
+            self.execution_timeout
+                .map_or("None".to_string(), |timeout| format!("{}s", timeout.as_secs())),
             storage_current_bytes,
             self.storage_limit,
             self.logtype,
@@ -485,12 +714,26 @@ impl PlaidModules {
         self.modules.clone()
     }
 
+    /// Get the channel of modules which operate on a given log type.
+    pub fn get_channel(&self, log_type: &str) -> Option<&Vec<Arc<PlaidModule>>> {
+        self.channels.get(log_type)
+    }
+
     /// Get a particular module by name. This makes the API ergonomic enough
     /// we don't need to expose the underlying data structures.
     pub fn get_module(&self, name: &str) -> Option<Arc<PlaidModule>> {
         self.modules.get(name).cloned()
     }
 
+    /// Add a module to its log type's channel and to the set of modules by name
+    pub(crate) fn insert(&mut self, module: Arc<PlaidModule>) {
+        self.channels
+            .entry(module.logtype.clone())
+            .or_default()
+            .push(module.clone());
+        self.modules.insert(module.name.clone(), module);
+    }
+
     /// Get a mapping between module names and log types.
     pub fn get_module_logtypes(&self) -> HashMap<String, String> {
         self.modules
@@ -604,34 +847,7 @@ pub async fn load(
                 }
             }
 
-            let type_ = if let Some(type_) = config.log_type_overrides.get(&filename) {
-                type_.to_string()
-            } else {
-                let type_: Vec<&str> = filename.split('_').collect();
-                type_[0].to_string()
-            };
-
-            let filename_without_ext = filename.trim_end_matches(".wasm");
-            if !config.log_type_overrides.contains_key(&filename) && filename_without_ext != type_ {
-                warn!(
-                    "Module [{filename}] assigned log type [{type_}] (inferred from first segment before '_'). \
-                     If you expected log type [{filename_without_ext}], add to [loading.log_type_overrides]: \
-                     \"{filename}\" = \"{filename_without_ext}\""
-                );
-            }
-
-            let test_mode = config.test_mode && !config.test_mode_exemptions.contains(&filename);
-            
-            let mut plaid_module = match PlaidModule::compile(
-                &filename,
-                &config.computation_amount,
-                &config.memory_page_count,
-                &config.storage_size,
-                module_bytes,
-                &type_,
-                test_mode,
-                &config.compiler_backend,
-            ) {
+            let plaid_module = match build_module(config, &filename, module_bytes, &byte_secrets) {
                 Ok(pm) => pm,
                 Err(e) => {
                     if config.panic_on_module_load_failure {
@@ -643,16 +859,6 @@ pub async fn load(
                 }
             };
 
-            let persistent_response = config
-                .persistent_response_size
-                .get(&filename)
-                .copied()
-                .map(PersistentResponse::new);
-
-            plaid_module.persistent_response = persistent_response;
-            plaid_module.secrets = byte_secrets.get(&type_).map(|x| x.clone());
-            plaid_module.accessory_data = module_accessory_data(config, &plaid_module.name, &type_);
-
             info!("Finished loading module [{filename}]");
             Some(plaid_module)
         })
@@ -666,25 +872,128 @@ pub async fn load(
         None => loaded_modules,
     };
 
-    for module in loaded_modules {
-        module.log_load_info();
-
-        let type_ = module.logtype.clone();
-        let filename = module.name.clone();
-        let plaid_module = Arc::new(module);
+    // Shadows are attached to their primary module instead of processing messages on their own
+    let (shadows, loaded_modules): (Vec<_>, Vec<_>) = loaded_modules
+        .into_iter()
+        .partition(|module| config.shadow_modules.contains_key(&module.name));
+    let mut shadows: HashMap<String, Arc<PlaidModule>> = shadows
+        .into_iter()
+        .map(|shadow| {
+            shadow.log_load_info();
+            (
+                config.shadow_modules[&shadow.name].clone(),
+                Arc::new(shadow),
+            )
+        })
+        .collect();
 
-        if let Some(mods) = modules.channels.get_mut(&type_) {
-            mods.push(plaid_module.clone());
-        } else {
-            modules.channels.insert(type_, vec![plaid_module.clone()]);
+    for mut module in loaded_modules {
+        module.shadow = shadows.remove(&module.name).map(Shadow::new);
+        if let Some(shadow) = &module.shadow {
+            info!(
+                "Module [{}] is shadowed by [{}]",
+                module.name, shadow.module.name
+            );
         }
+        module.log_load_info();
+        modules.insert(Arc::new(module));
+    }
 
-        modules.modules.insert(filename, plaid_module);
+    for (primary, shadow) in shadows {
+        warn!(
+            "Shadow module [{}] will not run because its primary module [{primary}] is not loaded",
+            shadow.name
+        );
     }
 
     Ok(modules)
 }
 
+/// Get the log type of a module: either its override, or the first segment of its filename
+fn module_log_type(config: &Configuration, filename: &str) -> String {
+    if let Some(type_) = config.log_type_overrides.get(filename) {
+        type_.to_string()
+    } else {
+        let type_: Vec<&str> = filename.split('_').collect();
+        type_[0].to_string()
+    }
+}
+
+/// Compile a module from its bytes and attach everything the configuration gives it: log type,
+/// test mode, persistent response, secrets and accessory data.
+fn build_module(
+    config: &Configuration,
+    filename: &str,
+    module_bytes: Vec<u8>,
+    byte_secrets: &HashMap<String, HashMap<String, Vec<u8>>>,
+) -> Result<PlaidModule, Errors> {
+    let type_ = module_log_type(config, filename);
+
+    let filename_without_ext = filename.trim_end_matches(".wasm");
+    if !config.log_type_overrides.contains_key(filename) && filename_without_ext != type_ {
+        warn!(
+            "Module [{filename}] assigned log type [{type_}] (inferred from first segment before '_'). \
+             If you expected log type [{filename_without_ext}], add to [loading.log_type_overrides]: \
+             \"{filename}\" = \"{filename_without_ext}\""
+        );
+    }
+
+    // A shadow always runs in test mode, on the messages of its primary module
+    let shadow_of = config.shadow_modules.get(filename);
+    let type_ = shadow_of.map_or(type_, |primary| module_log_type(config, primary));
+    let test_mode = shadow_of.is_some()
+        || config.test_mode && !config.test_mode_exemptions.iter().any(|m| m == filename);
+
+    let mut plaid_module = PlaidModule::compile(
+        filename,
+        &config.computation_amount,
+        &config.memory_page_count,
+        &config.storage_size,
+        &config.execution_timeout,
+        module_bytes,
+        &type_,
+        test_mode,
+        &config.compiler_backend,
+    )?;
+
+    let persistent_response = config
+        .persistent_response_size
+        .get(filename)
+        .copied()
+        .map(PersistentResponse::new);
+
+    plaid_module.persistent_response = persistent_response;
+    plaid_module.secrets = byte_secrets.get(&type_).map(|x| x.clone());
+    plaid_module.accessory_data = module_accessory_data(config, &plaid_module.name, &type_);
+    plaid_module.retry_policy = config.retry_policies.get(filename).cloned();
+    plaid_module.filter = config.module_filters.get(filename).cloned();
+    plaid_module.instance_pool = config
+        .instance_pools
+        .get(filename)
+        .filter(|size| **size > 0)
+        .map(|size| InstancePool::new(*size, plaid_module.computation_limit));
+    plaid_module.throttle = config
+        .execution_limits
+        .get(filename)
+        .map(|limits| Arc::new(ModuleThrottle::new(limits.clone())));
+    if let Some(limit) = config.custom_metric_series_limits.get(filename) {
+        plaid_module.custom_metric_series_limit = *limit;
+    }
+    if let Some(rate_limit) = config.module_log_rate_limits.get(filename) {
+        plaid_module.log_rate_limiter = RateLimiter::new(rate_limit);
+    }
+    if let Some(modules) = config.module_invocations.get(filename) {
+        plaid_module.invocable_modules = modules.clone();
+    }
+    if test_mode {
+        plaid_module.test_mode_journal = config.test_mode_journal.clone();
+    }
+    plaid_module.batching = config.batch_delivery.get(filename).cloned();
+    plaid_module.config_hash = config.module_config_hash(filename);
+
+    Ok(plaid_module)
+}
+
 /// Read the loading configuration and some data about the module, and return the optional
 /// accessory data that the module will have access to.
 fn module_accessory_data(
@@ -0,0 +1,401 @@
+use std::collections::HashSet;
+use std::fs;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::time::{interval, Interval, MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
+
+use super::errors::Errors;
+use super::signing::check_module_signatures;
+use super::utils::{read_and_configure_secrets, read_and_parse_modules};
+use super::{build_module, Configuration, PlaidModule, PlaidModules};
+use crate::cache::Cache;
+use crate::cryptography::hash::sha256_hex;
+use crate::storage::Storage;
+
+/// The set of modules Plaid is currently running. Modules can be hot reloaded, so everything that
+/// needs them (the executor, the GET handlers) goes through this handle instead of keeping its own copy.
+pub struct LiveModules {
+    current: RwLock<Arc<PlaidModules>>,
+}
+
+impl LiveModules {
+    pub fn new(modules: PlaidModules) -> Self {
+        Self {
+            current: RwLock::new(Arc::new(modules)),
+        }
+    }
+
+    /// Get the modules that are currently loaded. The returned snapshot is not affected by
+    /// later reloads, so a message is always processed by a consistent set of modules.
+    pub fn current(&self) -> Arc<PlaidModules> {
+        self.current
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Atomically swap in a new set of modules
+    fn replace(&self, modules: PlaidModules) {
+        *self.current.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(modules);
+    }
+}
+
+/// The result of comparing `module_dir` against the modules currently loaded
+struct ModuleChanges {
+    /// Modules that are ready to be used: unchanged, updated and failed-to-update ones
+    modules: PlaidModules,
+    /// Modules which were not loaded before. They still need their storage usage counted.
+    added: Vec<PlaidModule>,
+}
+
+/// Re-read `module_dir` and work out the new set of modules. Modules whose bytes and configuration
+/// have not changed are carried over as they are. New and changed modules go through the same
+/// signature checks, compilation and limit resolution as at boot. Modules whose file was removed
+/// are dropped.
+///
+/// Unlike at boot, a failure never stops Plaid: a changed module which fails to load keeps running
+/// its previous version and a new module which fails to load is skipped.
+///
+/// Returns `None` if nothing changed.
+fn read_module_changes(config: &Configuration, current: &PlaidModules) -> Option<ModuleChanges> {
+    let entries = match fs::read_dir(&config.module_dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            error!(
+                "Failed to read module directory [{}] while reloading modules: {e}",
+                config.module_dir
+            );
+            return None;
+        }
+    };
+    let byte_secrets = read_and_configure_secrets(&config.secrets);
+
+    let mut changes = ModuleChanges {
+        modules: PlaidModules::default(),
+        added: vec![],
+    };
+    let mut changed = false;
+    let mut seen = HashSet::new();
+
+    for entry in entries {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                error!("Bad entry in modules directory - skipping. Error: {e}");
+                continue;
+            }
+        };
+
+        let (filename, module_bytes) = match read_and_parse_modules(&entry) {
+            Ok(filename_and_bytes) => filename_and_bytes,
+            // Not a module: nothing to reload
+            Err(Errors::InvalidFileType(..)) => continue,
+            Err(e) => {
+                error!(
+                    "Failed to parse module at [{}]: {e}. Skipping reload",
+                    entry.path().to_string_lossy()
+                );
+                // Keep running what we have rather than unloading a module we could not read
+                if let Some(previous) = current.get_module(&entry.file_name().to_string_lossy()) {
+                    seen.insert(previous.name.clone());
+                    changes.modules.insert(previous);
+                }
+                continue;
+            }
+        };
+        // Shadows are only loaded at boot, attached to their primary module
+        if config.shadow_modules.contains_key(&filename) {
+            continue;
+        }
+        seen.insert(filename.clone());
+
+        let previous = current.get_module(&filename);
+        if let Some(previous) = &previous {
+            if previous.bytes_hash == sha256_hex(&module_bytes)
+                && previous.config_hash == config.module_config_hash(&filename)
+            {
+                changes.modules.insert(previous.clone());
+                continue;
+            }
+        }
+
+        let loaded = match &config.module_signing {
+            Some(signing) => check_module_signatures(signing, &filename, &module_bytes),
+            None => Ok(()),
+        }
+        .and_then(|_| build_module(config, &filename, module_bytes, &byte_secrets));
+
+        match (loaded, previous) {
+            (Ok(mut module), Some(previous)) => {
+                // The new version keeps the storage counter and persistent response of the old
+                // one, since both are shared with any execution of the old version still in flight.
+                module.storage_current = previous.storage_current.clone();
+                if let (Some(new), Some(old)) = (
+                    &mut module.persistent_response,
+                    &previous.persistent_response,
+                ) {
+                    new.data = old.data.clone();
+                }
+                module.shadow = previous.shadow.clone();
+
+                info!("Reloaded module [{filename}]");
+                module.log_load_info();
+                changes.modules.insert(Arc::new(module));
+                changed = true;
+            }
+            (Ok(module), None) => {
+                changes.added.push(module);
+                changed = true;
+            }
+            (Err(e), Some(previous)) => {
+                error!(
+                    "Module [{filename}] failed to reload: {e}. Keeping the version already loaded"
+                );
+                changes.modules.insert(previous);
+            }
+            (Err(e), None) => {
+                error!("Module [{filename}] failed to load: {e}. Skipping module load");
+            }
+        }
+    }
+
+    for name in current.modules.keys() {
+        if !seen.contains(name) {
+            info!("Module [{name}] was removed and has been unloaded");
+            changed = true;
+        }
+    }
+
+    changed.then_some(changes)
+}
+
+/// Reads the loading configuration again, or returns `None` if it cannot be read
+pub type ReadConfiguration = dyn Fn() -> Option<Configuration> + Send + Sync;
+
+/// Read the loading configuration again, then reload the modules in `module_dir` and swap them
+/// in for the ones currently loaded. If the configuration cannot be read, the previous one is
+/// used. New modules are given room in the cache before they can run.
+async fn reload_modules(
+    config: &mut Arc<Configuration>,
+    read_config: &Arc<ReadConfiguration>,
+    storage: &Option<Arc<Storage>>,
+    cache: &Option<Arc<Cache>>,
+    live_modules: &LiveModules,
+) {
+    let current = live_modules.current();
+    let previous_config = config.clone();
+    let read_config = read_config.clone();
+    // Compilation is CPU heavy so it is kept off the async runtime
+    let reload = tokio::task::spawn_blocking(move || {
+        let config = read_config().map(Arc::new).unwrap_or(previous_config);
+        let changes = read_module_changes(&config, &current);
+        (config, changes)
+    });
+    let changes = match reload.await {
+        Ok((new_config, changes)) => {
+            *config = new_config;
+            match changes {
+                Some(changes) => changes,
+                None => {
+                    debug!("No module changes found in [{}]", config.module_dir);
+                    return;
+                }
+            }
+        }
+        Err(e) => {
+            error!("Module reload task failed: {e}");
+            return;
+        }
+    };
+
+    let ModuleChanges { mut modules, added } = changes;
+    for module in added {
+        if let Some(storage) = storage {
+            match storage.get_namespace_byte_size(&module.name).await {
+                Ok(bytes) => *module.storage_current.write().unwrap() = bytes,
+                Err(e) => {
+                    error!(
+                        "Module [{}] failed to look up namespace byte size: {e}. Skipping module load",
+                        module.name
+                    );
+                    continue;
+                }
+            }
+        }
+
+        info!("Loaded new module [{}]", module.name);
+        module.log_load_info();
+        modules.insert(Arc::new(module));
+    }
+
+    if let Some(cache) = cache {
+        cache.add_modules(&modules.get_module_logtypes());
+    }
+    let module_count = modules.modules.len();
+    live_modules.replace(modules);
+    info!("Module reload complete. {module_count} modules are now loaded");
+}
+
+/// Wait for the next tick of the reload interval, if one is configured
+async fn next_tick(reload_interval: &mut Option<Interval>) {
+    match reload_interval {
+        Some(reload_interval) => {
+            reload_interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Hot reload modules whenever Plaid receives SIGHUP and, if `module_reload_interval` is set,
+/// periodically. The loading configuration is read again with `read_config` for every reload,
+/// so modules whose configuration changed are reloaded too. This runs until the cancellation
+/// token is cancelled.
+pub async fn watch_for_module_changes(
+    mut config: Arc<Configuration>,
+    read_config: Arc<ReadConfiguration>,
+    storage: Option<Arc<Storage>>,
+    cache: Option<Arc<Cache>>,
+    live_modules: Arc<LiveModules>,
+    cancellation_token: CancellationToken,
+) {
+    let mut sighup = signal(SignalKind::hangup()).expect("Failed to install SIGHUP handler");
+
+    let mut reload_interval = config
+        .module_reload_interval
+        .filter(|secs| *secs > 0)
+        .map(|secs| {
+            info!(
+                "Checking [{}] for module changes every {secs} seconds",
+                config.module_dir
+            );
+            let mut reload_interval = interval(Duration::from_secs(secs));
+            reload_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            reload_interval
+        });
+    // The first tick completes immediately and the modules were only just loaded
+    if let Some(reload_interval) = &mut reload_interval {
+        reload_interval.tick().await;
+    }
+
+    loop {
+        tokio::select! {
+            _ = cancellation_token.cancelled() => break,
+            _ = sighup.recv() => {
+                info!("SIGHUP received, reloading modules");
+            }
+            _ = next_tick(&mut reload_interval) => {}
+        }
+
+        reload_modules(&mut config, &read_config, &storage, &cache, &live_modules).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+
+    /// A module that does nothing. `value` makes modules with different bytes.
+    fn module_bytes(value: i32) -> Vec<u8> {
+        wat::parse_str(format!(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "entrypoint") (result i32) i32.const {value}))"#
+        ))
+        .unwrap()
+    }
+
+    fn config(module_dir: &Path, extra: &str) -> Configuration {
+        let text = format!(
+            r#"
+            module_dir = "{}"
+            compiler_backend = "cranelift"
+            computation_amount = {{ default = 1000000 }}
+            memory_page_count = {{ default = 100 }}
+            storage_size = {{ default = "Unlimited" }}
+            {extra}
+            "#,
+            module_dir.display()
+        );
+        let mut config: Configuration = toml::from_str(&text).unwrap();
+        config.raw = Some(toml::from_str(&text).unwrap());
+        config
+    }
+
+    /// Apply the changes in `module_dir`, like a reload does
+    fn reload(config: &Configuration, current: PlaidModules) -> Option<PlaidModules> {
+        let ModuleChanges { mut modules, added } = read_module_changes(config, &current)?;
+        for module in added {
+            modules.insert(Arc::new(module));
+        }
+        Some(modules)
+    }
+
+    fn module_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("plaid_reload_{}", uuid::Uuid::new_v4()));
+        fs::create_dir(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn modules_are_added_modified_and_removed() {
+        let dir = module_dir();
+        let config = config(&dir, "");
+        fs::write(dir.join("test_first.wasm"), module_bytes(0)).unwrap();
+
+        let modules = reload(&config, PlaidModules::default()).unwrap();
+        let first = modules.get_module("test_first.wasm").unwrap();
+        // Nothing changed
+        assert!(read_module_changes(&config, &modules).is_none());
+
+        fs::write(dir.join("test_second.wasm"), module_bytes(0)).unwrap();
+        let modules = reload(&config, modules).unwrap();
+        assert!(Arc::ptr_eq(
+            &first,
+            &modules.get_module("test_first.wasm").unwrap()
+        ));
+        assert!(modules.get_module("test_second.wasm").is_some());
+
+        fs::write(dir.join("test_first.wasm"), module_bytes(1)).unwrap();
+        let modules = reload(&config, modules).unwrap();
+        let reloaded = modules.get_module("test_first.wasm").unwrap();
+        assert!(!Arc::ptr_eq(&first, &reloaded));
+        assert_ne!(first.bytes_hash, reloaded.bytes_hash);
+
+        fs::remove_file(dir.join("test_second.wasm")).unwrap();
+        let modules = reload(&config, modules).unwrap();
+        assert!(modules.get_module("test_second.wasm").is_none());
+        assert_eq!(modules.get_channel("test").unwrap().len(), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn configuration_changes_reload_the_modules_they_apply_to() {
+        let dir = module_dir();
+        fs::write(dir.join("test_first.wasm"), module_bytes(0)).unwrap();
+        fs::write(dir.join("test_second.wasm"), module_bytes(0)).unwrap();
+        let modules = reload(&config(&dir, ""), PlaidModules::default()).unwrap();
+        let first = modules.get_module("test_first.wasm").unwrap();
+        let second = modules.get_module("test_second.wasm").unwrap();
+        assert!(first.instance_pool.is_none());
+
+        // Only the bytes are unchanged: the module is reloaded with its new configuration
+        let config = config(&dir, "instance_pools = { \"test_first.wasm\" = 2 }");
+        let modules = reload(&config, modules).unwrap();
+        let reloaded = modules.get_module("test_first.wasm").unwrap();
+        assert!(!Arc::ptr_eq(&first, &reloaded));
+        assert!(reloaded.instance_pool.is_some());
+        assert!(Arc::ptr_eq(
+            &second,
+            &modules.get_module("test_second.wasm").unwrap()
+        ));
+        assert!(read_module_changes(&config, &modules).is_none());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+use crate::executor::ModuleExecutionError;
+
+fn default_initial_backoff() -> u64 {
+    1
+}
+
+fn default_backoff_multiplier() -> u32 {
+    2
+}
+
+fn default_retry_on() -> Vec<RetryableError> {
+    vec![RetryableError::ModuleError]
+}
+
+/// The classes of module failure that a retry policy can retry
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+pub enum RetryableError {
+    /// The module returned a non-zero value from its entrypoint
+    ModuleError,
+    /// The module ran out of computation
+    ComputationExhausted,
+    /// The module ran for longer than its execution timeout
+    TimeoutExceeded,
+    /// The module crashed for some other reason (e.g., it panicked or ran out of memory)
+    UnknownExecutionError,
+}
+
+impl RetryableError {
+    fn matches(&self, error: &ModuleExecutionError) -> bool {
+        matches!(
+            (self, error),
+            (Self::ModuleError, ModuleExecutionError::ModuleError(_))
+                | (
+                    Self::ComputationExhausted,
+                    ModuleExecutionError::ComputationExhausted(_)
+                )
+                | (
+                    Self::TimeoutExceeded,
+                    ModuleExecutionError::TimeoutExceeded(_)
+                )
+                | (
+                    Self::UnknownExecutionError,
+                    ModuleExecutionError::UnknownExecutionError(_)
+                )
+        )
+    }
+}
+
+/// How Plaid retries messages that a module failed to process. Retries are sent through the
+/// delayed logback system, so they are persisted and survive a restart.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RetryPolicy {
+    /// How many times in total the module may attempt to process a message, including the first time
+    pub max_attempts: u32,
+    /// How many seconds to wait before the first retry. Defaults to 1.
+    #[serde(default = "default_initial_backoff")]
+    pub initial_backoff: u64,
+    /// What the wait is multiplied by after every retry. Defaults to 2.
+    #[serde(default = "default_backoff_multiplier")]
+    pub backoff_multiplier: u32,
+    /// The longest, in seconds, that Plaid will wait between two attempts
+    pub max_backoff: Option<u64>,
+    /// Which failures are retried. Defaults to only retrying errors that the module returns itself.
+    #[serde(default = "default_retry_on")]
+    pub retry_on: Vec<RetryableError>,
+}
+
+impl RetryPolicy {
+    /// If the message should be retried after failing `attempt` with `error`, returns how many
+    /// seconds to wait before the next attempt.
+    pub fn next_retry_delay(&self, error: &ModuleExecutionError, attempt: u32) -> Option<u64> {
+        if attempt >= self.max_attempts || !self.retry_on.iter().any(|r| r.matches(error)) {
+            return None;
+        }
+
+        let delay = self
+            .backoff_multiplier
+            .checked_pow(attempt.saturating_sub(1))
+            .map(|factor| self.initial_backoff.saturating_mul(factor as u64))
+            .unwrap_or(u64::MAX);
+        let delay = match self.max_backoff {
+            Some(max_backoff) => delay.min(max_backoff),
+            None => delay,
+        };
+
+        // Delayed messages are always queued at least a second in the future
+        Some(delay.max(1))
+    }
+}
+
+/// Which retry of which module a message is. This is kept on the message so that a retry is
+/// only processed by the module that failed, and so the module can know which attempt it is on.
+/// Messages deferred because a module was at its execution limits use it too, without
+/// counting an attempt.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RetryInfo {
+    /// The module the message is being retried for
+    pub module: String,
+    /// Which attempt this is. The first retry is attempt 2.
+    pub attempt: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(max_attempts: u32, max_backoff: Option<u64>) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff: 5,
+            backoff_multiplier: 3,
+            max_backoff,
+            retry_on: default_retry_on(),
+        }
+    }
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let policy = policy(4, None);
+        let error = ModuleExecutionError::ModuleError(String::new());
+        assert_eq!(policy.next_retry_delay(&error, 1), Some(5));
+        assert_eq!(policy.next_retry_delay(&error, 2), Some(15));
+        assert_eq!(policy.next_retry_delay(&error, 3), Some(45));
+        assert_eq!(policy.next_retry_delay(&error, 4), None);
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let policy = policy(100, Some(60));
+        let error = ModuleExecutionError::ModuleError(String::new());
+        assert_eq!(policy.next_retry_delay(&error, 3), Some(45));
+        assert_eq!(policy.next_retry_delay(&error, 4), Some(60));
+        assert_eq!(policy.next_retry_delay(&error, 99), Some(60));
+    }
+
+    #[test]
+    fn only_configured_errors_are_retried() {
+        let policy = policy(3, None);
+        assert_eq!(
+            policy.next_retry_delay(&ModuleExecutionError::ComputationExhausted(100), 1),
+            None
+        );
+        assert_eq!(
+            policy.next_retry_delay(
+                &ModuleExecutionError::UnknownExecutionError(String::new()),
+                1
+            ),
+            None
+        );
+    }
+}
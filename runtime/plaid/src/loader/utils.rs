@@ -5,6 +5,7 @@ use super::{LimitValue, LimitableAmount, LimitedAmount};
 
 use std::collections::HashMap;
 use std::fs::DirEntry;
+use std::time::Duration;
 
 const CALL_COST: u64 = 10;
 
@@ -60,6 +61,20 @@ pub fn get_module_computation_limit(
     get_limit_with_overrides(limit_amount, filename, log_type)
 }
 
+/// Get the execution timeout for the module, if one is configured, by checking the following in order:
+/// 1. Module Override
+/// 2. Log Type amount
+/// 3. Default amount
+pub fn get_module_execution_timeout(
+    limit_amount: &Option<LimitedAmount>,
+    filename: &str,
+    log_type: &str,
+) -> Option<Duration> {
+    limit_amount.as_ref().map(|limit_amount| {
+        Duration::from_secs(get_limit_with_overrides(limit_amount, filename, log_type))
+    })
+}
+
 /// Get the persistent storage limit for the module by checking the following in order:
 /// 1. Module Override
 /// 2. Log Type amount
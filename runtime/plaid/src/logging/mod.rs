@@ -1,11 +1,17 @@
+mod otlp;
 mod splunk;
 mod webhook;
 
 mod stdout;
 
-use crossbeam_channel::{bounded, Receiver, RecvTimeoutError, Sender};
+use crossbeam_channel::{bounded, unbounded, Receiver, RecvTimeoutError, Sender};
 
+use crate::executor::ShadowComparison;
+use crate::functions::BlockedCall;
+use crate::trace::Span;
+use plaid_stl::plaid::logging::{LogLevel, ModuleLogEntry};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::{
     thread::{self, JoinHandle},
     time::Duration,
@@ -48,6 +54,25 @@ pub enum Log {
     WebSocketConnectionDropped {
         socket_name: String,
     },
+    /// A structured log sent by a module while it processed a message
+    ModuleLog {
+        module: String,
+        message_id: String,
+        /// The trace of the message the module was processing
+        trace_id: String,
+        level: LogLevel,
+        message: String,
+        fields: Map<String, Value>,
+        /// How many of the module's logs were dropped by its rate limit since the previous one
+        suppressed: u64,
+    },
+    /// How a shadow module processed a message, compared to its primary module
+    ShadowComparison(ShadowComparison),
+    /// A call that a module in test mode was not allowed to make
+    TestModeBlockedCall(BlockedCall),
+    /// A finished span of a trace. These are only sent if an OTLP exporter is configured
+    /// and only go to the exporter.
+    Span(Span),
     /// Is not used by other components of Plaid. This is created and sent
     /// by the logging system if it has not received a message from the server
     /// module for a period of time.
@@ -90,6 +115,9 @@ pub struct LoggingConfiguration {
     /// so it's easy to operate on Plaid events. It's likely in future the
     /// Splunk logger code will be a specific instantiation of this.
     webhook: Option<webhook::Config>,
+    /// Export trace spans to an OpenTelemetry collector. Spans are recorded for every
+    /// module execution and API call, and are only recorded if this is configured.
+    otlp: Option<otlp::Config>,
     /// Determines whether logs forwarded to rules are output to the configured logging destinations when a module fails.
     /// When set to `true`, logs are displayed for debugging; when `false`, they are omitted.
     /// Defaults to `true` if not explicitly configured.
@@ -136,6 +164,8 @@ trait PlaidLogger {
 pub struct Logger {
     sender: Sender<Log>,
     show_log_on_error: bool,
+    /// Whether spans are exported anywhere. If not, they are not sent at all.
+    tracing_enabled: bool,
 }
 
 impl Logger {
@@ -183,6 +213,54 @@ impl Logger {
             .map_err(|_| LoggingError::LoggingSystemDead)
     }
 
+    pub fn log_module_message(
+        &self,
+        module: String,
+        message_id: String,
+        trace_id: String,
+        entry: ModuleLogEntry,
+        suppressed: u64,
+    ) -> Result<(), LoggingError> {
+        self.sender
+            .send(Log::ModuleLog {
+                module,
+                message_id,
+                trace_id,
+                level: entry.level,
+                message: entry.message,
+                fields: entry.fields,
+                suppressed,
+            })
+            .map_err(|_| LoggingError::LoggingSystemDead)
+    }
+
+    /// Whether spans are exported, so callers can skip building them if they are not
+    pub fn tracing_enabled(&self) -> bool {
+        self.tracing_enabled
+    }
+
+    /// Send a finished span to the OTLP exporter. This does nothing if tracing is not enabled.
+    pub fn record_span(&self, span: Span) -> Result<(), LoggingError> {
+        if !self.tracing_enabled {
+            return Ok(());
+        }
+        self.sender
+            .send(Log::Span(span))
+            .map_err(|_| LoggingError::LoggingSystemDead)
+    }
+
+    pub fn log_blocked_call(&self, call: BlockedCall) -> Result<(), LoggingError> {
+        self.sender
+            .send(Log::TestModeBlockedCall(call))
+            .map_err(|_| LoggingError::LoggingSystemDead)
+    }
+
+    pub fn log_shadow_comparison(&self, comparison: ShadowComparison) -> Result<(), LoggingError> {
+        self.sender
+            .send(Log::ShadowComparison(comparison))
+            .map_err(|_| LoggingError::LoggingSystemDead)
+    }
+
     pub fn log_websocket_dropped(&self, socket_name: String) -> Result<(), LoggingError> {
         self.sender
             .send(Log::WebSocketConnectionDropped { socket_name })
@@ -231,6 +309,18 @@ impl Logger {
             None => None,
         };
 
+        let otlp_exporter = match config.otlp {
+            Some(otlp_config) => {
+                info!("Configured logger: otlp");
+                Some(otlp::OtlpExporter::new(
+                    otlp_config,
+                    config.identifier.clone().unwrap_or_default(),
+                    runtime.handle().clone(),
+                ))
+            }
+            None => None,
+        };
+
         // Main logging loop
         loop {
             let log = match log_receiver.recv_timeout(Duration::from_secs(heartbeat_interval)) {
@@ -244,6 +334,16 @@ impl Logger {
                 identifier: config.identifier.clone().unwrap_or_default(),
             };
 
+            // Spans are only for the trace exporter
+            if let Log::Span(_) = &log.log {
+                if let Some(exporter) = &otlp_exporter {
+                    if let Err(e) = exporter.send_log(&log) {
+                        error!("Could not export span: {e}");
+                    }
+                }
+                continue;
+            }
+
             if let Some(logger) = &stdout_logger {
                 if let Err(_) = logger.send_log(&log) {
                     error!("Could not send logs to stdout");
@@ -267,15 +367,33 @@ impl Logger {
         Err(LoggingError::LoggingSystemDead)
     }
 
+    /// A logger which does not send logs anywhere: they can be read from the returned
+    /// receiver instead. This is for tools that run modules outside of Plaid and need to
+    /// see what the modules did, like which host functions they called.
+    pub fn capturing() -> (Self, Receiver<Log>) {
+        // Nothing reads the logs while the module runs, so they must never fill the channel
+        let (sender, receiver) = unbounded();
+        (
+            Self {
+                sender,
+                show_log_on_error: true,
+                tracing_enabled: false,
+            },
+            receiver,
+        )
+    }
+
     pub fn start(config: LoggingConfiguration) -> (Self, JoinHandle<Result<(), LoggingError>>) {
         let (sender, rx) = bounded(CHANNEL_CAPACITY);
         let show_log_on_error = config.show_log_on_error;
+        let tracing_enabled = config.otlp.is_some();
         let _handle = thread::spawn(move || Self::logging_thread_loop(config, rx));
 
         (
             Self {
                 sender,
                 show_log_on_error,
+                tracing_enabled,
             },
             _handle,
         )
@@ -0,0 +1,249 @@
+//! This module provides a way for Plaid to export trace spans to an OpenTelemetry
+//! collector, using OTLP over HTTP with JSON encoding. Only spans are exported: all
+//! other logs are ignored by this logger.
+
+use super::{Log, LoggingError, PlaidLogger, WrappedLog};
+use crate::trace::Span;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::runtime::Handle;
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+/// How many spans can wait to be exported before new ones are dropped
+const SPAN_BUFFER_CAPACITY: usize = 8192;
+
+/// The struct that defines the OTLP specific configuration of the logging
+/// service.
+#[derive(Deserialize)]
+pub struct Config {
+    /// The collector's traces endpoint, e.g. `http://localhost:4318/v1/traces`
+    pub endpoint: String,
+    /// Headers to send with every export, e.g. for authentication
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// The `service.name` of the exported spans. Defaults to `plaid`.
+    #[serde(default = "default_service_name")]
+    pub service_name: String,
+    pub timeout: u8,
+    /// The most spans to send in a single export. Defaults to 512.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    /// How often to export the spans collected so far, in milliseconds. Defaults to 5000.
+    #[serde(default = "default_flush_interval")]
+    pub flush_interval_ms: u64,
+}
+
+fn default_service_name() -> String {
+    "plaid".to_string()
+}
+
+fn default_batch_size() -> usize {
+    512
+}
+
+fn default_flush_interval() -> u64 {
+    5000
+}
+
+/// The OTLP specific logger that is configured from the OTLP `Config` struct.
+/// Spans are collected and exported in batches by a task on the logging runtime.
+pub struct OtlpExporter {
+    /// Where spans are sent to wait for the next export
+    sender: mpsc::Sender<Span>,
+}
+
+impl OtlpExporter {
+    pub fn new(config: Config, identifier: String, handle: Handle) -> Self {
+        // I don't think this can fail with our settings so we do an unwrap
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout.into()))
+            .build()
+            .unwrap();
+
+        let (sender, receiver) = mpsc::channel(SPAN_BUFFER_CAPACITY);
+        handle.spawn(export_loop(client, config, identifier, receiver));
+
+        Self { sender }
+    }
+}
+
+impl PlaidLogger for OtlpExporter {
+    /// Queue a span for export. Exporting happens in the background so this
+    /// never waits on the collector.
+    fn send_log(&self, log: &WrappedLog) -> Result<(), LoggingError> {
+        let Log::Span(span) = &log.log else {
+            return Ok(());
+        };
+
+        match self.sender.try_send(span.clone()) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(_)) => Err(LoggingError::CommunicationError(
+                "Too many spans are waiting to be exported".to_string(),
+            )),
+            Err(TrySendError::Closed(_)) => Err(LoggingError::LoggingSystemDead),
+        }
+    }
+}
+
+/// Collect spans and export them whenever a batch is full or the flush interval passes
+async fn export_loop(
+    client: reqwest::Client,
+    config: Config,
+    identifier: String,
+    mut receiver: mpsc::Receiver<Span>,
+) {
+    let batch_size = config.batch_size.max(1);
+    let mut interval =
+        tokio::time::interval(Duration::from_millis(config.flush_interval_ms.max(1)));
+    let mut batch = Vec::with_capacity(batch_size);
+
+    loop {
+        tokio::select! {
+            span = receiver.recv() => match span {
+                Some(span) => {
+                    batch.push(span);
+                    if batch.len() >= batch_size {
+                        export(&client, &config, &identifier, std::mem::take(&mut batch)).await;
+                    }
+                }
+                // The logging system has gone away, so send what is left and stop
+                None => {
+                    if !batch.is_empty() {
+                        export(&client, &config, &identifier, batch).await;
+                    }
+                    break;
+                }
+            },
+            _ = interval.tick() => {
+                if !batch.is_empty() {
+                    export(&client, &config, &identifier, std::mem::take(&mut batch)).await;
+                }
+            }
+        }
+    }
+}
+
+async fn export(client: &reqwest::Client, config: &Config, identifier: &str, spans: Vec<Span>) {
+    let body = encode_spans(&config.service_name, identifier, &spans);
+    let mut request = client.post(&config.endpoint).json(&body);
+    for (name, value) in &config.headers {
+        request = request.header(name, value);
+    }
+
+    match request.send().await {
+        Ok(response) if !response.status().is_success() => error!(
+            "Could not export {} spans: collector returned {}",
+            spans.len(),
+            response.status()
+        ),
+        Ok(_) => (),
+        Err(e) => error!("Could not export {} spans: {e}", spans.len()),
+    }
+}
+
+/// Build an OTLP `ExportTraceServiceRequest` in its JSON encoding
+fn encode_spans(service_name: &str, identifier: &str, spans: &[Span]) -> Value {
+    let mut resource_attributes = vec![attribute("service.name", service_name)];
+    if !identifier.is_empty() {
+        resource_attributes.push(attribute("service.instance.id", identifier));
+    }
+
+    let spans: Vec<Value> = spans
+        .iter()
+        .map(|span| {
+            let mut encoded = json!({
+                "traceId": span.trace_id,
+                "spanId": span.span_id,
+                "name": span.name,
+                // SPAN_KIND_INTERNAL
+                "kind": 1,
+                "startTimeUnixNano": unix_nanos(span.start),
+                "endTimeUnixNano": unix_nanos(span.end),
+                "attributes": span
+                    .attributes
+                    .iter()
+                    .map(|(key, value)| attribute(key, value))
+                    .collect::<Vec<_>>(),
+                "status": match &span.error {
+                    // STATUS_CODE_ERROR
+                    Some(error) => json!({ "code": 2, "message": error }),
+                    // STATUS_CODE_OK
+                    None => json!({ "code": 1 }),
+                },
+            });
+            if let Some(parent) = &span.parent_span_id {
+                encoded["parentSpanId"] = json!(parent);
+            }
+            encoded
+        })
+        .collect();
+
+    json!({
+        "resourceSpans": [{
+            "resource": { "attributes": resource_attributes },
+            "scopeSpans": [{
+                "scope": { "name": "plaid" },
+                "spans": spans,
+            }],
+        }],
+    })
+}
+
+fn attribute(key: &str, value: &str) -> Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+/// OTLP JSON encodes 64 bit integers as strings
+fn unix_nanos(time: SystemTime) -> String {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_are_encoded_as_otlp_json() {
+        let mut span = Span::start(
+            "example_rule.wasm",
+            "4bf92f3577b34da6a3ce929d0e0e4736",
+            "00f067aa0ba902b7".to_string(),
+            Some("53995c3f42cd8ad8".to_string()),
+        )
+        .with_attribute("plaid.log_type", "example");
+        span.start = UNIX_EPOCH + Duration::from_secs(1);
+        span.end = UNIX_EPOCH + Duration::from_secs(2);
+        let span = Span {
+            error: Some("Computation Exhausted".to_string()),
+            ..span
+        };
+
+        let encoded = encode_spans("plaid", "", &[span]);
+        let resource = &encoded["resourceSpans"][0];
+        assert_eq!(
+            resource["resource"]["attributes"],
+            json!([{ "key": "service.name", "value": { "stringValue": "plaid" } }])
+        );
+        assert_eq!(
+            resource["scopeSpans"][0]["spans"][0],
+            json!({
+                "traceId": "4bf92f3577b34da6a3ce929d0e0e4736",
+                "spanId": "00f067aa0ba902b7",
+                "parentSpanId": "53995c3f42cd8ad8",
+                "name": "example_rule.wasm",
+                "kind": 1,
+                "startTimeUnixNano": "1000000000",
+                "endTimeUnixNano": "2000000000",
+                "attributes": [{ "key": "plaid.log_type", "value": { "stringValue": "example" } }],
+                "status": { "code": 2, "message": "Computation Exhausted" },
+            })
+        );
+    }
+}
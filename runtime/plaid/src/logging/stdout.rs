@@ -2,6 +2,8 @@
 
 use super::{Log, LoggingError, PlaidLogger, Severity, WrappedLog};
 
+use plaid_stl::plaid::logging::LogLevel;
+
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -40,6 +42,65 @@ impl PlaidLogger for StdoutLogger {
             Log::WebSocketConnectionDropped { socket_name } => {
                 warn!("Connection to socket: {socket_name} dropped unexpectedly");
             }
+            Log::ModuleLog {
+                module,
+                message_id,
+                trace_id: _,
+                level,
+                message,
+                fields,
+                suppressed,
+            } => {
+                let fields = serde_json::Value::Object(fields.clone());
+                let suppressed = if *suppressed > 0 {
+                    format!(" ({suppressed} earlier logs were dropped by the rate limit)")
+                } else {
+                    String::new()
+                };
+                match level {
+                    LogLevel::Debug => {
+                        debug!("[{module}] [{message_id}] {message} {fields}{suppressed}")
+                    }
+                    LogLevel::Info => {
+                        info!("[{module}] [{message_id}] {message} {fields}{suppressed}")
+                    }
+                    LogLevel::Warning => {
+                        warn!("[{module}] [{message_id}] {message} {fields}{suppressed}")
+                    }
+                    LogLevel::Error => {
+                        error!("[{module}] [{message_id}] {message} {fields}{suppressed}")
+                    }
+                }
+            }
+            Log::ShadowComparison(comparison) => {
+                if comparison.differences.is_empty() {
+                    debug!(
+                        "Shadow [{}] behaved like [{}] on message [{}]",
+                        comparison.shadow, comparison.module, comparison.message_id
+                    )
+                } else {
+                    warn!(
+                        "Shadow [{}] diverged from [{}] on message [{}]: {}",
+                        comparison.shadow,
+                        comparison.module,
+                        comparison.message_id,
+                        comparison.differences.join(", ")
+                    )
+                }
+            }
+            Log::TestModeBlockedCall(call) => {
+                let outcome = match &call.synthetic_response {
+                    Some(_) => "returned a synthetic response",
+                    None => "was blocked",
+                };
+                info!(
+                    "TEST MODE [{}] call to [{}] {outcome} while processing message [{}]. Parameters: {}",
+                    call.module, call.function, call.message_id, call.params
+                )
+            }
+            Log::Span(span) => {
+                trace!("[{}] span [{}] finished", span.trace_id, span.name)
+            }
             Log::Heartbeat { .. } => (),
         }
         Ok(())
@@ -0,0 +1,475 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::sync::oneshot;
+
+use crate::apis::canned::CannedResponse;
+use crate::executor::Message;
+use crate::storage::{Storage, StorageError};
+
+/// Where the messages of a log type are recorded
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingDestination {
+    /// Append the messages to this file, one JSON object per line
+    File(String),
+    /// Insert the messages into this storage namespace
+    Storage(String),
+}
+
+/// Configuration for recording messages. When configured, the messages of the listed log types
+/// are recorded as they come in, so they can be replayed against modules offline with the
+/// `replay` binary.
+#[derive(Deserialize)]
+pub struct RecordingConfig {
+    /// The log types to record and where to record them.
+    /// The mapping is `{log_type -> destination}`
+    pub log_types: HashMap<String, RecordingDestination>,
+}
+
+/// A message as it was received, along with when it was received
+#[derive(Serialize, Deserialize)]
+pub struct RecordedMessage {
+    /// When the message was received, in seconds since the epoch
+    pub timestamp: u64,
+    /// Orders the recorded entries: when the entry was recorded, in nanoseconds since the
+    /// epoch, made unique across the entries of a recorder
+    #[serde(default)]
+    pub sequence: u64,
+    pub message: Message,
+    /// The responses the APIs gave to the modules which processed the message. These are
+    /// recorded separately and attached when the recording is read.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub api_calls: Vec<RecordedApiCall>,
+}
+
+impl RecordedMessage {
+    /// A message received just now
+    pub fn new(message: Message) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        Self {
+            timestamp: now.as_secs(),
+            sequence: now.as_nanos() as u64,
+            message,
+            api_calls: vec![],
+        }
+    }
+
+    pub fn create_duplicate(&self) -> Self {
+        Self {
+            timestamp: self.timestamp,
+            sequence: self.sequence,
+            message: self.message.create_duplicate(),
+            api_calls: self.api_calls.clone(),
+        }
+    }
+}
+
+/// The response an API gave to a module processing a recorded message
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RecordedApiCall {
+    /// The ID of the message the module was processing
+    pub message_id: String,
+    #[serde(default)]
+    pub sequence: u64,
+    /// The module which made the call
+    pub module: String,
+    /// The host function the module called, like `github_add_user_to_repo`
+    pub function: String,
+    /// The parameters the module passed
+    pub params: String,
+    pub response: CannedResponse,
+}
+
+/// An entry of a recording
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordedEntry {
+    Message(Box<RecordedMessage>),
+    ApiCall(RecordedApiCall),
+}
+
+impl RecordedEntry {
+    fn sequence(&self) -> u64 {
+        match self {
+            Self::Message(message) => message.sequence,
+            Self::ApiCall(call) => call.sequence,
+        }
+    }
+
+    /// The key of the entry when it is recorded to storage. API calls are kept under the ID
+    /// of their message, so that recording a message again replaces it but not its calls.
+    fn key(&self) -> String {
+        match self {
+            Self::Message(message) => message.message.id.clone(),
+            Self::ApiCall(call) => format!("{}/{}", call.message_id, call.sequence),
+        }
+    }
+}
+
+/// Errors encountered while recording messages or reading recordings
+#[derive(Debug)]
+pub enum RecordingError {
+    IoError(std::io::Error),
+    SerializationError(serde_json::Error),
+    StorageError(StorageError),
+}
+
+impl std::fmt::Display for RecordingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IoError(e) => write!(f, "I/O error: {e}"),
+            Self::SerializationError(e) => write!(f, "Could not (de)serialize message: {e}"),
+            Self::StorageError(e) => write!(f, "Storage error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for RecordingError {}
+
+impl From<std::io::Error> for RecordingError {
+    fn from(e: std::io::Error) -> Self {
+        Self::IoError(e)
+    }
+}
+
+impl From<serde_json::Error> for RecordingError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::SerializationError(e)
+    }
+}
+
+impl From<StorageError> for RecordingError {
+    fn from(e: StorageError) -> Self {
+        Self::StorageError(e)
+    }
+}
+
+/// What the writer of a recorder is asked to do
+enum WriterCommand {
+    Write(RecordingDestination, RecordedEntry),
+    /// Let the sender know once everything sent before has been written
+    Flush(oneshot::Sender<()>),
+}
+
+/// Records the messages of configured log types as they are executed, and the responses the
+/// APIs give to the modules processing them. Entries are written in batches by a task of
+/// their own, so recording never holds up the execution threads.
+pub struct MessageRecorder {
+    destinations: HashMap<String, RecordingDestination>,
+    /// The last sequence number given to an entry
+    sequence: AtomicU64,
+    writer: UnboundedSender<WriterCommand>,
+}
+
+impl MessageRecorder {
+    /// Open the recording files and start writing entries to them on the current Tokio
+    /// runtime. `storage` is where messages recorded to a storage namespace are kept.
+    pub fn new(config: &RecordingConfig, storage: Arc<Storage>) -> Result<Self, RecordingError> {
+        let mut files = HashMap::new();
+        for destination in config.log_types.values() {
+            if let RecordingDestination::File(path) = destination {
+                if !files.contains_key(path) {
+                    let file = OpenOptions::new().create(true).append(true).open(path)?;
+                    files.insert(path.clone(), tokio::fs::File::from_std(file));
+                }
+            }
+        }
+
+        let (writer, commands) = unbounded_channel();
+        tokio::spawn(write_entries(commands, files, storage));
+
+        Ok(Self {
+            destinations: config.log_types.clone(),
+            sequence: AtomicU64::new(0),
+            writer,
+        })
+    }
+
+    /// Get the next sequence number: the current time in nanoseconds since the epoch, or one
+    /// more than the last sequence number if the clock has not moved past it
+    fn next_sequence(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64;
+        let previous = self
+            .sequence
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+                Some(now.max(last + 1))
+            })
+            .unwrap_or_default();
+        now.max(previous + 1)
+    }
+
+    /// Whether the messages of a log type are recorded
+    pub fn records(&self, log_type: &str) -> bool {
+        self.destinations.contains_key(log_type)
+    }
+
+    fn send(&self, destination: &RecordingDestination, entry: RecordedEntry) {
+        if self
+            .writer
+            .send(WriterCommand::Write(destination.clone(), entry))
+            .is_err()
+        {
+            error!("Recording writer has stopped, an entry was not recorded");
+        }
+    }
+
+    /// Record a message, if its log type is recorded. Failures are logged rather than
+    /// returned: recording must never stop a message from being processed.
+    pub fn record(&self, message: &Message) {
+        let Some(destination) = self.destinations.get(&message.type_) else {
+            return;
+        };
+
+        let recorded = RecordedMessage {
+            sequence: self.next_sequence(),
+            ..RecordedMessage::new(message.create_duplicate())
+        };
+        self.send(destination, RecordedEntry::Message(Box::new(recorded)));
+    }
+
+    /// Record the response an API gave to `module` while it was processing `message`, if the
+    /// message's log type is recorded
+    pub fn record_api_call(
+        &self,
+        message: &Message,
+        module: &str,
+        function: &str,
+        params: String,
+        response: CannedResponse,
+    ) {
+        let Some(destination) = self.destinations.get(&message.type_) else {
+            return;
+        };
+
+        let call = RecordedApiCall {
+            message_id: message.id.clone(),
+            sequence: self.next_sequence(),
+            module: module.to_string(),
+            function: function.to_string(),
+            params,
+            response,
+        };
+        self.send(destination, RecordedEntry::ApiCall(call));
+    }
+
+    /// Wait until everything recorded so far has been written
+    pub async fn flush(&self) {
+        let (sender, receiver) = oneshot::channel();
+        if self.writer.send(WriterCommand::Flush(sender)).is_ok() {
+            let _ = receiver.await;
+        }
+    }
+}
+
+/// How many entries are written at once, at most
+const WRITE_BATCH_SIZE: usize = 256;
+
+/// Write the entries sent to a recorder, in batches, until the recorder is dropped
+async fn write_entries(
+    mut commands: UnboundedReceiver<WriterCommand>,
+    mut files: HashMap<String, tokio::fs::File>,
+    storage: Arc<Storage>,
+) {
+    let mut batch = Vec::with_capacity(WRITE_BATCH_SIZE);
+    while commands.recv_many(&mut batch, WRITE_BATCH_SIZE).await > 0 {
+        let mut lines: HashMap<String, Vec<u8>> = HashMap::new();
+        let mut flushed = vec![];
+        for command in batch.drain(..) {
+            let (destination, entry) = match command {
+                WriterCommand::Write(destination, entry) => (destination, entry),
+                WriterCommand::Flush(sender) => {
+                    flushed.push(sender);
+                    continue;
+                }
+            };
+            let serialized = match serde_json::to_vec(&entry) {
+                Ok(serialized) => serialized,
+                Err(e) => {
+                    error!("Failed to serialize a recorded entry. Error: {e}");
+                    continue;
+                }
+            };
+            match destination {
+                RecordingDestination::File(path) => {
+                    let lines = lines.entry(path).or_default();
+                    lines.extend(serialized);
+                    lines.push(b'\n');
+                }
+                RecordingDestination::Storage(namespace) => {
+                    if let Err(e) = storage.insert(namespace, entry.key(), serialized).await {
+                        error!("Failed to record an entry to storage. Error: {e}");
+                    }
+                }
+            }
+        }
+
+        for (path, lines) in lines {
+            // The file was opened in `MessageRecorder::new`, so it is always there
+            let file = files
+                .get_mut(&path)
+                .expect("recording files are opened up front");
+            if let Err(e) = write_lines(file, &lines).await {
+                error!("Failed to record to {path}. Error: {e}");
+            }
+        }
+        for sender in flushed {
+            let _ = sender.send(());
+        }
+    }
+}
+
+async fn write_lines(file: &mut tokio::fs::File, lines: &[u8]) -> std::io::Result<()> {
+    file.write_all(lines).await?;
+    file.flush().await
+}
+
+/// Attach the recorded API calls to the messages they were made for, and put the messages in
+/// the order they were recorded
+fn assemble(mut entries: Vec<RecordedEntry>) -> Vec<RecordedMessage> {
+    entries.sort_by_key(RecordedEntry::sequence);
+
+    let mut messages = vec![];
+    let mut api_calls: HashMap<String, Vec<RecordedApiCall>> = HashMap::new();
+    for entry in entries {
+        match entry {
+            RecordedEntry::Message(message) => messages.push(*message),
+            RecordedEntry::ApiCall(call) => api_calls
+                .entry(call.message_id.clone())
+                .or_default()
+                .push(call),
+        }
+    }
+    for message in &mut messages {
+        if let Some(calls) = api_calls.remove(&message.message.id) {
+            message.api_calls = calls;
+        }
+    }
+    messages
+}
+
+/// Read a recording file, in the order the messages were recorded
+pub fn read_recording_file(path: &str) -> Result<Vec<RecordedMessage>, RecordingError> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut entries = vec![];
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        entries.push(serde_json::from_str(&line)?);
+    }
+    Ok(assemble(entries))
+}
+
+/// Read the messages recorded to a storage namespace, in the order they were recorded
+pub async fn read_recording_namespace(
+    storage: &Storage,
+    namespace: &str,
+) -> Result<Vec<RecordedMessage>, RecordingError> {
+    let mut entries = vec![];
+    for (key, value) in storage.fetch_all(namespace, None).await? {
+        let Some(value) = value else {
+            warn!("Empty value for recorded entry with key {key}, skipping it.");
+            continue;
+        };
+        match serde_json::from_slice::<RecordedEntry>(&value) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => {
+                warn!("Skipping recorded entry [{key}] which could not be deserialized [{e}]")
+            }
+        }
+    }
+    Ok(assemble(entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use plaid_stl::messages::{LogSource, LogbacksAllowed};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn recorded_messages_can_be_read_back() {
+        let path = std::env::temp_dir().join(format!("plaid_recording_{}", uuid::Uuid::new_v4()));
+        let path = path.to_str().unwrap().to_string();
+        let storage = Arc::new(Storage::new_in_memory());
+        let config = RecordingConfig {
+            log_types: HashMap::from([
+                (
+                    "to_file".to_string(),
+                    RecordingDestination::File(path.clone()),
+                ),
+                (
+                    "to_storage".to_string(),
+                    RecordingDestination::Storage("recording".to_string()),
+                ),
+            ]),
+        };
+        let recorder = MessageRecorder::new(&config, storage.clone()).unwrap();
+
+        let message = |type_: &str, data: &str| {
+            let mut message = Message::new(
+                type_.to_string(),
+                data.as_bytes().to_vec(),
+                LogSource::WebhookPost("test".to_string()),
+                LogbacksAllowed::Limited(1),
+            );
+            message
+                .headers
+                .insert("X-Test".to_string(), b"header".to_vec());
+            message
+        };
+        let first = message("to_file", "first");
+        recorder.record(&first);
+        recorder.record(&message("not_recorded", "ignored"));
+        recorder.record(&message("to_file", "second"));
+        recorder.record_api_call(
+            &first,
+            "rule.wasm",
+            "general_make_named_request",
+            "{}".to_string(),
+            CannedResponse::Success("response".to_string()),
+        );
+        // Many messages are recorded within the same second, and they are read back in order
+        for i in 0..20 {
+            recorder.record(&message("to_storage", &i.to_string()));
+        }
+        recorder.flush().await;
+
+        let from_file = read_recording_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(from_file.len(), 2);
+        assert_eq!(from_file[0].message.data, b"first");
+        assert_eq!(from_file[1].message.data, b"second");
+        assert_eq!(from_file[0].message.headers["X-Test"], b"header");
+        assert_eq!(from_file[0].api_calls.len(), 1);
+        assert_eq!(from_file[0].api_calls[0].module, "rule.wasm");
+        assert_eq!(
+            from_file[0].api_calls[0].response,
+            CannedResponse::Success("response".to_string())
+        );
+        assert!(from_file[1].api_calls.is_empty());
+
+        let from_storage = read_recording_namespace(&storage, "recording")
+            .await
+            .unwrap();
+        let data: Vec<String> = from_storage
+            .iter()
+            .map(|recorded| String::from_utf8(recorded.message.data.clone()).unwrap())
+            .collect();
+        assert_eq!(data, (0..20).map(|i| i.to_string()).collect::<Vec<_>>());
+    }
+}
@@ -182,6 +182,15 @@ impl Storage {
         }
     }
 
+    /// Build a storage on top of any storage provider, for tests
+    #[cfg(test)]
+    pub(crate) fn with_provider(database: Box<dyn StorageProvider + Send + Sync>) -> Self {
+        Self {
+            database,
+            shared_dbs: None,
+        }
+    }
+
     pub async fn new(config: Config) -> Result<Self, StorageError> {
         // Try building a database from the values in the config
         let database: Box<dyn StorageProvider + Send + Sync> = match config.db {
@@ -0,0 +1,149 @@
+//! Fixtures shared by the crate's unit tests
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use crossbeam_channel::{unbounded, Receiver};
+use plaid_stl::messages::{LogSource, LogbacksAllowed};
+use tokio_util::sync::CancellationToken;
+
+use crate::apis::{Api, ApiConfigs};
+use crate::data::ScheduledLogbacks;
+use crate::executor::{ExecutionContext, Message, StandaloneContext};
+use crate::loader::{PlaidModule, PlaidModules};
+use crate::logging::{Log, Logger};
+use crate::storage::{Storage, StorageError, StorageProvider};
+
+/// A message of `log_type` carrying `data`, as a logback which cannot send logbacks itself
+pub fn message(log_type: &str, data: &[u8]) -> Message {
+    Message::new(
+        log_type.to_string(),
+        data.to_vec(),
+        LogSource::Logback("test".to_string()),
+        LogbacksAllowed::Limited(0),
+    )
+}
+
+/// A message of `log_type` carrying `data`, as received by `webhook`
+pub fn webhook_message(log_type: &str, webhook: &str, data: &[u8]) -> Message {
+    Message::new(
+        log_type.to_string(),
+        data.to_vec(),
+        LogSource::WebhookPost(webhook.to_string()),
+        LogbacksAllowed::Limited(0),
+    )
+}
+
+/// Compile a module written in WAT, named `{name}.wasm` and processing the log type `name`
+pub fn wat_module(name: &str, wat: &str) -> PlaidModule {
+    PlaidModule::compile_standalone(
+        &format!("{name}.wasm"),
+        name,
+        wat::parse_str(wat).unwrap(),
+        1_000_000,
+        10,
+    )
+    .unwrap()
+}
+
+/// An API with nothing configured
+pub fn api() -> Arc<Api> {
+    api_with(ApiConfigs::default())
+}
+
+/// An API with `configs`, on a runtime of its own
+pub fn api_with(configs: ApiConfigs) -> Arc<Api> {
+    let api = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(Api::new(configs))
+        .unwrap();
+    Arc::new(api)
+}
+
+/// What the execution threads use, with `storage` and nothing else configured. The logs are
+/// sent to the returned receiver, which must be kept for as long as modules run.
+pub fn execution_context(
+    api: Arc<Api>,
+    storage: Arc<Storage>,
+) -> (ExecutionContext, Receiver<Log>) {
+    let (els, logs) = Logger::capturing();
+    let context = ExecutionContext {
+        api,
+        storage: Some(storage.clone()),
+        cache: None,
+        els,
+        performance_mode: None,
+        module_execution_metrics: None,
+        delayed_log_sender: unbounded().0,
+        cancellation_token: CancellationToken::new(),
+        dead_letters: None,
+        scheduled_logbacks: Arc::new(ScheduledLogbacks::new(storage)),
+    };
+    (context, logs)
+}
+
+/// What a module run on its own uses, with `storage` and nothing else configured. The logs are
+/// sent to the returned receiver, which must be kept for as long as the module runs.
+pub fn standalone_context(
+    api: Arc<Api>,
+    storage: Arc<Storage>,
+) -> (StandaloneContext, Receiver<Log>) {
+    let (els, logs) = Logger::capturing();
+    let context = StandaloneContext {
+        api,
+        storage: Some(storage.clone()),
+        cache: None,
+        els,
+        immediate_sender: None,
+        delayed_log_sender: unbounded().0,
+        scheduled_logbacks: Arc::new(ScheduledLogbacks::new(storage)),
+        modules: Arc::new(PlaidModules::default()),
+    };
+    (context, logs)
+}
+
+/// A storage which never answers
+pub fn stuck_storage() -> Storage {
+    Storage::with_provider(Box::new(StuckStorage))
+}
+
+/// A storage provider which never answers, for tests
+struct StuckStorage;
+
+#[async_trait]
+impl StorageProvider for StuckStorage {
+    fn is_persistent(&self) -> bool {
+        false
+    }
+
+    async fn insert(
+        &self,
+        _: String,
+        _: String,
+        _: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>, StorageError> {
+        std::future::pending().await
+    }
+
+    async fn get(&self, _: &str, _: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        std::future::pending().await
+    }
+
+    async fn delete(&self, _: &str, _: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        std::future::pending().await
+    }
+
+    async fn list_keys(&self, _: &str, _: Option<&str>) -> Result<Vec<String>, StorageError> {
+        std::future::pending().await
+    }
+
+    async fn fetch_all(
+        &self,
+        _: &str,
+        _: Option<&str>,
+    ) -> Result<Vec<(String, Option<Vec<u8>>)>, StorageError> {
+        std::future::pending().await
+    }
+}
@@ -0,0 +1,401 @@
+//! Run a module against messages from Rust tests, without booting Plaid.
+//!
+//! A [`TestRuntime`] loads a compiled module and runs it on the messages it is sent, with
+//! storage kept in memory and no APIs configured. Calls the module makes to APIs are answered
+//! with canned responses set by the test, and everything the module did is returned so the
+//! test can check it:
+//!
+//! ```no_run
+//! use plaid::testing::{CannedResponse, TestRuntime};
+//!
+//! let mut runtime = TestRuntime::load("target/wasm32-unknown-unknown/release/my_rule.wasm", "my_rule").unwrap();
+//! runtime.set_secret("api_key", "not_a_real_key");
+//! runtime.set_named_request_response("get_user", CannedResponse::Success(r#"{"name":"alice"}"#.to_string()));
+//!
+//! let execution = runtime.send_data("some data").unwrap();
+//! assert!(execution.response.is_ok());
+//! assert_eq!(execution.host_calls, vec!["general_make_named_request"]);
+//! ```
+//!
+//! The runtime blocks while the module runs, so it must not be used from within an async
+//! context (like a `#[tokio::test]`).
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use crossbeam_channel::unbounded;
+use plaid_stl::messages::{LogSource, LogbacksAllowed};
+
+use crate::apis::{Api, ApiConfigs, ApiError};
+use crate::data::{DelayedMessage, ScheduledLogbacks};
+use crate::executor::{
+    execute_standalone, ExecutorError, Message, ModuleExecutionError, PoolSender, StandaloneContext,
+};
+use crate::loader::{PlaidModule, PlaidModules};
+use crate::logging::{Log, Logger};
+use crate::storage::{Storage, StorageError};
+
+pub use crate::apis::canned::{ApiCall, CannedResponse, CannedResponses};
+
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+/// How much computation a module can use per message, unless changed
+pub const DEFAULT_COMPUTATION_LIMIT: u64 = 55_000_000;
+/// How many pages of memory a module can use, unless changed
+pub const DEFAULT_PAGE_LIMIT: u32 = 300;
+
+/// A change a module made to its storage
+#[derive(Debug, Clone, PartialEq)]
+pub struct StorageMutation {
+    pub key: String,
+    /// The value before the module ran, if there was one
+    pub before: Option<Vec<u8>>,
+    /// The value after the module ran, or `None` if the module deleted it
+    pub after: Option<Vec<u8>>,
+}
+
+/// Everything a module did while processing a message
+pub struct TestExecution {
+    /// The response the module set, or why it failed
+    pub response: Result<Option<String>, ModuleExecutionError>,
+    /// How much computation the module used
+    pub computation_used: u64,
+    /// The host functions the module called, in order
+    pub host_calls: Vec<String>,
+    /// The calls the module made to APIs, with their parameters, in order
+    pub api_calls: Vec<ApiCall>,
+    /// The changes the module made to its storage, ordered by key
+    pub storage_mutations: Vec<StorageMutation>,
+    /// The messages the module logged back without a delay
+    pub logbacks: Vec<Message>,
+    /// The messages the module logged back with a delay
+    pub delayed_logbacks: Vec<DelayedMessage>,
+    /// Everything else the module logged, like its own log messages and errors
+    pub logs: Vec<Log>,
+}
+
+/// Errors encountered while setting up a test runtime or running a module in it
+#[derive(Debug)]
+pub enum TestRuntimeError {
+    IoError(std::io::Error),
+    CompileError(String),
+    ApiError(String),
+    StorageError(StorageError),
+    /// The module could not be run at all
+    ExecutorError(String),
+}
+
+impl std::fmt::Display for TestRuntimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IoError(e) => write!(f, "Could not read module: {e}"),
+            Self::CompileError(e) => write!(f, "Could not compile module: {e}"),
+            Self::ApiError(e) => write!(f, "Could not set up APIs: {e}"),
+            Self::StorageError(e) => write!(f, "Storage error: {e}"),
+            Self::ExecutorError(e) => write!(f, "Could not run module: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for TestRuntimeError {}
+
+impl From<std::io::Error> for TestRuntimeError {
+    fn from(e: std::io::Error) -> Self {
+        Self::IoError(e)
+    }
+}
+
+impl From<ApiError> for TestRuntimeError {
+    fn from(e: ApiError) -> Self {
+        Self::ApiError(format!("{e:?}"))
+    }
+}
+
+impl From<StorageError> for TestRuntimeError {
+    fn from(e: StorageError) -> Self {
+        Self::StorageError(e)
+    }
+}
+
+impl From<ExecutorError> for TestRuntimeError {
+    fn from(e: ExecutorError) -> Self {
+        Self::ExecutorError(e.to_string())
+    }
+}
+
+/// Runs a single module on messages, for testing it
+pub struct TestRuntime {
+    /// The module being tested. It is only shared while it is running.
+    module: Arc<PlaidModule>,
+    api: Arc<Api>,
+    canned_responses: Arc<CannedResponses>,
+    storage: Arc<Storage>,
+    modules: Arc<PlaidModules>,
+}
+
+impl TestRuntime {
+    /// Load the compiled module at `path`, which processes messages of `log_type`
+    pub fn load(path: &str, log_type: &str) -> Result<Self, TestRuntimeError> {
+        let filename = std::path::Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string());
+        Self::from_bytes(&filename, log_type, std::fs::read(path)?)
+    }
+
+    /// Load a module from its compiled bytes. `name` is the module's filename, which is also
+    /// the namespace of its storage.
+    pub fn from_bytes(
+        name: &str,
+        log_type: &str,
+        module_bytes: Vec<u8>,
+    ) -> Result<Self, TestRuntimeError> {
+        let module = PlaidModule::compile_standalone(
+            name,
+            log_type,
+            module_bytes,
+            DEFAULT_COMPUTATION_LIMIT,
+            DEFAULT_PAGE_LIMIT,
+        )
+        .map_err(|e| TestRuntimeError::CompileError(e.to_string()))?;
+
+        // Creating the APIs does not need a runtime when none of them are configured, but the
+        // future still has to be driven by one
+        let mut api = tokio::runtime::Builder::new_current_thread()
+            .build()?
+            .block_on(Api::new(ApiConfigs::default()))?;
+        let canned_responses = Arc::new(CannedResponses::default());
+        api.canned_responses = Some(canned_responses.clone());
+
+        Ok(Self {
+            module: Arc::new(module),
+            api: Arc::new(api),
+            canned_responses,
+            storage: Arc::new(Storage::new_in_memory()),
+            modules: Arc::new(PlaidModules::default()),
+        })
+    }
+
+    fn module_mut(&mut self) -> &mut PlaidModule {
+        Arc::get_mut(&mut self.module).expect("module is only shared while it runs")
+    }
+
+    /// Set how much computation the module can use per message
+    pub fn set_computation_limit(&mut self, limit: u64) {
+        self.module_mut().computation_limit = limit;
+    }
+
+    /// Set a secret the module can read
+    pub fn set_secret(&mut self, name: &str, value: impl Into<Vec<u8>>) {
+        self.module_mut()
+            .secrets
+            .get_or_insert_with(HashMap::new)
+            .insert(name.to_string(), value.into());
+    }
+
+    /// Set accessory data the module can read
+    pub fn set_accessory_data(&mut self, name: &str, value: impl Into<Vec<u8>>) {
+        self.module_mut()
+            .accessory_data
+            .get_or_insert_with(HashMap::new)
+            .insert(name.to_string(), value.into());
+    }
+
+    /// Put a value in the module's storage
+    pub fn set_storage(&self, key: &str, value: impl Into<Vec<u8>>) -> Result<(), StorageError> {
+        self.api.runtime.block_on(self.storage.insert(
+            self.module.name.clone(),
+            key.to_string(),
+            value.into(),
+        ))?;
+        Ok(())
+    }
+
+    /// Get a value from the module's storage
+    pub fn get_storage(&self, key: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        self.api
+            .runtime
+            .block_on(self.storage.get(&self.module.name, key))
+    }
+
+    /// Answer calls of the host function `function` (like `github_add_user_to_repo` or
+    /// `slack_post_to_named_webhook`) with `response`
+    pub fn set_response(&self, function: &str, response: CannedResponse) {
+        self.canned_responses
+            .set_function_response(function, response);
+    }
+
+    /// Answer named requests for `request_name` with `response`
+    pub fn set_named_request_response(&self, request_name: &str, response: CannedResponse) {
+        self.canned_responses
+            .set_named_request_response(request_name, response);
+    }
+
+    /// Send the module a message of its log type with `data`, from a webhook
+    pub fn send_data(&self, data: impl Into<Vec<u8>>) -> Result<TestExecution, TestRuntimeError> {
+        self.send(Message::new(
+            self.module.logtype.clone(),
+            data.into(),
+            LogSource::WebhookPost("test".to_string()),
+            LogbacksAllowed::Unlimited,
+        ))
+    }
+
+    /// Run the module on `message` and return what it did
+    pub fn send(&self, message: Message) -> Result<TestExecution, TestRuntimeError> {
+        let storage_before = self.storage_contents()?;
+        // Only calls made while processing this message are reported
+        self.canned_responses.take_calls();
+
+        let (els, logs) = Logger::capturing();
+        let (immediate_sender, immediate_logbacks) = PoolSender::unbounded();
+        let (delayed_log_sender, delayed_logbacks) = unbounded::<DelayedMessage>();
+
+        let execution = execute_standalone(
+            message,
+            self.module.clone(),
+            StandaloneContext {
+                api: self.api.clone(),
+                storage: Some(self.storage.clone()),
+                cache: None,
+                els,
+                immediate_sender: Some(immediate_sender),
+                delayed_log_sender,
+                scheduled_logbacks: Arc::new(ScheduledLogbacks::new(self.storage.clone())),
+                modules: self.modules.clone(),
+            },
+        )?;
+
+        let mut host_calls = vec![];
+        let mut other_logs = vec![];
+        for log in logs.try_iter() {
+            match log {
+                Log::HostFunctionCall { function, .. } => host_calls.push(function),
+                log => other_logs.push(log),
+            }
+        }
+
+        let storage_after = self.storage_contents()?;
+        let keys: BTreeSet<_> = storage_before.keys().chain(storage_after.keys()).collect();
+        let storage_mutations = keys
+            .into_iter()
+            .filter(|key| storage_before.get(*key) != storage_after.get(*key))
+            .map(|key| StorageMutation {
+                key: key.clone(),
+                before: storage_before.get(key).cloned(),
+                after: storage_after.get(key).cloned(),
+            })
+            .collect();
+
+        Ok(TestExecution {
+            response: execution.response,
+            computation_used: execution.computation_used,
+            host_calls,
+            api_calls: self.canned_responses.take_calls(),
+            storage_mutations,
+            logbacks: immediate_logbacks.try_iter().collect(),
+            delayed_logbacks: delayed_logbacks.try_iter().collect(),
+            logs: other_logs,
+        })
+    }
+
+    fn storage_contents(&self) -> Result<HashMap<String, Vec<u8>>, StorageError> {
+        let contents = self
+            .api
+            .runtime
+            .block_on(self.storage.fetch_all(&self.module.name, None))?;
+        Ok(contents
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, value?)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A module which answers with what a named request returned, stores the message it got
+    /// and logs it back to `next`
+    const MODULE: &str = r#"(module
+        (import "env" "fetch_data" (func $fetch_data (param i32 i32) (result i32)))
+        (import "env" "general_make_named_request" (func $named_request (param i32 i32 i32 i32) (result i32)))
+        (import "env" "storage_insert" (func $storage_insert (param i32 i32 i32 i32 i32 i32) (result i32)))
+        (import "env" "log_back" (func $log_back (param i32 i32 i32 i32 i32 i32) (result i32)))
+        (import "env" "set_response" (func $set_response (param i32 i32)))
+        (memory (export "memory") 1)
+        (data (i32.const 0) "{\"request_name\":\"get_user\",\"variables\":{}}")
+        (data (i32.const 64) "seen")
+        (data (i32.const 72) "next")
+        (func (export "entrypoint") (result i32)
+            (local $length i32)
+            (local $response i32)
+            (local.set $length (call $fetch_data (i32.const 256) (i32.const 64)))
+            (local.set $response (call $named_request (i32.const 0) (i32.const 42) (i32.const 512) (i32.const 64)))
+            (if (i32.lt_s (local.get $response) (i32.const 0))
+                (then (return (i32.const 1))))
+            (call $set_response (i32.const 512) (local.get $response))
+            (drop (call $storage_insert (i32.const 64) (i32.const 4) (i32.const 256) (local.get $length) (i32.const 0) (i32.const 0)))
+            (drop (call $log_back (i32.const 72) (i32.const 4) (i32.const 256) (local.get $length) (i32.const 0) (i32.const 0)))
+            i32.const 0))"#;
+
+    #[test]
+    fn modules_run_against_canned_responses() {
+        let runtime =
+            TestRuntime::from_bytes("rule.wasm", "rule", wat::parse_str(MODULE).unwrap()).unwrap();
+        runtime.set_named_request_response(
+            "get_user",
+            CannedResponse::Success(r#"{"name":"alice"}"#.to_string()),
+        );
+
+        let execution = runtime.send_data("hello").unwrap();
+        assert_eq!(
+            execution.response.unwrap_or_else(|e| panic!("{e}")),
+            Some(r#"{"name":"alice"}"#.to_string())
+        );
+        assert_eq!(
+            execution.host_calls,
+            vec!["general_make_named_request".to_string()]
+        );
+        assert_eq!(execution.api_calls.len(), 1);
+        assert_eq!(
+            execution.api_calls[0].params,
+            r#"{"request_name":"get_user","variables":{}}"#
+        );
+        assert_eq!(
+            execution.storage_mutations,
+            vec![StorageMutation {
+                key: "seen".to_string(),
+                before: None,
+                after: Some(b"hello".to_vec()),
+            }]
+        );
+        assert_eq!(execution.logbacks.len(), 1);
+        assert_eq!(execution.logbacks[0].type_, "next");
+        assert_eq!(execution.logbacks[0].data, b"hello");
+
+        // Storage is kept between messages, and calls are reported for each message
+        let execution = runtime.send_data("again").unwrap();
+        assert_eq!(execution.api_calls.len(), 1);
+        assert_eq!(
+            execution.storage_mutations[0].before,
+            Some(b"hello".to_vec())
+        );
+    }
+
+    #[test]
+    fn calls_without_a_canned_response_fail() {
+        let runtime =
+            TestRuntime::from_bytes("rule.wasm", "rule", wat::parse_str(MODULE).unwrap()).unwrap();
+
+        let execution = runtime.send_data("hello").unwrap();
+        assert!(matches!(
+            execution.response,
+            Err(ModuleExecutionError::ModuleError(_))
+        ));
+        assert_eq!(execution.api_calls[0].response, None);
+        assert!(execution.storage_mutations.is_empty());
+        assert!(execution.logbacks.is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+//! Trace contexts that follow a message through everything it causes to run, and the spans
+//! that record what ran. A message gets a new trace when it enters Plaid (unless its sender
+//! already started one) and every message it leads to, like logbacks and retries, stays in
+//! the same trace. Spans are exported by the logging system if an OTLP exporter is configured.
+
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+/// The trace a message belongs to and the span that caused it, if any
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TraceContext {
+    /// Hex encoded, 16 bytes
+    pub trace_id: String,
+    /// Hex encoded, 8 bytes. This is the span of the module execution that sent the message.
+    pub parent_span_id: Option<String>,
+}
+
+impl TraceContext {
+    /// Start a new trace
+    pub fn new_root() -> Self {
+        Self {
+            trace_id: hex::encode(rand::random::<[u8; 16]>()),
+            parent_span_id: None,
+        }
+    }
+
+    /// The context of a message sent by the span `parent_span_id` in this trace
+    pub fn child(&self, parent_span_id: &str) -> Self {
+        Self {
+            trace_id: self.trace_id.clone(),
+            parent_span_id: Some(parent_span_id.to_string()),
+        }
+    }
+
+    /// Continue a trace started by the sender of a message, from a W3C `traceparent`
+    /// header (e.g. `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`).
+    /// Returns `None` if the header is not valid.
+    pub fn from_traceparent(header: &str) -> Option<Self> {
+        let mut parts = header.trim().split('-');
+        let (version, trace_id, parent_id, _flags) =
+            (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+        if version != "00" || parts.next().is_some() {
+            return None;
+        }
+        let is_id = |id: &str, len: usize| {
+            id.len() == len
+                && id.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+                && id.chars().any(|c| c != '0')
+        };
+        if !is_id(trace_id, 32) || !is_id(parent_id, 16) {
+            return None;
+        }
+
+        Some(Self {
+            trace_id: trace_id.to_string(),
+            parent_span_id: Some(parent_id.to_string()),
+        })
+    }
+}
+
+/// Generate the ID of a new span
+pub fn new_span_id() -> String {
+    hex::encode(rand::random::<[u8; 8]>())
+}
+
+/// A finished unit of work in a trace, like a module processing a message or a host function call
+#[derive(Serialize, Debug, Clone)]
+pub struct Span {
+    pub trace_id: String,
+    pub span_id: String,
+    pub parent_span_id: Option<String>,
+    pub name: String,
+    pub start: SystemTime,
+    pub end: SystemTime,
+    pub attributes: BTreeMap<String, String>,
+    /// Why the work failed, if it did
+    pub error: Option<String>,
+}
+
+impl Span {
+    /// Start a span in the trace `trace_id`. `parent_span_id` is the span it is part of, if any.
+    pub fn start(
+        name: impl Into<String>,
+        trace_id: &str,
+        span_id: String,
+        parent_span_id: Option<String>,
+    ) -> Self {
+        let now = SystemTime::now();
+        Self {
+            trace_id: trace_id.to_string(),
+            span_id,
+            parent_span_id,
+            name: name.into(),
+            start: now,
+            end: now,
+            attributes: BTreeMap::new(),
+            error: None,
+        }
+    }
+
+    pub fn with_attribute(mut self, key: &str, value: impl ToString) -> Self {
+        self.attributes.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Mark the span as finished now
+    pub fn finish(mut self, error: Option<String>) -> Self {
+        self.end = SystemTime::now();
+        self.error = error;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traceparent_is_parsed() {
+        let context = TraceContext::from_traceparent(
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        )
+        .unwrap();
+        assert_eq!(context.trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(context.parent_span_id.as_deref(), Some("00f067aa0ba902b7"));
+
+        for invalid in [
+            "",
+            "01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-00",
+        ] {
+            assert_eq!(TraceContext::from_traceparent(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn children_stay_in_the_trace() {
+        let root = TraceContext::new_root();
+        assert_eq!(root.trace_id.len(), 32);
+        assert_eq!(root.parent_span_id, None);
+
+        let span_id = new_span_id();
+        assert_eq!(span_id.len(), 16);
+        let child = root.child(&span_id);
+        assert_eq!(child.trace_id, root.trace_id);
+        assert_eq!(child.parent_span_id, Some(span_id));
+    }
+}
@@ -0,0 +1,550 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use plaid_stl::messages::LogSource;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+use crate::executor::Message;
+use crate::recording::RecordedMessage;
+use crate::storage::{Storage, StorageError};
+
+/// Where the write-ahead log is kept. Each instance must have its own log: an instance
+/// replays every unfinished message in its log when it starts.
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteAheadLogConfig {
+    /// Append the messages to this file
+    File(String),
+    /// Insert the messages into this storage namespace
+    Storage(String),
+}
+
+/// A line of the write-ahead log file
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum FileRecord {
+    /// A message was received
+    Received(Box<RecordedMessage>),
+    /// All the modules processed the message with this ID
+    Processed(String),
+}
+
+/// Errors encountered while writing to or reading the write-ahead log
+#[derive(Debug)]
+pub enum WriteAheadLogError {
+    IoError(std::io::Error),
+    SerializationError(serde_json::Error),
+    StorageError(StorageError),
+}
+
+impl std::fmt::Display for WriteAheadLogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IoError(e) => write!(f, "I/O error: {e}"),
+            Self::SerializationError(e) => write!(f, "Could not (de)serialize message: {e}"),
+            Self::StorageError(e) => write!(f, "Storage error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for WriteAheadLogError {}
+
+impl From<std::io::Error> for WriteAheadLogError {
+    fn from(e: std::io::Error) -> Self {
+        Self::IoError(e)
+    }
+}
+
+impl From<serde_json::Error> for WriteAheadLogError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::SerializationError(e)
+    }
+}
+
+impl From<StorageError> for WriteAheadLogError {
+    fn from(e: StorageError) -> Self {
+        Self::StorageError(e)
+    }
+}
+
+/// How many commands the writer of a log file handles at once. The messages appended by
+/// these commands are synced to disk together.
+const MAX_WRITER_BATCH: usize = 256;
+
+/// The write-ahead log file, and the messages in it which are not processed yet. It is only
+/// touched by its writer thread.
+struct LogFile {
+    file: File,
+    path: String,
+    /// When each unfinished message was received, by ID
+    pending: HashMap<String, RecordedMessage>,
+}
+
+/// Where the writer of a log file says whether an appended message is on disk
+type Acknowledgement = oneshot::Sender<Result<(), WriteAheadLogError>>;
+
+/// What the writer of a log file is asked to do
+enum FileCommand {
+    /// Append a message, and say once it is on disk
+    Append {
+        recorded: Box<RecordedMessage>,
+        written: Acknowledgement,
+    },
+    /// Remove the message with this ID
+    Complete(String),
+    /// Send the unfinished messages
+    Unfinished(oneshot::Sender<Vec<RecordedMessage>>),
+}
+
+enum Backend {
+    /// The file is written by its own thread, so webhooks never wait on the disk while holding
+    /// up the async runtime
+    File(Sender<FileCommand>),
+    Storage {
+        storage: Arc<Storage>,
+        namespace: String,
+    },
+}
+
+/// Persists the messages received by webhooks before they are acknowledged, until all the
+/// modules have processed them, so that the ones still queued when Plaid stops (or crashes)
+/// are processed when it starts again.
+pub struct WriteAheadLog {
+    backend: Backend,
+}
+
+impl WriteAheadLog {
+    /// Open the write-ahead log. `storage` is where the log is kept if it is kept in a
+    /// storage namespace. A log file is compacted to the messages it has not finished.
+    pub fn new(
+        config: &WriteAheadLogConfig,
+        storage: Arc<Storage>,
+    ) -> Result<Self, WriteAheadLogError> {
+        let backend = match config {
+            WriteAheadLogConfig::File(path) => {
+                let pending = match File::open(path) {
+                    Ok(file) => read_pending(file)?,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+                    Err(e) => return Err(e.into()),
+                };
+
+                compact(path, &pending)?;
+                let file = OpenOptions::new().append(true).open(path)?;
+
+                let log_file = LogFile {
+                    file,
+                    path: path.clone(),
+                    pending,
+                };
+                let (commands, receiver) = unbounded();
+                thread::spawn(move || write_log_file(log_file, receiver));
+                Backend::File(commands)
+            }
+            WriteAheadLogConfig::Storage(namespace) => Backend::Storage {
+                storage,
+                namespace: namespace.clone(),
+            },
+        };
+
+        Ok(Self { backend })
+    }
+
+    /// Whether a message goes through the write-ahead log. Only the messages received by
+    /// webhooks do: the others are either persisted elsewhere (like logbacks) or produced
+    /// again if they are lost (like the ones from data generators).
+    pub fn is_logged(message: &Message) -> bool {
+        matches!(message.source, LogSource::WebhookPost(_))
+            && message.retry.is_none()
+            && message.module.is_none()
+    }
+
+    /// Persist a message before it is acknowledged
+    pub async fn append(&self, message: &Message) -> Result<(), WriteAheadLogError> {
+        let recorded = RecordedMessage::new(message.create_duplicate());
+
+        match &self.backend {
+            Backend::File(commands) => {
+                let (written, on_disk) = oneshot::channel();
+                commands
+                    .send(FileCommand::Append {
+                        recorded: Box::new(recorded),
+                        written,
+                    })
+                    .map_err(|_| writer_stopped())?;
+                on_disk.await.map_err(|_| writer_stopped())??;
+            }
+            Backend::Storage { storage, namespace } => {
+                storage
+                    .insert(
+                        namespace.clone(),
+                        message.id.clone(),
+                        serde_json::to_vec(&recorded)?,
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove a message from the log, once all the modules processed it or if it could not be
+    /// queued. Failures are logged rather than returned: the worst that can happen is that the
+    /// message is processed again when Plaid restarts.
+    pub async fn complete(&self, id: &str) {
+        if let Err(e) = self.remove(id).await {
+            error!("Failed to remove message [{id}] from the write-ahead log. Error: {e}");
+        }
+    }
+
+    async fn remove(&self, id: &str) -> Result<(), WriteAheadLogError> {
+        match &self.backend {
+            // The writer logs its own failures. Nothing waits for the removal to be on disk.
+            Backend::File(commands) => commands
+                .send(FileCommand::Complete(id.to_string()))
+                .map_err(|_| writer_stopped())?,
+            Backend::Storage { storage, namespace } => {
+                storage.delete(namespace, id).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The messages which were received but not processed by all the modules, oldest first.
+    /// They stay in the log until they are completed.
+    pub async fn unfinished(&self) -> Result<Vec<Message>, WriteAheadLogError> {
+        let mut unfinished = match &self.backend {
+            Backend::File(commands) => {
+                let (sender, unfinished) = oneshot::channel();
+                commands
+                    .send(FileCommand::Unfinished(sender))
+                    .map_err(|_| writer_stopped())?;
+                unfinished.await.map_err(|_| writer_stopped())?
+            }
+            Backend::Storage { storage, namespace } => {
+                let mut unfinished = vec![];
+                for (key, value) in storage.fetch_all(namespace, None).await? {
+                    match value.map(|value| serde_json::from_slice::<RecordedMessage>(&value)) {
+                        Some(Ok(recorded)) => unfinished.push(recorded),
+                        _ => {
+                            warn!("Removing message [{key}] from the write-ahead log: it could not be read");
+                            storage.delete(namespace, &key).await?;
+                        }
+                    }
+                }
+                unfinished
+            }
+        };
+
+        unfinished.sort_by_key(|recorded: &RecordedMessage| recorded.sequence);
+        Ok(unfinished
+            .into_iter()
+            .map(|recorded| recorded.message)
+            .collect())
+    }
+}
+
+/// The error given when the writer of the log file is gone
+fn writer_stopped() -> WriteAheadLogError {
+    WriteAheadLogError::IoError(std::io::Error::other(
+        "the write-ahead log writer has stopped",
+    ))
+}
+
+/// Write to a log file until the log is dropped. Commands are handled in batches, and the
+/// messages a batch appends are synced to disk once, before any of them is acknowledged.
+fn write_log_file(mut log_file: LogFile, commands: Receiver<FileCommand>) {
+    while let Ok(command) = commands.recv() {
+        let mut batch = vec![command];
+        batch.extend(commands.try_iter().take(MAX_WRITER_BATCH - 1));
+
+        // Appends are written after the rest of the batch, so that a failed sync can be
+        // rolled back by cutting the file where they start. A message is only completed
+        // once its append is acknowledged, so it is never completed in the same batch.
+        let mut appends = vec![];
+        for command in batch {
+            match command {
+                FileCommand::Append { recorded, written } => appends.push((recorded, written)),
+                FileCommand::Complete(id) => {
+                    if let Err(e) = complete_line(&mut log_file, &id) {
+                        error!(
+                            "Failed to remove message [{id}] from the write-ahead log. Error: {e}"
+                        );
+                    }
+                }
+                FileCommand::Unfinished(sender) => {
+                    debug!("Reading unfinished messages from [{}]", log_file.path);
+                    let _ = sender.send(
+                        log_file
+                            .pending
+                            .values()
+                            .map(RecordedMessage::create_duplicate)
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        if !appends.is_empty() {
+            append_lines(&mut log_file, appends);
+        }
+    }
+}
+
+/// Append received messages to a log file and sync them to disk. If they cannot all be
+/// synced, the file is cut back to where they start and every one of them is reported as
+/// failed, so none of them is replayed when Plaid restarts.
+fn append_lines(log_file: &mut LogFile, appends: Vec<(Box<RecordedMessage>, Acknowledgement)>) {
+    let mut lines = vec![];
+    let mut appended = vec![];
+    for (recorded, written) in appends {
+        match serde_json::to_vec(&FileRecord::Received(Box::new(recorded.create_duplicate()))) {
+            Ok(line) => {
+                lines.extend(line);
+                lines.push(b'\n');
+                appended.push((recorded, written));
+            }
+            Err(e) => {
+                let _ = written.send(Err(e.into()));
+            }
+        }
+    }
+    if appended.is_empty() {
+        return;
+    }
+
+    let written = log_file.file.metadata().and_then(|metadata| {
+        let start = metadata.len();
+        let result = log_file
+            .file
+            .write_all(&lines)
+            .and_then(|()| log_file.file.sync_data());
+        if result.is_err() {
+            // If this fails too, the messages may be replayed when Plaid restarts
+            if let Err(e) = log_file.file.set_len(start) {
+                error!(
+                    "Failed to roll back the write-ahead log [{}]. Error: {e}",
+                    log_file.path
+                );
+            }
+        }
+        result
+    });
+
+    match written {
+        Ok(()) => {
+            for (recorded, written) in appended {
+                log_file
+                    .pending
+                    .insert(recorded.message.id.clone(), *recorded);
+                let _ = written.send(Ok(()));
+            }
+        }
+        Err(e) => {
+            error!(
+                "Failed to sync the write-ahead log [{}]. Error: {e}",
+                log_file.path
+            );
+            // The messages are not acknowledged, so they are not kept either
+            for (_, written) in appended {
+                let _ = written.send(Err(std::io::Error::new(e.kind(), e.to_string()).into()));
+            }
+        }
+    }
+}
+
+/// Rewrite a log file with only its unfinished messages. They are written to a file next to
+/// it, which replaces it once it is on disk, so the log is never without them.
+fn compact(
+    path: &str,
+    pending: &HashMap<String, RecordedMessage>,
+) -> Result<(), WriteAheadLogError> {
+    let compacted = format!("{path}.compacting");
+    let mut file = File::create(&compacted)?;
+    for recorded in pending.values() {
+        let line =
+            serde_json::to_vec(&FileRecord::Received(Box::new(recorded.create_duplicate())))?;
+        file.write_all(&line)?;
+        file.write_all(b"\n")?;
+    }
+    file.sync_all()?;
+    std::fs::rename(&compacted, path)?;
+
+    // The rename is only durable once the directory is synced
+    let directory = match Path::new(path).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    File::open(directory)?.sync_all()?;
+    Ok(())
+}
+
+/// Mark a message of a log file as processed
+fn complete_line(log_file: &mut LogFile, id: &str) -> Result<(), WriteAheadLogError> {
+    if log_file.pending.remove(id).is_none() {
+        return Ok(());
+    }
+    if log_file.pending.is_empty() {
+        // Nothing is unfinished anymore, so nothing in the file is needed
+        log_file.file.set_len(0)?;
+    } else {
+        let mut line = serde_json::to_vec(&FileRecord::Processed(id.to_string()))?;
+        line.push(b'\n');
+        log_file.file.write_all(&line)?;
+    }
+    Ok(())
+}
+
+/// Read the messages in a write-ahead log file which were not processed
+fn read_pending(file: File) -> Result<HashMap<String, RecordedMessage>, WriteAheadLogError> {
+    let mut pending = HashMap::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        // The last line can be cut short if Plaid stopped while writing it, in which case the
+        // message was never acknowledged
+        match serde_json::from_str(&line) {
+            Ok(FileRecord::Received(recorded)) => {
+                pending.insert(recorded.message.id.clone(), *recorded);
+            }
+            Ok(FileRecord::Processed(id)) => {
+                pending.remove(&id);
+            }
+            Err(e) => warn!("Skipping a line of the write-ahead log which could not be read: {e}"),
+        }
+    }
+    Ok(pending)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::future::join_all;
+
+    use super::*;
+    use crate::testing::fixtures::webhook_message;
+
+    fn message(data: &str) -> Message {
+        webhook_message("test", "test", data.as_bytes())
+    }
+
+    #[tokio::test]
+    async fn unfinished_messages_survive_a_restart() {
+        let path = std::env::temp_dir().join(format!("plaid_wal_{}", uuid::Uuid::new_v4()));
+        let config = WriteAheadLogConfig::File(path.to_str().unwrap().to_string());
+        let storage = Arc::new(Storage::new_in_memory());
+
+        let wal = WriteAheadLog::new(&config, storage.clone()).unwrap();
+        let (first, second, third) = (message("first"), message("second"), message("third"));
+        wal.append(&first).await.unwrap();
+        wal.append(&second).await.unwrap();
+        wal.append(&third).await.unwrap();
+        wal.complete(&second.id).await;
+        // The writer handles commands in order, so once this returns the removal is written
+        assert_eq!(wal.unfinished().await.unwrap().len(), 2);
+        drop(wal);
+
+        let wal = WriteAheadLog::new(&config, storage.clone()).unwrap();
+        let mut unfinished: Vec<Vec<u8>> = wal
+            .unfinished()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|message| message.data)
+            .collect();
+        unfinished.sort();
+        assert_eq!(unfinished, vec![b"first".to_vec(), b"third".to_vec()]);
+
+        // Once everything is processed, the file is emptied
+        wal.complete(&first.id).await;
+        wal.complete(&third.id).await;
+        assert!(wal.unfinished().await.unwrap().is_empty());
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 0);
+        std::fs::remove_file(&path).unwrap();
+
+        let config = WriteAheadLogConfig::Storage("wal".to_string());
+        let wal = WriteAheadLog::new(&config, storage).unwrap();
+        wal.append(&first).await.unwrap();
+        wal.append(&second).await.unwrap();
+        wal.complete(&first.id).await;
+        let unfinished = wal.unfinished().await.unwrap();
+        assert_eq!(unfinished.len(), 1);
+        assert_eq!(unfinished[0].id, second.id);
+    }
+
+    #[tokio::test]
+    async fn compaction_replaces_the_log_once_the_unfinished_messages_are_on_disk() {
+        let path = std::env::temp_dir().join(format!("plaid_wal_{}", uuid::Uuid::new_v4()));
+        let config = WriteAheadLogConfig::File(path.to_str().unwrap().to_string());
+        let storage = Arc::new(Storage::new_in_memory());
+        let compacted = format!("{}.compacting", path.to_str().unwrap());
+
+        let wal = WriteAheadLog::new(&config, storage.clone()).unwrap();
+        let (first, second) = (message("first"), message("second"));
+        wal.append(&first).await.unwrap();
+        wal.append(&second).await.unwrap();
+        wal.complete(&first.id).await;
+        wal.unfinished().await.unwrap();
+        drop(wal);
+
+        // A compaction which was interrupted left its file behind
+        std::fs::write(&compacted, b"partial").unwrap();
+
+        let wal = WriteAheadLog::new(&config, storage).unwrap();
+        let unfinished = wal.unfinished().await.unwrap();
+        assert_eq!(unfinished.len(), 1);
+        assert_eq!(unfinished[0].id, second.id);
+        assert!(!Path::new(&compacted).exists());
+
+        let on_disk = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(on_disk.lines().count(), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn appends_which_cannot_be_written_are_reported_and_not_kept() {
+        let path = std::env::temp_dir().join(format!("plaid_wal_{}", uuid::Uuid::new_v4()));
+        std::fs::write(&path, b"").unwrap();
+        // Writing to a file opened for reading fails
+        let mut log_file = LogFile {
+            file: File::open(&path).unwrap(),
+            path: path.to_str().unwrap().to_string(),
+            pending: HashMap::new(),
+        };
+
+        let (written, on_disk) = oneshot::channel();
+        let recorded = RecordedMessage::new(message("lost"));
+        append_lines(&mut log_file, vec![(Box::new(recorded), written)]);
+
+        assert!(on_disk.await.unwrap().is_err());
+        assert!(log_file.pending.is_empty());
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 0);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn concurrent_appends_are_all_on_disk_once_acknowledged() {
+        let path = std::env::temp_dir().join(format!("plaid_wal_{}", uuid::Uuid::new_v4()));
+        let config = WriteAheadLogConfig::File(path.to_str().unwrap().to_string());
+        let storage = Arc::new(Storage::new_in_memory());
+
+        let wal = WriteAheadLog::new(&config, storage.clone()).unwrap();
+        let messages: Vec<Message> = (0..50).map(|i| message(&i.to_string())).collect();
+        for result in join_all(messages.iter().map(|message| wal.append(message))).await {
+            result.unwrap();
+        }
+
+        // Read the file itself, rather than asking the writer
+        let on_disk = read_pending(File::open(&path).unwrap()).unwrap();
+        assert_eq!(on_disk.len(), messages.len());
+        assert!(messages
+            .iter()
+            .all(|message| on_disk.contains_key(&message.id)));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1 @@
+const RUST_VERSION: &str = "1.95.0";
\ No newline at end of file
@@ -0,0 +1,3 @@
+vec![
+  "/root/crate/runtime/target-base/debug/build/cranelift-assembler-x64-a5e66db21b0a9919/out/assembler.rs".into(),
+]
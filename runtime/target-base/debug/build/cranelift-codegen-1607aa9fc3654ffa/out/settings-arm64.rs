@@ -0,0 +1,174 @@
+#[derive(Clone, PartialEq, Hash)] // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:397
+/// Flags group `arm64`.
+pub struct Flags {
+    bytes: [u8; 1], // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:400
+}
+impl Flags {
+    /// Create flags arm64 settings group.
+    #[allow(unused_variables, reason = "generated code")] // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:24
+    pub fn new(shared: &settings::Flags, builder: &Builder) -> Self {
+        let bvec = builder.state_for("arm64"); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:29
+        let mut arm64 = Self { bytes: [0; 1] }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:30
+        debug_assert_eq!(bvec.len(), 1); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:36
+        arm64.bytes[0..1].copy_from_slice(&bvec); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:41
+        arm64 // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:48
+    }
+}
+impl Flags {
+    /// Iterates the setting values.
+    pub fn iter(&self) -> impl Iterator<Item = Value> + use<> {
+        let mut bytes = [0; 1]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:58
+        bytes.copy_from_slice(&self.bytes[0..1]); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:59
+        DESCRIPTORS.iter().filter_map(move |d| {
+            let values = match &d.detail {
+                detail::Detail::Preset => return None, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:62
+                detail::Detail::Enum { last, enumerators } => Some(TEMPLATE.enums(*last, *enumerators)), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:63
+                _ => None // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:64
+            }
+            ; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:66
+            Some(Value { name: d.name, detail: d.detail, values, value: bytes[d.offset as usize] }) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:67
+        }
+        ) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:69
+    }
+}
+/// User-defined settings.
+#[allow(dead_code, reason = "generated code")] // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:183
+impl Flags {
+    /// Dynamic numbered predicate getter.
+    fn numbered_predicate(&self, p: usize) -> bool {
+        self.bytes[0 + p / 8] & (1 << (p % 8)) != 0 // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:188
+    }
+    /// Has Large System Extensions (FEAT_LSE) support.
+    pub fn has_lse(&self) -> bool {
+        self.numbered_predicate(0) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// Has Pointer authentication (FEAT_PAuth) support; enables the use of non-HINT instructions, but does not have an effect on code generation by itself.
+    pub fn has_pauth(&self) -> bool {
+        self.numbered_predicate(1) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// Use half-precision floating point (FEAT_FP16) instructions.
+    pub fn has_fp16(&self) -> bool {
+        self.numbered_predicate(2) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// If function return address signing is enabled, then apply it to all functions; does not have an effect on code generation by itself.
+    pub fn sign_return_address_all(&self) -> bool {
+        self.numbered_predicate(3) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// Use pointer authentication instructions to sign function return addresses; HINT-space instructions using the A key are generated and simple functions that do not use the stack are not affected unless overridden by other settings.
+    pub fn sign_return_address(&self) -> bool {
+        self.numbered_predicate(4) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// Use the B key with pointer authentication instructions instead of the default A key; does not have an effect on code generation by itself. Some platform ABIs may require this, for example.
+    pub fn sign_return_address_with_bkey(&self) -> bool {
+        self.numbered_predicate(5) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// Use Branch Target Identification (FEAT_BTI) instructions.
+    pub fn use_bti(&self) -> bool {
+        self.numbered_predicate(6) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+}
+static DESCRIPTORS: [detail::Descriptor; 7] = [ // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:224
+    detail::Descriptor {
+        name: "has_lse", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "Has Large System Extensions (FEAT_LSE) support.", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 0 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_pauth", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "Has Pointer authentication (FEAT_PAuth) support; enables the use of non-HINT instructions, but does not have an effect on code generation by itself.", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 1 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_fp16", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "Use half-precision floating point (FEAT_FP16) instructions.", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 2 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "sign_return_address_all", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "If function return address signing is enabled, then apply it to all functions; does not have an effect on code generation by itself.", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 3 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "sign_return_address", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "Use pointer authentication instructions to sign function return addresses; HINT-space instructions using the A key are generated and simple functions that do not use the stack are not affected unless overridden by other settings.", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 4 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "sign_return_address_with_bkey", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "Use the B key with pointer authentication instructions instead of the default A key; does not have an effect on code generation by itself. Some platform ABIs may require this, for example.", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 5 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "use_bti", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "Use Branch Target Identification (FEAT_BTI) instructions.", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 6 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:275
+static ENUMERATORS: [&str; 0] = [ // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:278
+]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:284
+static HASH_TABLE: [u16; 16] = [ // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:294
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    5, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    6, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    4, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:310
+static PRESETS: [(u8, u8); 0] = [ // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:313
+]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:330
+static TEMPLATE: detail::Template = detail::Template {
+    name: "arm64", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:345
+    descriptors: &DESCRIPTORS, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:346
+    enumerators: &ENUMERATORS, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:347
+    hash_table: &HASH_TABLE, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:348
+    defaults: &[0x00], // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:349
+    presets: &PRESETS, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:350
+}
+; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:353
+/// Create a `settings::Builder` for the arm64 settings group.
+pub fn builder() -> Builder {
+    Builder::new(&TEMPLATE) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:360
+}
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "[arm64]")?; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:369
+        for d in &DESCRIPTORS {
+            if !d.detail.is_preset() {
+                write!(f, "{} = ", d.name)?; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:372
+                TEMPLATE.format_toml_value(d.detail, self.bytes[d.offset as usize], f)?; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:373
+                writeln!(f)?; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:377
+            }
+        }
+        Ok(()) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:380
+    }
+}
+impl Flags {
+    /// Get the flag values as raw bytes for hashing.
+    pub fn hash_key(&self) -> &[u8] {
+        &self.bytes // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:390
+    }
+}
@@ -0,0 +1,685 @@
+#[derive(Clone, PartialEq, Hash)] // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:397
+/// Flags group `riscv64`.
+pub struct Flags {
+    bytes: [u8; 4], // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:400
+}
+impl Flags {
+    /// Create flags riscv64 settings group.
+    #[allow(unused_variables, reason = "generated code")] // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:24
+    pub fn new(shared: &settings::Flags, builder: &Builder) -> Self {
+        let bvec = builder.state_for("riscv64"); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:29
+        let mut riscv64 = Self { bytes: [0; 4] }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:30
+        debug_assert_eq!(bvec.len(), 4); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:36
+        riscv64.bytes[0..4].copy_from_slice(&bvec); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:41
+        riscv64 // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:48
+    }
+}
+impl Flags {
+    /// Iterates the setting values.
+    pub fn iter(&self) -> impl Iterator<Item = Value> + use<> {
+        let mut bytes = [0; 4]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:58
+        bytes.copy_from_slice(&self.bytes[0..4]); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:59
+        DESCRIPTORS.iter().filter_map(move |d| {
+            let values = match &d.detail {
+                detail::Detail::Preset => return None, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:62
+                detail::Detail::Enum { last, enumerators } => Some(TEMPLATE.enums(*last, *enumerators)), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:63
+                _ => None // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:64
+            }
+            ; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:66
+            Some(Value { name: d.name, detail: d.detail, values, value: bytes[d.offset as usize] }) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:67
+        }
+        ) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:69
+    }
+}
+/// User-defined settings.
+#[allow(dead_code, reason = "generated code")] // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:183
+impl Flags {
+    /// Dynamic numbered predicate getter.
+    fn numbered_predicate(&self, p: usize) -> bool {
+        self.bytes[0 + p / 8] & (1 << (p % 8)) != 0 // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:188
+    }
+    /// has extension M?
+    /// Integer multiplication and division
+    pub fn has_m(&self) -> bool {
+        self.numbered_predicate(0) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension A?
+    /// Atomic instructions
+    pub fn has_a(&self) -> bool {
+        self.numbered_predicate(1) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension F?
+    /// Single-precision floating point
+    pub fn has_f(&self) -> bool {
+        self.numbered_predicate(2) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension D?
+    /// Double-precision floating point
+    pub fn has_d(&self) -> bool {
+        self.numbered_predicate(3) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zfa?
+    /// Zfa: Extension for Additional Floating-Point Instructions
+    pub fn has_zfa(&self) -> bool {
+        self.numbered_predicate(4) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zfhmin?
+    /// Zfhmin: Minimal Half-Precision Floating-Point
+    pub fn has_zfhmin(&self) -> bool {
+        self.numbered_predicate(5) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zfh?
+    /// Zfh: Half-Precision Floating-Point Instructions
+    pub fn has_zfh(&self) -> bool {
+        self.numbered_predicate(6) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension V?
+    /// Vector instruction support
+    pub fn has_v(&self) -> bool {
+        self.numbered_predicate(7) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvfh?
+    /// Zvfh: Vector Extension for Half-Precision Floating-Point
+    pub fn has_zvfh(&self) -> bool {
+        self.numbered_predicate(8) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zca?
+    /// Zca is the C extension without floating point loads
+    pub fn has_zca(&self) -> bool {
+        self.numbered_predicate(9) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zcd?
+    /// Zcd contains only the double precision floating point loads from the C extension
+    pub fn has_zcd(&self) -> bool {
+        self.numbered_predicate(10) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zcb?
+    /// Zcb: Extra compressed instructions
+    pub fn has_zcb(&self) -> bool {
+        self.numbered_predicate(11) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension zbkb?
+    /// Zbkb: Bit-manipulation for Cryptography
+    pub fn has_zbkb(&self) -> bool {
+        self.numbered_predicate(12) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension zba?
+    /// Zba: Address Generation
+    pub fn has_zba(&self) -> bool {
+        self.numbered_predicate(13) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension zbb?
+    /// Zbb: Basic bit-manipulation
+    pub fn has_zbb(&self) -> bool {
+        self.numbered_predicate(14) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension zbc?
+    /// Zbc: Carry-less multiplication
+    pub fn has_zbc(&self) -> bool {
+        self.numbered_predicate(15) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension zbs?
+    /// Zbs: Single-bit instructions
+    pub fn has_zbs(&self) -> bool {
+        self.numbered_predicate(16) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension zicond?
+    /// ZiCond: Integer Conditional Operations
+    pub fn has_zicond(&self) -> bool {
+        self.numbered_predicate(17) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension zicsr?
+    /// Zicsr: Control and Status Register (CSR) Instructions
+    pub fn has_zicsr(&self) -> bool {
+        self.numbered_predicate(18) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension zifencei?
+    /// Zifencei: Instruction-Fetch Fence
+    pub fn has_zifencei(&self) -> bool {
+        self.numbered_predicate(19) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl32b?
+    /// Zvl32b: Vector register has a minimum of 32 bits
+    pub fn has_zvl32b(&self) -> bool {
+        self.numbered_predicate(20) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl64b?
+    /// Zvl64b: Vector register has a minimum of 64 bits
+    pub fn has_zvl64b(&self) -> bool {
+        self.numbered_predicate(21) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl128b?
+    /// Zvl128b: Vector register has a minimum of 128 bits
+    pub fn has_zvl128b(&self) -> bool {
+        self.numbered_predicate(22) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl256b?
+    /// Zvl256b: Vector register has a minimum of 256 bits
+    pub fn has_zvl256b(&self) -> bool {
+        self.numbered_predicate(23) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl512b?
+    /// Zvl512b: Vector register has a minimum of 512 bits
+    pub fn has_zvl512b(&self) -> bool {
+        self.numbered_predicate(24) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl1024b?
+    /// Zvl1024b: Vector register has a minimum of 1024 bits
+    pub fn has_zvl1024b(&self) -> bool {
+        self.numbered_predicate(25) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl2048b?
+    /// Zvl2048b: Vector register has a minimum of 2048 bits
+    pub fn has_zvl2048b(&self) -> bool {
+        self.numbered_predicate(26) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl4096b?
+    /// Zvl4096b: Vector register has a minimum of 4096 bits
+    pub fn has_zvl4096b(&self) -> bool {
+        self.numbered_predicate(27) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl8192b?
+    /// Zvl8192b: Vector register has a minimum of 8192 bits
+    pub fn has_zvl8192b(&self) -> bool {
+        self.numbered_predicate(28) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl16384b?
+    /// Zvl16384b: Vector register has a minimum of 16384 bits
+    pub fn has_zvl16384b(&self) -> bool {
+        self.numbered_predicate(29) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl32768b?
+    /// Zvl32768b: Vector register has a minimum of 32768 bits
+    pub fn has_zvl32768b(&self) -> bool {
+        self.numbered_predicate(30) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+    /// has extension Zvl65536b?
+    /// Zvl65536b: Vector register has a minimum of 65536 bits
+    pub fn has_zvl65536b(&self) -> bool {
+        self.numbered_predicate(31) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:155
+    }
+}
+static DESCRIPTORS: [detail::Descriptor; 45] = [ // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:224
+    detail::Descriptor {
+        name: "has_m", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension M?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 0 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_a", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension A?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 1 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_f", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension F?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 2 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_d", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension D?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 3 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zfa", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zfa?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 4 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zfhmin", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zfhmin?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 5 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zfh", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zfh?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 6 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_v", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension V?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 7 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvfh", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvfh?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 0 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zca", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zca?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 1 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zcd", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zcd?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 2 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zcb", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zcb?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 3 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zbkb", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension zbkb?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 4 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zba", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension zba?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 5 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zbb", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension zbb?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 6 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zbc", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension zbc?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 7 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zbs", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension zbs?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 0 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zicond", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension zicond?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 1 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zicsr", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension zicsr?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 2 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zifencei", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension zifencei?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 3 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl32b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl32b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 4 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl64b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl64b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 5 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl128b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl128b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 6 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl256b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl256b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 7 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl512b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl512b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 0 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl1024b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl1024b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 1 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl2048b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl2048b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 2 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl4096b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl4096b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 3 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl8192b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl8192b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 4 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl16384b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl16384b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 5 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl32768b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl32768b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 6 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_zvl65536b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:232
+        description: "has extension Zvl65536b?", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:233
+        offset: 3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:234
+        detail: detail::Detail::Bool { bit: 7 }, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:237
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:259
+    detail::Descriptor {
+        name: "has_c", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Support for compressed instructions", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl32b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 32 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 4, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl64b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 64 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 8, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl128b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 128 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 12, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl256b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 256 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 16, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl512b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 512 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 20, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl1024b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 1024 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 24, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl2048b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 2048 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 28, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl4096b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 4096 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 32, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl8192b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 8192 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 36, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl16384b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 16384 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 40, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl32768b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 32768 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 44, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+    detail::Descriptor {
+        name: "zvl65536b", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:264
+        description: "Has a vector register size of at least 65536 bits", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:265
+        offset: 48, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:266
+        detail: detail::Detail::Preset, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:267
+    }
+    , // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:269
+]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:275
+static ENUMERATORS: [&str; 0] = [ // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:278
+]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:284
+static HASH_TABLE: [u16; 64] = [ // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:294
+    26, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    20, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    24, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    42, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    44, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    30, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    4, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    14, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    11, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    9, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    13, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    15, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    6, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    10, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    35, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    36, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    28, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    18, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    37, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    5, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    7, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    27, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    19, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    12, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    22, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    33, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    23, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    32, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    31, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    1, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    34, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    2, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    43, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    3, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    8, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    38, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    25, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    29, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    17, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    16, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    0xffff, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:306
+    40, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    39, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    21, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+    41, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:298
+]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:310
+static PRESETS: [(u8, u8); 52] = [ // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:313
+    // has_c: has_zca, has_zcd
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000110, 0b00000110), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl32b: has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00010000, 0b00010000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl64b: has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00110000, 0b00110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl128b: has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b01110000, 0b01110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl256b: has_zvl256b, has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11110000, 0b11110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl512b: has_zvl512b, has_zvl256b, has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11110000, 0b11110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000001, 0b00000001), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl1024b: has_zvl1024b, has_zvl512b, has_zvl256b, has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11110000, 0b11110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000011, 0b00000011), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl2048b: has_zvl2048b, has_zvl1024b, has_zvl512b, has_zvl256b, has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11110000, 0b11110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000111, 0b00000111), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl4096b: has_zvl4096b, has_zvl2048b, has_zvl1024b, has_zvl512b, has_zvl256b, has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11110000, 0b11110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00001111, 0b00001111), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl8192b: has_zvl8192b, has_zvl4096b, has_zvl2048b, has_zvl1024b, has_zvl512b, has_zvl256b, has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11110000, 0b11110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00011111, 0b00011111), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl16384b: has_zvl16384b, has_zvl8192b, has_zvl4096b, has_zvl2048b, has_zvl1024b, has_zvl512b, has_zvl256b, has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11110000, 0b11110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00111111, 0b00111111), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl32768b: has_zvl32768b, has_zvl16384b, has_zvl8192b, has_zvl4096b, has_zvl2048b, has_zvl1024b, has_zvl512b, has_zvl256b, has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11110000, 0b11110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b01111111, 0b01111111), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    // zvl65536b: has_zvl65536b, has_zvl32768b, has_zvl16384b, has_zvl8192b, has_zvl4096b, has_zvl2048b, has_zvl1024b, has_zvl512b, has_zvl256b, has_zvl128b, has_zvl64b, has_zvl32b
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b00000000, 0b00000000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11110000, 0b11110000), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+    (0b11111111, 0b11111111), // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:326
+]; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:330
+static TEMPLATE: detail::Template = detail::Template {
+    name: "riscv64", // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:345
+    descriptors: &DESCRIPTORS, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:346
+    enumerators: &ENUMERATORS, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:347
+    hash_table: &HASH_TABLE, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:348
+    defaults: &[0x0f, 0x00, 0x0c, 0x00], // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:349
+    presets: &PRESETS, // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:350
+}
+; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:353
+/// Create a `settings::Builder` for the riscv64 settings group.
+pub fn builder() -> Builder {
+    Builder::new(&TEMPLATE) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:360
+}
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "[riscv64]")?; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:369
+        for d in &DESCRIPTORS {
+            if !d.detail.is_preset() {
+                write!(f, "{} = ", d.name)?; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:372
+                TEMPLATE.format_toml_value(d.detail, self.bytes[d.offset as usize], f)?; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:373
+                writeln!(f)?; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:377
+            }
+        }
+        Ok(()) // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:380
+    }
+}
+impl Flags {
+    /// Get the flag values as raw bytes for hashing.
+    pub fn hash_key(&self) -> &[u8] {
+        &self.bytes // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-codegen-meta-0.128.4/src/gen_settings.rs:390
+    }
+}
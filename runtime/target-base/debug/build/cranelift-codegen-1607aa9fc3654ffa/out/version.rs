@@ -0,0 +1,2 @@
+/// Version number of this crate. 
+pub const VERSION: &str = "0.128.0";
\ No newline at end of file